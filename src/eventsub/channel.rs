@@ -4,17 +4,28 @@ use super::{EventSubscription, EventType};
 use crate::types;
 use serde::{Deserialize, Serialize};
 
+pub mod automod;
 pub mod ban;
 pub mod channel_points_custom_reward;
 pub mod channel_points_custom_reward_redemption;
 pub mod cheer;
 pub mod follow;
 pub mod hypetrain;
+pub mod moderate;
+pub mod moderator;
+pub mod poll;
+pub mod prediction;
 pub mod raid;
+pub mod shield_mode;
 pub mod subscribe;
+pub mod subscription;
 pub mod unban;
 pub mod update;
 
+#[doc(inline)]
+pub use automod::{AutomodMessageHoldV1, AutomodMessageHoldV1Payload};
+#[doc(inline)]
+pub use automod::{AutomodMessageUpdateV1, AutomodMessageUpdateV1Payload};
 #[doc(inline)]
 pub use ban::{ChannelBanV1, ChannelBanV1Payload};
 #[doc(inline)]
@@ -48,10 +59,40 @@ pub use hypetrain::{ChannelHypeTrainEndV1, ChannelHypeTrainEndV1Payload};
 #[doc(inline)]
 pub use hypetrain::{ChannelHypeTrainProgressV1, ChannelHypeTrainProgressV1Payload};
 #[doc(inline)]
+pub use moderate::{ChannelModerateV1, ChannelModerateV1Payload};
+#[doc(inline)]
+pub use moderator::{ChannelModeratorAddV1, ChannelModeratorAddV1Payload};
+#[doc(inline)]
+pub use moderator::{ChannelModeratorRemoveV1, ChannelModeratorRemoveV1Payload};
+#[doc(inline)]
+pub use poll::{ChannelPollBeginV1, ChannelPollBeginV1Payload};
+#[doc(inline)]
+pub use poll::{ChannelPollEndV1, ChannelPollEndV1Payload};
+#[doc(inline)]
+pub use poll::{ChannelPollProgressV1, ChannelPollProgressV1Payload};
+#[doc(inline)]
+pub use prediction::{ChannelPredictionBeginV1, ChannelPredictionBeginV1Payload};
+#[doc(inline)]
+pub use prediction::{ChannelPredictionEndV1, ChannelPredictionEndV1Payload};
+#[doc(inline)]
+pub use prediction::{ChannelPredictionLockV1, ChannelPredictionLockV1Payload};
+#[doc(inline)]
+pub use prediction::{ChannelPredictionProgressV1, ChannelPredictionProgressV1Payload};
+#[doc(inline)]
 pub use raid::{ChannelRaidV1, ChannelRaidV1Payload};
 #[doc(inline)]
+pub use shield_mode::{ChannelShieldModeBeginV1, ChannelShieldModeBeginV1Payload};
+#[doc(inline)]
+pub use shield_mode::{ChannelShieldModeEndV1, ChannelShieldModeEndV1Payload};
+#[doc(inline)]
 pub use subscribe::{ChannelSubscribeV1, ChannelSubscribeV1Payload};
 #[doc(inline)]
+pub use subscription::{ChannelSubscriptionEndV1, ChannelSubscriptionEndV1Payload};
+#[doc(inline)]
+pub use subscription::{ChannelSubscriptionGiftV1, ChannelSubscriptionGiftV1Payload};
+#[doc(inline)]
+pub use subscription::{ChannelSubscriptionMessageV1, ChannelSubscriptionMessageV1Payload};
+#[doc(inline)]
 pub use unban::{ChannelUnbanV1, ChannelUnbanV1Payload};
 #[doc(inline)]
 pub use update::{ChannelUpdateV1, ChannelUpdateV1Payload};
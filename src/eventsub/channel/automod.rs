@@ -0,0 +1,78 @@
+#![doc(alias = "automod.message")]
+//! A message has been held by AutoMod, or a held message has been approved, denied or expired.
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod hold;
+pub mod update;
+
+#[doc(inline)]
+pub use hold::{AutomodMessageHoldV1, AutomodMessageHoldV1Payload};
+#[doc(inline)]
+pub use update::{AutomodMessageUpdateV1, AutomodMessageUpdateV1Payload};
+
+/// A message held by AutoMod
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomodMessage {
+    /// The contents of the message caught by AutoMod.
+    pub text: String,
+    /// The emotes and cheermotes in the message.
+    pub fragments: AutomodMessageFragments,
+}
+
+/// The emotes and cheermotes in an [`AutomodMessage`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomodMessageFragments {
+    /// The emotes in the message.
+    #[serde(default)]
+    pub emotes: Vec<AutomodEmote>,
+    /// The cheermotes in the message.
+    #[serde(default)]
+    pub cheermotes: Vec<AutomodCheermote>,
+}
+
+/// An emote in an [`AutomodMessage`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomodEmote {
+    /// The text of the emote.
+    pub text: String,
+    /// The ID of the emote.
+    pub id: String,
+    /// The ID of the emote set the emote belongs to.
+    #[serde(rename = "set-id")]
+    pub set_id: String,
+}
+
+/// A cheermote in an [`AutomodMessage`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomodCheermote {
+    /// The text of the cheermote.
+    pub text: String,
+    /// The amount of Bits cheered.
+    pub amount: i64,
+    /// The name of the cheermote, e.g. `Cheer`.
+    pub prefix: String,
+    /// The tier of the cheermote.
+    pub tier: i64,
+}
+
+/// The status of a message held by AutoMod
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum AutomodMessageStatus {
+    /// The message was approved and sent to chat.
+    Approved,
+    /// The message was denied.
+    Denied,
+    /// The message was not approved or denied in time.
+    Expired,
+}
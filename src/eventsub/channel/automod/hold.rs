@@ -0,0 +1,113 @@
+#![doc(alias = "automod.message.hold")]
+//! A message was caught by AutoMod for review.
+
+use super::*;
+/// [`automod.message.hold`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#automodmessagehold): a message was caught by AutoMod for review.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomodMessageHoldV1 {
+    /// The broadcaster user ID of the channel for which held message notifications will be received.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The user ID of the moderator or broadcaster that is authorized to manage AutoMod in the channel.
+    #[builder(setter(into))]
+    pub moderator_user_id: types::UserId,
+}
+
+impl EventSubscription for AutomodMessageHoldV1 {
+    type Payload = AutomodMessageHoldV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::AutomodMessageHold;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModeratorManageAutoMod];
+    const VERSION: &'static str = "1";
+}
+
+/// [`automod.message.hold`](AutomodMessageHoldV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomodMessageHoldV1Payload {
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The ID of the user who sent the message.
+    pub user_id: types::UserId,
+    /// The login of the user who sent the message.
+    pub user_login: types::UserName,
+    /// The display name of the user who sent the message.
+    pub user_name: types::DisplayName,
+    /// The ID of the held message.
+    pub message_id: types::MsgId,
+    /// The held message.
+    pub message: AutomodMessage,
+    /// The category of the message, e.g. `aggressive` or `swearing`.
+    pub category: String,
+    /// The level of severity. Measured between 1 to 4.
+    pub level: i64,
+    /// The time the message was held.
+    pub held_at: types::Timestamp,
+}
+
+#[test]
+fn parse_payload() {
+    let payload = r#"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "automod.message.hold",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337",
+                "moderator_user_id": "9001"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "blahblah",
+            "broadcaster_user_name": "blah",
+            "user_id": "456789012",
+            "user_login": "baduserbla",
+            "user_name": "baduser",
+            "message_id": "bad-message-id",
+            "message": {
+                "text": "This is a bad message… pogchamp Cheer100",
+                "fragments": {
+                    "emotes": [
+                        {
+                            "text": "pogchamp",
+                            "id": "emote-123",
+                            "set-id": "set-emote-1"
+                        }
+                    ],
+                    "cheermotes": [
+                        {
+                            "text": "Cheer100",
+                            "amount": 100,
+                            "prefix": "Cheer",
+                            "tier": 1
+                        }
+                    ]
+                }
+            },
+            "category": "aggressive",
+            "level": 4,
+            "held_at": "2022-12-02T15:00:00.00Z"
+        }
+    }
+    "#;
+
+    let val = dbg!(crate::eventsub::Payload::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
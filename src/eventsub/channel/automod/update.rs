@@ -0,0 +1,119 @@
+#![doc(alias = "automod.message.update")]
+//! A message in the AutoMod queue had its status changed.
+
+use super::*;
+/// [`automod.message.update`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#automodmessageupdate): a message in the AutoMod queue had its status changed.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomodMessageUpdateV1 {
+    /// The broadcaster user ID of the channel for which held message update notifications will be received.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The user ID of the moderator or broadcaster that is authorized to manage AutoMod in the channel.
+    #[builder(setter(into))]
+    pub moderator_user_id: types::UserId,
+}
+
+impl EventSubscription for AutomodMessageUpdateV1 {
+    type Payload = AutomodMessageUpdateV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::AutomodMessageUpdate;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModeratorManageAutoMod];
+    const VERSION: &'static str = "1";
+}
+
+/// [`automod.message.update`](AutomodMessageUpdateV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomodMessageUpdateV1Payload {
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The ID of the user who sent the message.
+    pub user_id: types::UserId,
+    /// The login of the user who sent the message.
+    pub user_login: types::UserName,
+    /// The display name of the user who sent the message.
+    pub user_name: types::DisplayName,
+    /// The ID of the moderator who approved or denied the message.
+    pub moderator_user_id: types::UserId,
+    /// The login of the moderator who approved or denied the message.
+    pub moderator_user_login: types::UserName,
+    /// The display name of the moderator who approved or denied the message.
+    pub moderator_user_name: types::DisplayName,
+    /// The ID of the held message.
+    pub message_id: types::MsgId,
+    /// The held message.
+    pub message: AutomodMessage,
+    /// The category of the message, e.g. `aggressive` or `swearing`.
+    pub category: String,
+    /// The level of severity. Measured between 1 to 4.
+    pub level: i64,
+    /// The new status of the message.
+    pub status: AutomodMessageStatus,
+    /// The time the message was held.
+    pub held_at: types::Timestamp,
+}
+
+#[test]
+fn parse_payload() {
+    let payload = r#"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "automod.message.update",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337",
+                "moderator_user_id": "9001"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "blahblah",
+            "broadcaster_user_name": "blah",
+            "user_id": "456789012",
+            "user_login": "baduserbla",
+            "user_name": "baduser",
+            "moderator_user_id": "9001",
+            "moderator_user_login": "the_mod",
+            "moderator_user_name": "The_Mod",
+            "message_id": "bad-message-id",
+            "message": {
+                "text": "This is a bad message… ",
+                "fragments": {
+                    "emotes": [],
+                    "cheermotes": []
+                }
+            },
+            "category": "aggressive",
+            "level": 4,
+            "status": "Approved",
+            "held_at": "2022-12-02T15:00:00.00Z"
+        }
+    }
+    "#;
+
+    let val = dbg!(crate::eventsub::Payload::parse(payload).unwrap());
+    crate::tests::roundtrip(&val);
+
+    match val {
+        crate::eventsub::Payload::AutomodMessageUpdateV1(val) => {
+            assert_eq!(val.event.status, AutomodMessageStatus::Approved);
+        }
+        _ => panic!("expected automod message update payload"),
+    }
+}
@@ -0,0 +1,464 @@
+#![doc(alias = "channel.moderate")]
+//! A moderator or bot performs a moderation action in a channel.
+//!
+//! This is the EventSub equivalent of the PubSub topic `chat_moderator_actions`, see `pubsub::moderation::ChatModeratorActions`.
+use super::*;
+
+/// [`channel.moderate`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelmoderate): a moderator or bot performs a moderation action in a channel.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelModerateV1 {
+    /// The user ID of the broadcaster.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The user ID of the moderator or broadcaster that is authorized to read the moderation actions in the channel.
+    #[builder(setter(into))]
+    pub moderator_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelModerateV1 {
+    type Payload = ChannelModerateV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelModerate;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:blocked_terms")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:chat_settings")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:unban_requests")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:banned_users")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:chat_messages")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:moderators")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:vips")),
+    ];
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.moderate`](ChannelModerateV1) response payload.
+///
+/// The data for the [action](ChannelModerateV1Payload::action) is in the field of the same name, all other action fields are `None`.
+/// Data for actions not known by this crate is in [`other`](ChannelModerateV1Payload::other).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ChannelModerateV1Payload {
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The ID of the moderator who performed the action.
+    pub moderator_user_id: types::UserId,
+    /// The login of the moderator who performed the action.
+    pub moderator_user_login: types::UserName,
+    /// The display name of the moderator who performed the action.
+    pub moderator_user_name: types::DisplayName,
+    /// The action performed.
+    pub action: ModerateAction,
+    /// Data for [`followers`](ModerateAction::Followers).
+    pub followers: Option<Followers>,
+    /// Data for [`slow`](ModerateAction::Slow).
+    pub slow: Option<Slow>,
+    /// Data for [`vip`](ModerateAction::Vip).
+    pub vip: Option<ModeratedUser>,
+    /// Data for [`unvip`](ModerateAction::Unvip).
+    pub unvip: Option<ModeratedUser>,
+    /// Data for [`mod`](ModerateAction::Mod).
+    #[serde(rename = "mod")]
+    pub mod_: Option<ModeratedUser>,
+    /// Data for [`unmod`](ModerateAction::Unmod).
+    pub unmod: Option<ModeratedUser>,
+    /// Data for [`ban`](ModerateAction::Ban).
+    pub ban: Option<Ban>,
+    /// Data for [`unban`](ModerateAction::Unban).
+    pub unban: Option<ModeratedUser>,
+    /// Data for [`timeout`](ModerateAction::Timeout).
+    pub timeout: Option<Timeout>,
+    /// Data for [`untimeout`](ModerateAction::Untimeout).
+    pub untimeout: Option<ModeratedUser>,
+    /// Data for [`raid`](ModerateAction::Raid).
+    pub raid: Option<Raid>,
+    /// Data for [`unraid`](ModerateAction::Unraid).
+    pub unraid: Option<ModeratedUser>,
+    /// Data for [`delete`](ModerateAction::Delete).
+    pub delete: Option<Delete>,
+    /// Data for the blocked and permitted term actions, e.g. [`add_blocked_term`](ModerateAction::AddBlockedTerm).
+    pub automod_terms: Option<AutomodTerms>,
+    /// Data for [`approve_unban_request`](ModerateAction::ApproveUnbanRequest) and [`deny_unban_request`](ModerateAction::DenyUnbanRequest).
+    pub unban_request: Option<UnbanRequest>,
+    /// Fields not known by this crate, such as the data for [`Other`](ModerateAction::Other) actions.
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A moderation action. The PubSub equivalent is `pubsub::moderation::ModerationActionCommand`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+// Derived (de)serialization is used for the known actions, see the impls below
+#[serde(remote = "Self")]
+#[non_exhaustive]
+pub enum ModerateAction {
+    /// A user was banned
+    Ban,
+    /// A user was timed out
+    Timeout,
+    /// A user was unbanned
+    Unban,
+    /// A user was removed from timeout
+    Untimeout,
+    /// Chat was cleared for all viewers
+    Clear,
+    /// Emote-only chat enabled
+    #[serde(rename = "emoteonly")]
+    EmoteOnly,
+    /// Emote-only chat disabled
+    #[serde(rename = "emoteonlyoff")]
+    EmoteOnlyOff,
+    /// Followers-only chat enabled
+    Followers,
+    /// Followers-only chat disabled
+    #[serde(rename = "followersoff")]
+    FollowersOff,
+    /// Unique chat enabled
+    #[serde(rename = "uniquechat")]
+    UniqueChat,
+    /// Unique chat disabled
+    #[serde(rename = "uniquechatoff")]
+    UniqueChatOff,
+    /// Slow-mode chat enabled
+    Slow,
+    /// Slow-mode chat disabled
+    #[serde(rename = "slowoff")]
+    SlowOff,
+    /// Subscriber-only chat enabled
+    Subscribers,
+    /// Subscriber-only chat disabled
+    #[serde(rename = "subscribersoff")]
+    SubscribersOff,
+    /// A raid was started
+    Raid,
+    /// A raid was canceled
+    Unraid,
+    /// A message was deleted
+    Delete,
+    /// A user was added as VIP
+    Vip,
+    /// A user was removed as VIP
+    Unvip,
+    /// A user was added as moderator
+    Mod,
+    /// A user was removed as moderator
+    Unmod,
+    /// A blocked term was added
+    AddBlockedTerm,
+    /// A permitted term was added
+    AddPermittedTerm,
+    /// A blocked term was removed
+    RemoveBlockedTerm,
+    /// A permitted term was removed
+    RemovePermittedTerm,
+    /// An unban request was approved
+    ApproveUnbanRequest,
+    /// An unban request was denied
+    DenyUnbanRequest,
+    /// An action not known by this crate.
+    #[serde(skip)]
+    Other(String),
+}
+
+impl Serialize for ModerateAction {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ModerateAction::Other(action) => serializer.serialize_str(action),
+            _ => ModerateAction::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ModerateAction {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::IntoDeserializer;
+
+        let action = String::deserialize(deserializer)?;
+        let deserializer: serde::de::value::StrDeserializer<'_, serde::de::value::Error> =
+            action.as_str().into_deserializer();
+        Ok(ModerateAction::deserialize(deserializer).unwrap_or(ModerateAction::Other(action)))
+    }
+}
+
+/// Data for [`followers`](ModerateAction::Followers)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Followers {
+    /// The length of time, in minutes, that the followers must have followed the broadcaster to participate in the chat room.
+    pub follow_duration_minutes: i64,
+}
+
+/// Data for [`slow`](ModerateAction::Slow)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Slow {
+    /// The amount of time, in seconds, that users need to wait between sending messages.
+    pub wait_time_seconds: i64,
+}
+
+/// The user targeted by a moderation action
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ModeratedUser {
+    /// The ID of the user.
+    pub user_id: types::UserId,
+    /// The login of the user.
+    pub user_login: types::UserName,
+    /// The display name of the user.
+    pub user_name: types::DisplayName,
+}
+
+/// Data for [`ban`](ModerateAction::Ban)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Ban {
+    /// The ID of the banned user.
+    pub user_id: types::UserId,
+    /// The login of the banned user.
+    pub user_login: types::UserName,
+    /// The display name of the banned user.
+    pub user_name: types::DisplayName,
+    /// Reason given for the ban.
+    pub reason: String,
+}
+
+/// Data for [`timeout`](ModerateAction::Timeout)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Timeout {
+    /// The ID of the user being timed out.
+    pub user_id: types::UserId,
+    /// The login of the user being timed out.
+    pub user_login: types::UserName,
+    /// The display name of the user being timed out.
+    pub user_name: types::DisplayName,
+    /// Reason given for the timeout.
+    pub reason: String,
+    /// The time at which the timeout ends.
+    pub expires_at: types::Timestamp,
+}
+
+/// Data for [`raid`](ModerateAction::Raid)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Raid {
+    /// The ID of the user being raided.
+    pub user_id: types::UserId,
+    /// The login of the user being raided.
+    pub user_login: types::UserName,
+    /// The display name of the user being raided.
+    pub user_name: types::DisplayName,
+    /// The viewer count.
+    pub viewer_count: i64,
+}
+
+/// Data for [`delete`](ModerateAction::Delete)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Delete {
+    /// The ID of the user whose message is being deleted.
+    pub user_id: types::UserId,
+    /// The login of the user whose message is being deleted.
+    pub user_login: types::UserName,
+    /// The display name of the user whose message is being deleted.
+    pub user_name: types::DisplayName,
+    /// The ID of the message being deleted.
+    pub message_id: types::MsgId,
+    /// The message body of the message being deleted.
+    pub message_body: String,
+}
+
+/// Data for the blocked and permitted term actions
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct AutomodTerms {
+    /// Whether the terms were added or removed.
+    pub action: AutomodTermsAction,
+    /// The list the terms were added to or removed from.
+    pub list: AutomodTermsList,
+    /// The terms being added or removed.
+    pub terms: Vec<String>,
+    /// Whether the terms were added due to an AutoMod message approve or deny action.
+    pub from_automod: bool,
+}
+
+/// Action done to [`AutomodTerms`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AutomodTermsAction {
+    /// The terms were added
+    Add,
+    /// The terms were removed
+    Remove,
+}
+
+/// List modified by [`AutomodTerms`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AutomodTermsList {
+    /// Blocked terms
+    Blocked,
+    /// Permitted terms
+    Permitted,
+}
+
+/// Data for [`approve_unban_request`](ModerateAction::ApproveUnbanRequest) and [`deny_unban_request`](ModerateAction::DenyUnbanRequest)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct UnbanRequest {
+    /// Whether the unban request was approved or denied.
+    pub is_approved: bool,
+    /// The ID of the banned user.
+    pub user_id: types::UserId,
+    /// The login of the banned user.
+    pub user_login: types::UserName,
+    /// The display name of the banned user.
+    pub user_name: types::DisplayName,
+    /// The message included by the moderator explaining their approval or denial.
+    pub moderator_message: String,
+}
+
+#[test]
+fn parse_payload() {
+    let payload = r#"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.moderate",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337",
+                "moderator_user_id": "9001"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "glowillig",
+            "broadcaster_user_name": "glowillig",
+            "moderator_user_id": "424596340",
+            "moderator_user_login": "quotrok",
+            "moderator_user_name": "quotrok",
+            "action": "timeout",
+            "followers": null,
+            "slow": null,
+            "vip": null,
+            "unvip": null,
+            "mod": null,
+            "unmod": null,
+            "ban": null,
+            "unban": null,
+            "timeout": {
+                "user_id": "141981764",
+                "user_login": "twitchdev",
+                "user_name": "TwitchDev",
+                "reason": "Does not like pineapple on pizza.",
+                "expires_at": "2022-03-15T02:00:28Z"
+            },
+            "untimeout": null,
+            "raid": null,
+            "unraid": null,
+            "delete": null,
+            "automod_terms": null,
+            "unban_request": null
+        }
+    }
+    "#;
+
+    let val = dbg!(crate::eventsub::Payload::parse(payload).unwrap());
+    crate::tests::roundtrip(&val);
+
+    match val {
+        crate::eventsub::Payload::ChannelModerateV1(val) => {
+            assert_eq!(val.event.action, ModerateAction::Timeout);
+            assert_eq!(val.event.timeout.unwrap().user_id, "141981764");
+        }
+        _ => panic!("expected channel moderate payload"),
+    }
+
+    let payload = r#"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.moderate",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337",
+                "moderator_user_id": "9001"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "glowillig",
+            "broadcaster_user_name": "glowillig",
+            "moderator_user_id": "424596340",
+            "moderator_user_login": "quotrok",
+            "moderator_user_name": "quotrok",
+            "action": "warn",
+            "followers": null,
+            "slow": null,
+            "vip": null,
+            "unvip": null,
+            "mod": null,
+            "unmod": null,
+            "ban": null,
+            "unban": null,
+            "timeout": null,
+            "untimeout": null,
+            "raid": null,
+            "unraid": null,
+            "delete": null,
+            "automod_terms": null,
+            "unban_request": null,
+            "warn": {
+                "user_id": "141981764",
+                "user_login": "twitchdev",
+                "user_name": "TwitchDev",
+                "reason": "Does not like pineapple on pizza.",
+                "chat_rules_cited": null
+            }
+        }
+    }
+    "#;
+
+    let val = dbg!(crate::eventsub::Payload::parse(payload).unwrap());
+    crate::tests::roundtrip(&val);
+
+    match val {
+        crate::eventsub::Payload::ChannelModerateV1(val) => {
+            assert_eq!(val.event.action, ModerateAction::Other("warn".to_string()));
+            assert_eq!(val.event.other["warn"]["user_id"], "141981764");
+        }
+        _ => panic!("expected channel moderate payload"),
+    }
+}
@@ -0,0 +1,13 @@
+#![doc(alias = "channel.moderator")]
+//! A user has been added or removed as a moderator.
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod add;
+pub mod remove;
+
+#[doc(inline)]
+pub use add::{ChannelModeratorAddV1, ChannelModeratorAddV1Payload};
+#[doc(inline)]
+pub use remove::{ChannelModeratorRemoveV1, ChannelModeratorRemoveV1Payload};
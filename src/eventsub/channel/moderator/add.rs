@@ -0,0 +1,75 @@
+#![doc(alias = "channel.moderator.add")]
+//! A user is given moderator privileges on a specified channel.
+
+use super::*;
+/// [`channel.moderator.add`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelmoderatoradd): a user is given moderator privileges on a specified channel.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelModeratorAddV1 {
+    /// The broadcaster user ID for the channel you want to get moderator add notifications for.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelModeratorAddV1 {
+    type Payload = ChannelModeratorAddV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelModeratorAdd;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ModerationRead];
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.moderator.add`](ChannelModeratorAddV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelModeratorAddV1Payload {
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The user ID of the user added as a moderator.
+    pub user_id: types::UserId,
+    /// The login of the user added as a moderator.
+    pub user_login: types::UserName,
+    /// The display name of the user added as a moderator.
+    pub user_name: types::DisplayName,
+}
+
+#[test]
+fn parse_payload() {
+    let payload = r#"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.moderator.add",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+               "broadcaster_user_id": "1337"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "user_id": "1234",
+            "user_login": "mod_user",
+            "user_name": "Mod_User",
+            "broadcaster_user_id": "1337",
+            "broadcaster_user_login": "cooler_user",
+            "broadcaster_user_name": "Cooler_User"
+        }
+    }
+    "#;
+
+    let val = dbg!(crate::eventsub::Payload::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
@@ -0,0 +1,57 @@
+#![doc(alias = "channel.poll")]
+//! A poll has begun, progressed or ended.
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod begin;
+pub mod end;
+pub mod progress;
+
+#[doc(inline)]
+pub use begin::{ChannelPollBeginV1, ChannelPollBeginV1Payload};
+#[doc(inline)]
+pub use end::{ChannelPollEndV1, ChannelPollEndV1Payload};
+#[doc(inline)]
+pub use progress::{ChannelPollProgressV1, ChannelPollProgressV1Payload};
+
+/// A choice in a poll
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PollChoice {
+    /// ID for the choice.
+    pub id: types::PollChoiceId,
+    /// Text displayed for the choice.
+    pub title: String,
+    /// Number of votes received via Bits. Not included in [`channel.poll.begin`](ChannelPollBeginV1).
+    pub bits_votes: Option<i64>,
+    /// Number of votes received via Channel Points. Not included in [`channel.poll.begin`](ChannelPollBeginV1).
+    pub channel_points_votes: Option<i64>,
+    /// Total number of votes received for the choice across all methods of voting. Not included in [`channel.poll.begin`](ChannelPollBeginV1).
+    pub votes: Option<i64>,
+}
+
+/// Voting settings for a method of voting in a poll
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Voting {
+    /// Indicates if this method of voting is enabled.
+    pub is_enabled: bool,
+    /// Number of Bits or Channel Points required to vote once.
+    pub amount_per_vote: i64,
+}
+
+/// Status of an ended poll
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "lowercase")]
+pub enum PollEndStatus {
+    /// The poll ended normally.
+    Completed,
+    /// The poll ended and is no longer visible on the channel.
+    Archived,
+    /// The poll was ended manually by the broadcaster or a moderator.
+    Terminated,
+}
@@ -0,0 +1,77 @@
+#![doc(alias = "channel.prediction")]
+//! A prediction has begun, progressed, been locked or ended.
+use super::{EventSubscription, EventType};
+use crate::types;
+use serde::{Deserialize, Serialize};
+
+pub mod begin;
+pub mod end;
+pub mod lock;
+pub mod progress;
+
+#[doc(inline)]
+pub use begin::{ChannelPredictionBeginV1, ChannelPredictionBeginV1Payload};
+#[doc(inline)]
+pub use end::{ChannelPredictionEndV1, ChannelPredictionEndV1Payload};
+#[doc(inline)]
+pub use lock::{ChannelPredictionLockV1, ChannelPredictionLockV1Payload};
+#[doc(inline)]
+pub use progress::{ChannelPredictionProgressV1, ChannelPredictionProgressV1Payload};
+
+/// An outcome in a prediction
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct PredictionOutcome {
+    /// The outcome ID.
+    pub id: types::PredictionOutcomeId,
+    /// The outcome title.
+    pub title: String,
+    /// The color for the outcome.
+    pub color: OutcomeColor,
+    /// The number of users who used Channel Points on this outcome. Not included in [`channel.prediction.begin`](ChannelPredictionBeginV1).
+    pub users: Option<i64>,
+    /// The total number of Channel Points used on this outcome. Not included in [`channel.prediction.begin`](ChannelPredictionBeginV1).
+    pub channel_points: Option<i64>,
+    /// The users who used the most Channel Points on this outcome, at most 10. Not included in [`channel.prediction.begin`](ChannelPredictionBeginV1).
+    pub top_predictors: Option<Vec<TopPredictor>>,
+}
+
+/// Color of a [prediction outcome](PredictionOutcome)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "lowercase")]
+pub enum OutcomeColor {
+    /// Blue
+    Blue,
+    /// Pink
+    Pink,
+}
+
+/// A user who used Channel Points on a [prediction outcome](PredictionOutcome)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct TopPredictor {
+    /// The ID of the user.
+    pub user_id: types::UserId,
+    /// The login of the user.
+    pub user_login: types::UserName,
+    /// The display name of the user.
+    pub user_name: types::DisplayName,
+    /// The number of Channel Points won. `None` if the prediction is not resolved, `Some(0)` if the user lost.
+    pub channel_points_won: Option<i64>,
+    /// The number of Channel Points used to participate in the prediction.
+    pub channel_points_used: i64,
+}
+
+/// Status of an ended prediction
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "lowercase")]
+pub enum PredictionEndStatus {
+    /// A winning outcome was chosen and the Channel Points were distributed to the users who predicted it.
+    Resolved,
+    /// The prediction was canceled and the Channel Points were refunded.
+    Canceled,
+}
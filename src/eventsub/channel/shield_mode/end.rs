@@ -0,0 +1,84 @@
+#![doc(alias = "channel.shield_mode.end")]
+//! A moderator deactivates Shield Mode on the specified channel.
+
+use super::*;
+/// [`channel.shield_mode.end`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types#channelshield_modeend): a moderator deactivates Shield Mode on the specified channel.
+#[derive(Clone, Debug, typed_builder::TypedBuilder, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelShieldModeEndV1 {
+    /// The broadcaster user ID of the channel for which “shield mode end” notifications will be received.
+    #[builder(setter(into))]
+    pub broadcaster_user_id: types::UserId,
+    /// The user ID of the moderator or broadcaster that is authorized to read the channel’s Shield Mode status.
+    #[builder(setter(into))]
+    pub moderator_user_id: types::UserId,
+}
+
+impl EventSubscription for ChannelShieldModeEndV1 {
+    type Payload = ChannelShieldModeEndV1Payload;
+
+    const EVENT_TYPE: EventType = EventType::ChannelShieldModeEnd;
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:read:shield_mode"),
+    )];
+    const VERSION: &'static str = "1";
+}
+
+/// [`channel.shield_mode.end`](ChannelShieldModeEndV1) response payload.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelShieldModeEndV1Payload {
+    /// The requested broadcaster ID.
+    pub broadcaster_user_id: types::UserId,
+    /// The requested broadcaster login.
+    pub broadcaster_user_login: types::UserName,
+    /// The requested broadcaster display name.
+    pub broadcaster_user_name: types::DisplayName,
+    /// The ID of the moderator that deactivated Shield Mode.
+    pub moderator_user_id: types::UserId,
+    /// The login of the moderator that deactivated Shield Mode.
+    pub moderator_user_login: types::UserName,
+    /// The display name of the moderator that deactivated Shield Mode.
+    pub moderator_user_name: types::DisplayName,
+    /// The time the moderator deactivated Shield Mode.
+    pub ended_at: types::Timestamp,
+}
+
+#[test]
+fn parse_payload() {
+    let payload = r#"
+    {
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.shield_mode.end",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "12345",
+                "moderator_user_id": "98765"
+            },
+             "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "broadcaster_user_id": "12345",
+            "broadcaster_user_name": "SimplySimple",
+            "broadcaster_user_login": "simplysimple",
+            "moderator_user_id": "98765",
+            "moderator_user_name": "ParticularlyParticular123",
+            "moderator_user_login": "particularlyparticular123",
+            "ended_at": "2022-07-26T17:00:03.17106713Z"
+        }
+    }
+    "#;
+
+    let val = dbg!(crate::eventsub::Payload::parse(payload).unwrap());
+    crate::tests::roundtrip(&val)
+}
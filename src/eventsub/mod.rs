@@ -53,6 +53,13 @@ use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 pub mod channel;
 pub mod stream;
 pub mod user;
+#[cfg(feature = "hmac")]
+#[cfg_attr(nightly, doc(cfg(feature = "hmac")))]
+pub mod verifier;
+#[cfg(feature = "hmac")]
+#[cfg_attr(nightly, doc(cfg(feature = "hmac")))]
+pub mod webhook;
+pub mod websocket;
 
 /// An EventSub subscription.
 pub trait EventSubscription: DeserializeOwned + Serialize + PartialEq + Clone {
@@ -92,6 +99,119 @@ pub struct VerificationRequest {
     // pub id: types::EventSubId,
 }
 
+/// Revocation of a subscription
+///
+/// Sent when a subscription is no longer enabled, for example when the user revoked authorization or the subscription failed too many times.
+#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Revocation {
+    /// Information about subscription that was revoked
+    pub subscription: EventSubSubscription,
+}
+
+impl Revocation {
+    /// Why the subscription was revoked
+    pub fn reason(&self) -> &Status { &self.subscription.status }
+}
+
+/// Type of a webhook message, sent in the `Twitch-Eventsub-Message-Type` header
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum WebhookMessageType {
+    /// Verification of the callback, respond with the [challenge](VerificationRequest::challenge)
+    WebhookCallbackVerification,
+    /// An event notification
+    Notification,
+    /// A subscription was [revoked](Revocation)
+    Revocation,
+}
+
+impl std::str::FromStr for WebhookMessageType {
+    type Err = PayloadParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "webhook_callback_verification" => Ok(WebhookMessageType::WebhookCallbackVerification),
+            "notification" => Ok(WebhookMessageType::Notification),
+            "revocation" => Ok(WebhookMessageType::Revocation),
+            other => Err(PayloadParseError::UnknownMessageType(other.to_string())),
+        }
+    }
+}
+
+/// Headers of a webhook message
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct WebhookHeaders {
+    /// `Twitch-Eventsub-Message-Id`: unique ID of the message, the same for every retry of the message.
+    pub message_id: String,
+    /// `Twitch-Eventsub-Message-Retry`: how many times this message has been sent before.
+    pub message_retry: Option<u32>,
+    /// `Twitch-Eventsub-Message-Type`: the type of message.
+    pub message_type: WebhookMessageType,
+    /// `Twitch-Eventsub-Message-Signature`: the `HMAC-SHA256` signature of the message, see [`Payload::verify_payload`].
+    pub message_signature: Option<String>,
+    /// `Twitch-Eventsub-Message-Timestamp`: when the message was sent, in RFC3339 format.
+    pub message_timestamp: types::Timestamp,
+    /// `Twitch-Eventsub-Subscription-Type`: the type of the subscription.
+    pub subscription_type: Option<EventType>,
+    /// `Twitch-Eventsub-Subscription-Version`: the version of the subscription.
+    pub subscription_version: Option<String>,
+}
+
+impl WebhookHeaders {
+    /// Read the EventSub headers of a webhook message.
+    ///
+    /// `Twitch-Eventsub-Message-Id`, `Twitch-Eventsub-Message-Type` and `Twitch-Eventsub-Message-Timestamp` are required.
+    pub fn from_headers(headers: &http::HeaderMap) -> Result<WebhookHeaders, PayloadParseError> {
+        fn get<'a>(
+            headers: &'a http::HeaderMap,
+            name: &'static str,
+        ) -> Result<Option<&'a str>, PayloadParseError> {
+            headers
+                .get(name)
+                .map(|v| {
+                    v.to_str()
+                        .map_err(|_| PayloadParseError::InvalidHeader(name))
+                })
+                .transpose()
+        }
+
+        fn required<'a>(
+            headers: &'a http::HeaderMap,
+            name: &'static str,
+        ) -> Result<&'a str, PayloadParseError> {
+            get(headers, name)?.ok_or(PayloadParseError::MissingHeader(name))
+        }
+
+        Ok(WebhookHeaders {
+            message_id: required(headers, "Twitch-Eventsub-Message-Id")?.to_string(),
+            message_retry: get(headers, "Twitch-Eventsub-Message-Retry")?
+                .map(|v| {
+                    v.parse().map_err(|_| {
+                        PayloadParseError::InvalidHeader("Twitch-Eventsub-Message-Retry")
+                    })
+                })
+                .transpose()?,
+            message_type: required(headers, "Twitch-Eventsub-Message-Type")?.parse()?,
+            message_signature: get(headers, "Twitch-Eventsub-Message-Signature")?
+                .map(str::to_string),
+            message_timestamp: required(headers, "Twitch-Eventsub-Message-Timestamp")?.to_string(),
+            subscription_type: get(headers, "Twitch-Eventsub-Subscription-Type")?
+                .map(|v| {
+                    serde_json::from_value(serde_json::Value::String(v.to_string())).map_err(|_| {
+                        PayloadParseError::InvalidHeader("Twitch-Eventsub-Subscription-Type")
+                    })
+                })
+                .transpose()?,
+            subscription_version: get(headers, "Twitch-Eventsub-Subscription-Version")?
+                .map(str::to_string),
+        })
+    }
+}
+
 /// Subscription payload. Received on events. Enumerates all possible [`NotificationPayload`s](NotificationPayload)
 ///
 /// Use [`Payload::parse`] to construct
@@ -106,6 +226,12 @@ pub enum Payload {
     ChannelFollowV1(NotificationPayload<channel::ChannelFollowV1>),
     /// Channel Subscribe V1 Event
     ChannelSubscribeV1(NotificationPayload<channel::ChannelSubscribeV1>),
+    /// Channel Subscription End V1 Event
+    ChannelSubscriptionEndV1(NotificationPayload<channel::ChannelSubscriptionEndV1>),
+    /// Channel Subscription Gift V1 Event
+    ChannelSubscriptionGiftV1(NotificationPayload<channel::ChannelSubscriptionGiftV1>),
+    /// Channel Subscription Message V1 Event
+    ChannelSubscriptionMessageV1(NotificationPayload<channel::ChannelSubscriptionMessageV1>),
     /// Channel Cheer V1 Event
     ChannelCheerV1(NotificationPayload<channel::ChannelCheerV1>),
     /// Channel Ban V1 Event
@@ -136,6 +262,34 @@ pub enum Payload {
     ChannelHypeTrainProgressV1(NotificationPayload<channel::ChannelHypeTrainProgressV1>),
     /// Channel Hype Train End V1 Event
     ChannelHypeTrainEndV1(NotificationPayload<channel::ChannelHypeTrainEndV1>),
+    /// Channel Poll Begin V1 Event
+    ChannelPollBeginV1(NotificationPayload<channel::ChannelPollBeginV1>),
+    /// Channel Poll Progress V1 Event
+    ChannelPollProgressV1(NotificationPayload<channel::ChannelPollProgressV1>),
+    /// Channel Poll End V1 Event
+    ChannelPollEndV1(NotificationPayload<channel::ChannelPollEndV1>),
+    /// Channel Prediction Begin V1 Event
+    ChannelPredictionBeginV1(NotificationPayload<channel::ChannelPredictionBeginV1>),
+    /// Channel Prediction Progress V1 Event
+    ChannelPredictionProgressV1(NotificationPayload<channel::ChannelPredictionProgressV1>),
+    /// Channel Prediction Lock V1 Event
+    ChannelPredictionLockV1(NotificationPayload<channel::ChannelPredictionLockV1>),
+    /// Channel Prediction End V1 Event
+    ChannelPredictionEndV1(NotificationPayload<channel::ChannelPredictionEndV1>),
+    /// Channel Moderator Add V1 Event
+    ChannelModeratorAddV1(NotificationPayload<channel::ChannelModeratorAddV1>),
+    /// Channel Moderator Remove V1 Event
+    ChannelModeratorRemoveV1(NotificationPayload<channel::ChannelModeratorRemoveV1>),
+    /// Channel Moderate V1 Event
+    ChannelModerateV1(NotificationPayload<channel::ChannelModerateV1>),
+    /// Automod Message Hold V1 Event
+    AutomodMessageHoldV1(NotificationPayload<channel::AutomodMessageHoldV1>),
+    /// Automod Message Update V1 Event
+    AutomodMessageUpdateV1(NotificationPayload<channel::AutomodMessageUpdateV1>),
+    /// Channel Shield Mode Begin V1 Event
+    ChannelShieldModeBeginV1(NotificationPayload<channel::ChannelShieldModeBeginV1>),
+    /// Channel Shield Mode End V1 Event
+    ChannelShieldModeEndV1(NotificationPayload<channel::ChannelShieldModeEndV1>),
     /// StreamOnline V1 Event
     StreamOnlineV1(NotificationPayload<stream::StreamOnlineV1>),
     /// StreamOffline V1 Event
@@ -146,6 +300,15 @@ pub enum Payload {
     UserAuthorizationRevokeV1(NotificationPayload<user::UserAuthorizationRevokeV1>),
     /// Channel Raid V1 Event
     ChannelRaidV1(NotificationPayload<channel::ChannelRaidV1>),
+    /// Subscription Revocation
+    Revocation(Revocation),
+    /// Notification for a subscription type or version not known by this crate
+    Unknown {
+        /// Subscription information.
+        subscription: EventSubSubscription,
+        /// Event information.
+        event: serde_json::Value,
+    },
 }
 
 impl Payload {
@@ -154,15 +317,49 @@ impl Payload {
         serde_json::from_str(source).map_err(Into::into)
     }
 
+    /// Parse string slice as a [Payload] of the given [message type](WebhookMessageType)
+    pub fn parse_message(
+        message_type: &WebhookMessageType,
+        source: &str,
+    ) -> Result<Payload, PayloadParseError> {
+        Ok(match message_type {
+            WebhookMessageType::WebhookCallbackVerification => {
+                Payload::VerificationRequest(serde_json::from_str(source)?)
+            }
+            WebhookMessageType::Notification => match Payload::parse(source)? {
+                Payload::VerificationRequest(_) | Payload::Revocation(_) => {
+                    return Err(PayloadParseError::UnexpectedPayload(message_type.clone()))
+                }
+                payload => payload,
+            },
+            WebhookMessageType::Revocation => Payload::Revocation(serde_json::from_str(source)?),
+        })
+    }
+
     /// Parse http post request as a [Payload].
+    ///
+    /// The `Twitch-Eventsub-Message-Type` header decides what kind of payload to expect.
+    /// If the header is missing, the payload is detected from the body.
     pub fn parse_http(request: &http::Request<Vec<u8>>) -> Result<Payload, PayloadParseError> {
-        Payload::parse(std::str::from_utf8(request.body())?)
+        let source = std::str::from_utf8(request.body())?;
+        match request.headers().get("Twitch-Eventsub-Message-Type") {
+            Some(message_type) => {
+                let message_type: WebhookMessageType = message_type
+                    .to_str()
+                    .map_err(|_| PayloadParseError::InvalidHeader("Twitch-Eventsub-Message-Type"))?
+                    .parse()?;
+                Payload::parse_message(&message_type, source)
+            }
+            None => Payload::parse(source),
+        }
     }
 
     /// Verify that this payload is authentic using `HMAC-SHA256`.
     ///
     /// HMAC key is `secret`, HMAC message is a concatenation of `Twitch-Eventsub-Message-Id` header, `Twitch-Eventsub-Message-Timestamp` header and the request body.
     /// HMAC signature is `Twitch-Eventsub-Message-Signature` header
+    ///
+    /// This does not protect against replayed or redelivered messages, see [`verifier::Verifier`] for that.
     #[cfg(feature = "hmac")]
     #[cfg_attr(nightly, doc(cfg(feature = "hmac")))]
     pub fn verify_payload(request: &http::Request<Vec<u8>>, secret: &[u8]) -> bool {
@@ -227,6 +424,14 @@ pub enum PayloadParseError {
     Utf8Error(#[from] std::str::Utf8Error),
     /// could not parse [`http::Request::body()`] as a [`Payload`]
     DeserializeError(#[from] serde_json::Error),
+    /// header `{0}` is missing
+    MissingHeader(&'static str),
+    /// header `{0}` is invalid
+    InvalidHeader(&'static str),
+    /// unknown message type `{0}`
+    UnknownMessageType(String),
+    /// payload does not match message type `{0:?}`
+    UnexpectedPayload(WebhookMessageType),
 }
 
 impl<'de> Deserialize<'de> for Payload {
@@ -247,7 +452,19 @@ impl<'de> Deserialize<'de> for Payload {
                             event: serde_json::from_value($response.e).map_err(serde::de::Error::custom)?,
                         })
                     }  )*
-                    (v, e) => return Err(serde::de::Error::custom(format!("could not find a match for version `{}` on event type `{}`", v, e)))
+                    _ => Payload::Unknown {
+                        subscription: EventSubSubscription {
+                            cost: sub.cost.try_into().map_err(serde::de::Error::custom)?,
+                            condition: Condition::from_value(&sub.type_, &sub.version, sub.condition),
+                            created_at: sub.created_at,
+                            id: sub.id,
+                            status: sub.status,
+                            transport: sub.transport,
+                            type_: sub.type_,
+                            version: sub.version,
+                        },
+                        event: $response.e,
+                    },
                 }
             }}
         }
@@ -263,7 +480,12 @@ impl<'de> Deserialize<'de> for Payload {
                 #[serde(remote = "Payload")]
                 pub enum Corrected {
                     $($event(NotificationPayload<$module::$event>),)*
-                    VerificationRequest(VerificationRequest)
+                    VerificationRequest(VerificationRequest),
+                    Revocation(Revocation),
+                    Unknown {
+                        subscription: EventSubSubscription,
+                        event: serde_json::Value,
+                    },
                 }
         }
     }
@@ -318,12 +540,17 @@ impl<'de> Deserialize<'de> for Payload {
             Valid(Payload),
             VerificationRequest(VerificationRequest),
             InternalPayloadResponse(InternalPayloadResponse),
+            // Must come after the notification, which also has a subscription
+            Revocation(Revocation),
         }
 
         corrected!(
             channel::ChannelUpdateV1;
                 channel::ChannelFollowV1;
                 channel::ChannelSubscribeV1;
+                channel::ChannelSubscriptionEndV1;
+                channel::ChannelSubscriptionGiftV1;
+                channel::ChannelSubscriptionMessageV1;
                 channel::ChannelCheerV1;
                 channel::ChannelBanV1;
                 channel::ChannelUnbanV1;
@@ -336,6 +563,20 @@ impl<'de> Deserialize<'de> for Payload {
                 channel::ChannelHypeTrainBeginV1;
                 channel::ChannelHypeTrainProgressV1;
                 channel::ChannelHypeTrainEndV1;
+                channel::ChannelPollBeginV1;
+                channel::ChannelPollProgressV1;
+                channel::ChannelPollEndV1;
+                channel::ChannelPredictionBeginV1;
+                channel::ChannelPredictionProgressV1;
+                channel::ChannelPredictionLockV1;
+                channel::ChannelPredictionEndV1;
+                channel::ChannelModeratorAddV1;
+                channel::ChannelModeratorRemoveV1;
+                channel::ChannelModerateV1;
+                channel::AutomodMessageHoldV1;
+                channel::AutomodMessageUpdateV1;
+                channel::ChannelShieldModeBeginV1;
+                channel::ChannelShieldModeEndV1;
                 stream::StreamOnlineV1;
                 stream::StreamOfflineV1;
                 user::UserUpdateV1;
@@ -350,10 +591,14 @@ impl<'de> Deserialize<'de> for Payload {
             InternalResponse::VerificationRequest(verification) => {
                 Ok(Payload::VerificationRequest(verification))
             }
+            InternalResponse::Revocation(revocation) => Ok(Payload::Revocation(revocation)),
             InternalResponse::InternalPayloadResponse(response) => Ok(match_event! { response;
                 channel::ChannelUpdateV1;
                 channel::ChannelFollowV1;
                 channel::ChannelSubscribeV1;
+                channel::ChannelSubscriptionEndV1;
+                channel::ChannelSubscriptionGiftV1;
+                channel::ChannelSubscriptionMessageV1;
                 channel::ChannelCheerV1;
                 channel::ChannelBanV1;
                 channel::ChannelUnbanV1;
@@ -366,6 +611,20 @@ impl<'de> Deserialize<'de> for Payload {
                 channel::ChannelHypeTrainBeginV1;
                 channel::ChannelHypeTrainProgressV1;
                 channel::ChannelHypeTrainEndV1;
+                channel::ChannelPollBeginV1;
+                channel::ChannelPollProgressV1;
+                channel::ChannelPollEndV1;
+                channel::ChannelPredictionBeginV1;
+                channel::ChannelPredictionProgressV1;
+                channel::ChannelPredictionLockV1;
+                channel::ChannelPredictionEndV1;
+                channel::ChannelModeratorAddV1;
+                channel::ChannelModeratorRemoveV1;
+                channel::ChannelModerateV1;
+                channel::AutomodMessageHoldV1;
+                channel::AutomodMessageUpdateV1;
+                channel::ChannelShieldModeBeginV1;
+                channel::ChannelShieldModeEndV1;
                 stream::StreamOnlineV1;
                 stream::StreamOfflineV1;
                 user::UserUpdateV1;
@@ -415,14 +674,61 @@ pub struct EventSubscriptionInformation<E: EventSubscription> {
 pub struct Transport {
     /// Method for transport
     pub method: TransportMethod,
-    /// Callback
-    pub callback: String,
-    /// Secret attached to the subscription.
+    /// Callback, only for [webhooks](TransportMethod::Webhook)
+    #[builder(default, setter(strip_option, into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback: Option<String>,
+    /// Secret attached to the subscription, only for [webhooks](TransportMethod::Webhook)
     ///
     /// # Notes
     ///
     /// Secret must be between 10 and 100 characters
-    pub secret: String,
+    #[builder(default, setter(strip_option, into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// ID of the conduit to send notifications to, only for [conduits](TransportMethod::Conduit)
+    #[builder(default, setter(strip_option, into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conduit_id: Option<String>,
+    /// ID of the WebSocket session to send notifications to, only for [websockets](TransportMethod::Websocket)
+    #[builder(default, setter(strip_option, into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+}
+
+impl Transport {
+    /// Send notifications to a webhook `callback`, signed with `secret`
+    pub fn webhook(callback: impl Into<String>, secret: impl Into<String>) -> Transport {
+        Transport {
+            method: TransportMethod::Webhook,
+            callback: Some(callback.into()),
+            secret: Some(secret.into()),
+            conduit_id: None,
+            session_id: None,
+        }
+    }
+
+    /// Send notifications to the shards of a [conduit](crate::helix::eventsub::Conduit)
+    pub fn conduit(conduit_id: impl Into<String>) -> Transport {
+        Transport {
+            method: TransportMethod::Conduit,
+            callback: None,
+            secret: None,
+            conduit_id: Some(conduit_id.into()),
+            session_id: None,
+        }
+    }
+
+    /// Send notifications to a [WebSocket session](websocket::WebsocketSession::session_id)
+    pub fn websocket(session_id: impl Into<String>) -> Transport {
+        Transport {
+            method: TransportMethod::Websocket,
+            callback: None,
+            secret: None,
+            conduit_id: None,
+            session_id: Some(session_id.into()),
+        }
+    }
 }
 
 /// Transport response on event notification
@@ -434,24 +740,41 @@ pub struct Transport {
 pub struct TransportResponse {
     /// Method for transport
     pub method: TransportMethod,
-    /// Callback
-    pub callback: String,
+    /// Callback, only for [webhooks](TransportMethod::Webhook)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback: Option<String>,
+    /// ID of the conduit notifications are sent to, only for [conduits](TransportMethod::Conduit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conduit_id: Option<String>,
+    /// ID of the WebSocket session notifications are sent to, only for [websockets](TransportMethod::Websocket)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// When the WebSocket session connected, only for [websockets](TransportMethod::Websocket)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connected_at: Option<types::Timestamp>,
+    /// When the WebSocket session disconnected, only for [websockets](TransportMethod::Websocket)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disconnected_at: Option<types::Timestamp>,
 }
 
 /// Transport method
-///
-/// Currently, only webhooks are supported
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 #[serde(rename_all = "lowercase")]
 pub enum TransportMethod {
     /// Webhook
     Webhook,
+    /// Conduit, notifications are distributed over the shards of the conduit
+    Conduit,
+    /// WebSocket, notifications are sent over a [WebSocket session](websocket)
+    Websocket,
 }
 
 /// Event name
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+// Derived (de)serialization is used for the known event types, see the impls below
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub enum EventType {
     /// `channel.update` subscription type sends notifications when a broadcaster updates the category, title, mature flag, or broadcast language for their channel.
@@ -463,6 +786,15 @@ pub enum EventType {
     /// `channel.subscribe`: a specified channel receives a subscriber. This does not include resubscribes.
     #[serde(rename = "channel.subscribe")]
     ChannelSubscribe,
+    /// `channel.subscription.end`: a subscription to the specified channel expires.
+    #[serde(rename = "channel.subscription.end")]
+    ChannelSubscriptionEnd,
+    /// `channel.subscription.gift`: a user gives one or more gifted subscriptions in a channel.
+    #[serde(rename = "channel.subscription.gift")]
+    ChannelSubscriptionGift,
+    /// `channel.subscription.message`: a user sends a resubscription chat message in a specific channel.
+    #[serde(rename = "channel.subscription.message")]
+    ChannelSubscriptionMessage,
     /// `channel.cheer`: a user cheers on the specified channel.
     #[serde(rename = "channel.cheer")]
     ChannelCheer,
@@ -499,6 +831,48 @@ pub enum EventType {
     /// `channel.hype_train.end`: a hype train ends on the specified channel.
     #[serde(rename = "channel.hype_train.end")]
     ChannelHypeTrainEnd,
+    /// `channel.poll.begin`: a poll started on the specified channel.
+    #[serde(rename = "channel.poll.begin")]
+    ChannelPollBegin,
+    /// `channel.poll.progress`: users respond to a poll on the specified channel.
+    #[serde(rename = "channel.poll.progress")]
+    ChannelPollProgress,
+    /// `channel.poll.end`: a poll ended on the specified channel.
+    #[serde(rename = "channel.poll.end")]
+    ChannelPollEnd,
+    /// `channel.prediction.begin`: a Channel Points Prediction started on the specified channel.
+    #[serde(rename = "channel.prediction.begin")]
+    ChannelPredictionBegin,
+    /// `channel.prediction.progress`: users participated in a Channel Points Prediction on the specified channel.
+    #[serde(rename = "channel.prediction.progress")]
+    ChannelPredictionProgress,
+    /// `channel.prediction.lock`: a Channel Points Prediction was locked on the specified channel.
+    #[serde(rename = "channel.prediction.lock")]
+    ChannelPredictionLock,
+    /// `channel.prediction.end`: a Channel Points Prediction ended on the specified channel.
+    #[serde(rename = "channel.prediction.end")]
+    ChannelPredictionEnd,
+    /// `channel.moderator.add`: a user is given moderator privileges on a specified channel.
+    #[serde(rename = "channel.moderator.add")]
+    ChannelModeratorAdd,
+    /// `channel.moderator.remove`: a user has moderator privileges removed on a specified channel.
+    #[serde(rename = "channel.moderator.remove")]
+    ChannelModeratorRemove,
+    /// `channel.moderate`: a moderator or bot performs a moderation action in a channel.
+    #[serde(rename = "channel.moderate")]
+    ChannelModerate,
+    /// `automod.message.hold`: a message was caught by AutoMod for review.
+    #[serde(rename = "automod.message.hold")]
+    AutomodMessageHold,
+    /// `automod.message.update`: a message in the AutoMod queue had its status changed.
+    #[serde(rename = "automod.message.update")]
+    AutomodMessageUpdate,
+    /// `channel.shield_mode.begin`: a moderator activates Shield Mode on the specified channel.
+    #[serde(rename = "channel.shield_mode.begin")]
+    ChannelShieldModeBegin,
+    /// `channel.shield_mode.end`: a moderator deactivates Shield Mode on the specified channel.
+    #[serde(rename = "channel.shield_mode.end")]
+    ChannelShieldModeEnd,
     /// `stream.online`: the specified broadcaster starts a stream.
     #[serde(rename = "stream.online")]
     StreamOnline,
@@ -511,6 +885,29 @@ pub enum EventType {
     /// `user.authorization.revoke`: a user has revoked authorization for your client id. Use this webhook to meet government requirements for handling user data, such as GDPR, LGPD, or CCPA.
     #[serde(rename = "user.authorization.revoke")]
     UserAuthorizationRevoke,
+    /// A subscription type not known by this crate.
+    #[serde(skip)]
+    Other(String),
+}
+
+impl Serialize for EventType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            EventType::Other(event_type) => serializer.serialize_str(event_type),
+            _ => EventType::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::IntoDeserializer;
+
+        let event_type = String::deserialize(deserializer)?;
+        let deserializer: serde::de::value::StrDeserializer<'_, serde::de::value::Error> =
+            event_type.as_str().into_deserializer();
+        Ok(EventType::deserialize(deserializer).unwrap_or(EventType::Other(event_type)))
+    }
 }
 
 impl std::fmt::Display for EventType {
@@ -534,21 +931,38 @@ pub enum Status {
     AuthorizationRevoked,
     /// A user in the condition of the subscription was removed.
     UserRemoved,
+    /// The conduit the subscription sent notifications to was deleted.
+    ConduitDeleted,
+    /// The client closed the connection.
+    WebsocketDisconnected,
+    /// The client failed to respond to a ping message.
+    WebsocketFailedPingPong,
+    /// The client sent a non-pong message. Clients may only send pong messages (and only in response to a ping message).
+    WebsocketReceivedInboundTraffic,
+    /// The client failed to subscribe to events within the required time.
+    WebsocketConnectionUnused,
+    /// The Twitch WebSocket server experienced an unexpected error.
+    WebsocketInternalError,
+    /// The Twitch WebSocket server timed out writing the message to the client.
+    WebsocketNetworkTimeout,
+    /// The Twitch WebSocket server experienced a network error writing the message to the client.
+    WebsocketNetworkError,
+    /// The client failed to reconnect to the Twitch WebSocket server within the required time after a Reconnect Message.
+    WebsocketFailedToReconnect,
 }
 
 /// General information about an EventSub subscription.
 ///
 /// See also [`EventSubscriptionInformation`]
-#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[derive(PartialEq, Serialize, Debug, Clone)]
 #[non_exhaustive]
 #[cfg(feature = "eventsub")]
 #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
 pub struct EventSubSubscription {
     /// How much the subscription counts against your limit.
     pub cost: usize,
-    /// JSON object specifying custom parameters for the subscription.
-    // FIXME: Should be [eventsub::Condition]
-    pub condition: serde_json::Value,
+    /// Custom parameters for the subscription, typed by [`type_`](EventSubSubscription::type_) and [`version`](EventSubSubscription::version).
+    pub condition: Condition,
     /// RFC3339 timestamp indicating when the subscription was created.
     pub created_at: types::Timestamp,
     /// ID of the subscription.
@@ -564,6 +978,208 @@ pub struct EventSubSubscription {
     pub version: String,
 }
 
+impl<'de> Deserialize<'de> for EventSubSubscription {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+        struct IEventSubSubscription {
+            cost: usize,
+            condition: serde_json::Value,
+            created_at: types::Timestamp,
+            id: types::EventSubId,
+            status: Status,
+            transport: TransportResponse,
+            #[serde(rename = "type")]
+            type_: EventType,
+            version: String,
+        }
+
+        let sub = IEventSubSubscription::deserialize(deserializer)?;
+        Ok(EventSubSubscription {
+            cost: sub.cost,
+            condition: Condition::from_value(&sub.type_, &sub.version, sub.condition),
+            created_at: sub.created_at,
+            id: sub.id,
+            status: sub.status,
+            transport: sub.transport,
+            type_: sub.type_,
+            version: sub.version,
+        })
+    }
+}
+
+/// The [condition](https://dev.twitch.tv/docs/eventsub/eventsub-reference#conditions) of a subscription, typed by its [`EventType`] and version.
+///
+/// Serializes as the condition itself. Compare with a subscription using [`From`], e.g. `Condition::from(subscription)`.
+#[derive(PartialEq, Serialize, Debug, Clone)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum Condition {
+    /// Channel Update V1 Condition
+    ChannelUpdateV1(channel::ChannelUpdateV1),
+    /// Channel Follow V1 Condition
+    ChannelFollowV1(channel::ChannelFollowV1),
+    /// Channel Subscribe V1 Condition
+    ChannelSubscribeV1(channel::ChannelSubscribeV1),
+    /// Channel Subscription End V1 Condition
+    ChannelSubscriptionEndV1(channel::ChannelSubscriptionEndV1),
+    /// Channel Subscription Gift V1 Condition
+    ChannelSubscriptionGiftV1(channel::ChannelSubscriptionGiftV1),
+    /// Channel Subscription Message V1 Condition
+    ChannelSubscriptionMessageV1(channel::ChannelSubscriptionMessageV1),
+    /// Channel Cheer V1 Condition
+    ChannelCheerV1(channel::ChannelCheerV1),
+    /// Channel Ban V1 Condition
+    ChannelBanV1(channel::ChannelBanV1),
+    /// Channel Unban V1 Condition
+    ChannelUnbanV1(channel::ChannelUnbanV1),
+    /// Channel Points Custom Reward Add V1 Condition
+    ChannelPointsCustomRewardAddV1(channel::ChannelPointsCustomRewardAddV1),
+    /// Channel Points Custom Reward Update V1 Condition
+    ChannelPointsCustomRewardUpdateV1(channel::ChannelPointsCustomRewardUpdateV1),
+    /// Channel Points Custom Reward Remove V1 Condition
+    ChannelPointsCustomRewardRemoveV1(channel::ChannelPointsCustomRewardRemoveV1),
+    /// Channel Points Custom Reward Redemption Add V1 Condition
+    ChannelPointsCustomRewardRedemptionAddV1(channel::ChannelPointsCustomRewardRedemptionAddV1),
+    /// Channel Points Custom Reward Redemption Update V1 Condition
+    ChannelPointsCustomRewardRedemptionUpdateV1(
+        channel::ChannelPointsCustomRewardRedemptionUpdateV1,
+    ),
+    /// Channel Hype Train Begin V1 Condition
+    ChannelHypeTrainBeginV1(channel::ChannelHypeTrainBeginV1),
+    /// Channel Hype Train Progress V1 Condition
+    ChannelHypeTrainProgressV1(channel::ChannelHypeTrainProgressV1),
+    /// Channel Hype Train End V1 Condition
+    ChannelHypeTrainEndV1(channel::ChannelHypeTrainEndV1),
+    /// Channel Poll Begin V1 Condition
+    ChannelPollBeginV1(channel::ChannelPollBeginV1),
+    /// Channel Poll Progress V1 Condition
+    ChannelPollProgressV1(channel::ChannelPollProgressV1),
+    /// Channel Poll End V1 Condition
+    ChannelPollEndV1(channel::ChannelPollEndV1),
+    /// Channel Prediction Begin V1 Condition
+    ChannelPredictionBeginV1(channel::ChannelPredictionBeginV1),
+    /// Channel Prediction Progress V1 Condition
+    ChannelPredictionProgressV1(channel::ChannelPredictionProgressV1),
+    /// Channel Prediction Lock V1 Condition
+    ChannelPredictionLockV1(channel::ChannelPredictionLockV1),
+    /// Channel Prediction End V1 Condition
+    ChannelPredictionEndV1(channel::ChannelPredictionEndV1),
+    /// Channel Moderator Add V1 Condition
+    ChannelModeratorAddV1(channel::ChannelModeratorAddV1),
+    /// Channel Moderator Remove V1 Condition
+    ChannelModeratorRemoveV1(channel::ChannelModeratorRemoveV1),
+    /// Channel Moderate V1 Condition
+    ChannelModerateV1(channel::ChannelModerateV1),
+    /// Automod Message Hold V1 Condition
+    AutomodMessageHoldV1(channel::AutomodMessageHoldV1),
+    /// Automod Message Update V1 Condition
+    AutomodMessageUpdateV1(channel::AutomodMessageUpdateV1),
+    /// Channel Shield Mode Begin V1 Condition
+    ChannelShieldModeBeginV1(channel::ChannelShieldModeBeginV1),
+    /// Channel Shield Mode End V1 Condition
+    ChannelShieldModeEndV1(channel::ChannelShieldModeEndV1),
+    /// StreamOnline V1 Condition
+    StreamOnlineV1(stream::StreamOnlineV1),
+    /// StreamOffline V1 Condition
+    StreamOfflineV1(stream::StreamOfflineV1),
+    /// User Update V1 Condition
+    UserUpdateV1(user::UserUpdateV1),
+    /// User Authorization Revoke V1 Condition
+    UserAuthorizationRevokeV1(user::UserAuthorizationRevokeV1),
+    /// Channel Raid V1 Condition
+    ChannelRaidV1(channel::ChannelRaidV1),
+    /// Condition for a subscription type or version not known by this crate
+    Unknown(serde_json::Value),
+}
+
+/// Implement [`Condition`] constructors for all defined eventsub types.
+macro_rules! impl_condition {
+    ($($module:ident::$event:ident);* $(;)?) => {
+        impl Condition {
+            /// Parse the condition of a subscription with the given type and version
+            ///
+            /// Conditions for unknown subscriptions, or that do not match the condition of their subscription, become [`Condition::Unknown`].
+            pub fn from_value(
+                type_: &EventType,
+                version: &str,
+                condition: serde_json::Value,
+            ) -> Condition {
+                #[deny(unreachable_patterns)]
+                match (version, type_) {
+                    $(  (<$module::$event as EventSubscription>::VERSION, &<$module::$event as EventSubscription>::EVENT_TYPE) => {
+                        <$module::$event>::deserialize(&condition)
+                            .map(Condition::$event)
+                            .unwrap_or(Condition::Unknown(condition))
+                    }  )*
+                    _ => Condition::Unknown(condition),
+                }
+            }
+
+            /// The type of the subscription, `None` if [unknown](Condition::Unknown)
+            pub fn event_type(&self) -> Option<EventType> {
+                match self {
+                    $(Condition::$event(_) => Some(<$module::$event as EventSubscription>::EVENT_TYPE),)*
+                    Condition::Unknown(_) => None,
+                }
+            }
+
+            /// The version of the subscription, `None` if [unknown](Condition::Unknown)
+            pub fn version(&self) -> Option<&'static str> {
+                match self {
+                    $(Condition::$event(_) => Some(<$module::$event as EventSubscription>::VERSION),)*
+                    Condition::Unknown(_) => None,
+                }
+            }
+        }
+
+        $(
+            impl From<$module::$event> for Condition {
+                fn from(subscription: $module::$event) -> Self { Condition::$event(subscription) }
+            }
+        )*
+    };
+}
+
+impl_condition!(
+    channel::ChannelUpdateV1;
+    channel::ChannelFollowV1;
+    channel::ChannelSubscribeV1;
+    channel::ChannelSubscriptionEndV1;
+    channel::ChannelSubscriptionGiftV1;
+    channel::ChannelSubscriptionMessageV1;
+    channel::ChannelCheerV1;
+    channel::ChannelBanV1;
+    channel::ChannelUnbanV1;
+    channel::ChannelPointsCustomRewardAddV1;
+    channel::ChannelPointsCustomRewardUpdateV1;
+    channel::ChannelPointsCustomRewardRemoveV1;
+    channel::ChannelPointsCustomRewardRedemptionAddV1;
+    channel::ChannelPointsCustomRewardRedemptionUpdateV1;
+    channel::ChannelRaidV1;
+    channel::ChannelHypeTrainBeginV1;
+    channel::ChannelHypeTrainProgressV1;
+    channel::ChannelHypeTrainEndV1;
+    channel::ChannelPollBeginV1;
+    channel::ChannelPollProgressV1;
+    channel::ChannelPollEndV1;
+    channel::ChannelPredictionBeginV1;
+    channel::ChannelPredictionProgressV1;
+    channel::ChannelPredictionLockV1;
+    channel::ChannelPredictionEndV1;
+    channel::ChannelModeratorAddV1;
+    channel::ChannelModeratorRemoveV1;
+    channel::ChannelModerateV1;
+    channel::AutomodMessageHoldV1;
+    channel::AutomodMessageUpdateV1;
+    channel::ChannelShieldModeBeginV1;
+    channel::ChannelShieldModeEndV1;
+    stream::StreamOnlineV1;
+    stream::StreamOfflineV1;
+    user::UserUpdateV1;
+    user::UserAuthorizationRevokeV1;
+);
+
 #[test]
 fn test_verification_response() {
     let body = r#"{
@@ -621,3 +1237,162 @@ fn verify_request() {
     dbg!(&body);
     assert!(crate::eventsub::Payload::verify_payload(&request, secret));
 }
+
+#[test]
+fn test_condition() {
+    let body = r#"{
+        "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+        "status": "enabled",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {
+            "broadcaster_user_id": "12826"
+        },
+        "transport": {
+            "method": "webhook",
+            "callback": "https://example.com/webhooks/callback"
+        },
+        "created_at": "2019-11-16T10:11:12.123Z"
+    }"#;
+
+    let val: EventSubSubscription = serde_json::from_str(body).unwrap();
+    assert_eq!(
+        val.condition,
+        Condition::from(
+            channel::ChannelFollowV1::builder()
+                .broadcaster_user_id("12826")
+                .build()
+        )
+    );
+    assert_eq!(val.condition.event_type(), Some(EventType::ChannelFollow));
+    crate::tests::roundtrip(&val);
+
+    let val: EventSubSubscription =
+        serde_json::from_str(&body.replace(r#""version": "1""#, r#""version": "beta""#)).unwrap();
+    assert_eq!(
+        val.condition,
+        Condition::Unknown(serde_json::json!({ "broadcaster_user_id": "12826" }))
+    );
+    crate::tests::roundtrip(&val);
+
+    let val: EventSubSubscription = serde_json::from_str(&body.replace(
+        r#""broadcaster_user_id": "12826""#,
+        r#""broadcaster_id": "12826""#,
+    ))
+    .unwrap();
+    assert_eq!(
+        val.condition,
+        Condition::Unknown(serde_json::json!({ "broadcaster_id": "12826" }))
+    );
+    crate::tests::roundtrip(&val);
+}
+
+#[test]
+fn test_unknown_payload() {
+    let body = r#"{
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "type": "channel.some_new_event",
+            "version": "1",
+            "status": "enabled",
+            "cost": 0,
+            "condition": {
+                "broadcaster_user_id": "1337"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        },
+        "event": {
+            "broadcaster_user_id": "1337",
+            "something_new": true
+        }
+    }"#;
+
+    let val = dbg!(Payload::parse(body).unwrap());
+    match &val {
+        Payload::Unknown {
+            subscription,
+            event,
+        } => {
+            assert_eq!(
+                subscription.type_,
+                EventType::Other("channel.some_new_event".to_string())
+            );
+            assert_eq!(event["something_new"], true);
+        }
+        _ => panic!("expected unknown payload"),
+    }
+    crate::tests::roundtrip(&val);
+    assert_eq!(
+        serde_json::to_string(&EventType::Other("channel.some_new_event".to_string())).unwrap(),
+        r#""channel.some_new_event""#
+    );
+
+    // Known event type, unknown version
+    let body = body
+        .replace("channel.some_new_event", "channel.follow")
+        .replace(r#""version": "1""#, r#""version": "beta""#);
+    let val = dbg!(Payload::parse(&body).unwrap());
+    assert!(matches!(val, Payload::Unknown { .. }));
+    crate::tests::roundtrip(&val);
+}
+
+#[test]
+fn test_revocation() {
+    let body = r#"{
+        "subscription": {
+            "id": "f1c2a387-161a-49f9-a165-0f21d7a4e1c4",
+            "status": "authorization_revoked",
+            "type": "channel.follow",
+            "cost": 1,
+            "version": "1",
+            "condition": {
+                "broadcaster_user_id": "12826"
+            },
+            "transport": {
+                "method": "webhook",
+                "callback": "https://example.com/webhooks/callback"
+            },
+            "created_at": "2019-11-16T10:11:12.123Z"
+        }
+    }"#;
+
+    let request = http::Request::builder()
+        .header(
+            "Twitch-Eventsub-Message-Id",
+            "e76c6bd4-55c9-4987-8304-da1588d8988b",
+        )
+        .header("Twitch-Eventsub-Message-Retry", "0")
+        .header("Twitch-Eventsub-Message-Type", "revocation")
+        .header(
+            "Twitch-Eventsub-Message-Timestamp",
+            "2019-11-16T10:11:12.123Z",
+        )
+        .header("Twitch-Eventsub-Subscription-Type", "channel.follow")
+        .header("Twitch-Eventsub-Subscription-Version", "1")
+        .body(body.as_bytes().to_vec())
+        .unwrap();
+
+    let headers = WebhookHeaders::from_headers(request.headers()).unwrap();
+    assert_eq!(headers.message_type, WebhookMessageType::Revocation);
+    assert_eq!(headers.message_retry, Some(0));
+    assert_eq!(headers.subscription_type, Some(EventType::ChannelFollow));
+
+    let val = dbg!(Payload::parse_http(&request).unwrap());
+    match &val {
+        Payload::Revocation(revocation) => {
+            assert_eq!(revocation.reason(), &Status::AuthorizationRevoked)
+        }
+        _ => panic!("expected revocation"),
+    }
+    assert_eq!(Payload::parse(body).unwrap(), val);
+    assert!(matches!(
+        Payload::parse_message(&WebhookMessageType::Notification, body),
+        Err(PayloadParseError::UnexpectedPayload(_))
+    ));
+    crate::tests::roundtrip(&val)
+}
@@ -0,0 +1,364 @@
+//! Verification of EventSub webhook messages, with replay protection and deduplication
+//!
+//! [`Payload::verify_payload`] only checks the signature of a message. A captured request with a valid signature can be sent again,
+//! and Twitch may deliver the same message more than once. [`Verifier`] also rejects messages that are too old and
+//! messages that have already been processed.
+//!
+//! A [valid](Verdict::Valid) message is claimed, until it is [processed](Verifier::mark_processed) or [released](Verifier::release)
+//! the same message is a [duplicate](Verdict::Duplicate), also when it is delivered again while still being handled.
+//! If handling fails, release the message so that Twitch's retry is valid again.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use twitch_api2::eventsub::{verifier::{Verdict, Verifier}, Payload};
+//! # let request = http::Request::new(vec![]);
+//! let verifier = Verifier::new("secretabcd");
+//!
+//! match verifier.verify(&request) {
+//!     Verdict::Valid => match Payload::parse_http(&request) {
+//!         Ok(payload) => {
+//!             // handle the payload
+//!             verifier.mark_processed(&request);
+//!         }
+//!         Err(_) => verifier.release(&request),
+//!     },
+//!     // Twitch already got a 2xx response for this message, respond with 2xx again so it stops retrying
+//!     Verdict::Duplicate => {}
+//!     // Reject everything else
+//!     _ => {}
+//! }
+//! ```
+
+use super::{Payload, WebhookHeaders};
+use crate::types::{self, parse_timestamp};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+/// Default maximum age of a message, 10 minutes as recommended by Twitch
+pub const DEFAULT_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+/// Result of [verifying](Verifier::verify) a message
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Verdict {
+    /// The message is authentic, recent and has not been processed before.
+    Valid,
+    /// The message is authentic and recent, but has already been [processed](Verifier::mark_processed) or is being handled.
+    ///
+    /// This is either a retry from Twitch or a replayed request.
+    Duplicate,
+    /// The message is authentic, but older than the [window](Verifier::window).
+    Expired {
+        /// Age of the message
+        age: Duration,
+    },
+    /// The `Twitch-Eventsub-Message-Signature` header is missing or does not match the message.
+    InvalidSignature,
+    /// A required header is missing or could not be parsed.
+    InvalidHeaders,
+}
+
+impl Verdict {
+    /// Whether the message should be handled
+    pub fn is_valid(&self) -> bool { matches!(self, Verdict::Valid) }
+}
+
+/// Store of message IDs that have been processed or are being handled
+///
+/// Implement this to share processed messages between processes, e.g. in a database.
+pub trait MessageIdStore: Send + Sync {
+    /// Whether `message_id` has been recorded
+    fn contains(&self, message_id: &str) -> bool;
+
+    /// Claim `message_id` for handling, returns `false` if it has been recorded or is already claimed.
+    ///
+    /// Checking and claiming must be atomic, so that a message delivered twice at the same time is only claimed once.
+    fn try_claim(&self, message_id: &str) -> bool;
+
+    /// Release the claim on `message_id` without recording it
+    fn release(&self, message_id: &str);
+
+    /// Record `message_id` and release its claim, returns `true` if it was recorded before.
+    fn insert(&self, message_id: &str) -> bool;
+}
+
+/// In-memory [`MessageIdStore`] remembering the most recently recorded message IDs
+#[derive(Debug)]
+pub struct LruMessageIdStore {
+    capacity: usize,
+    inner: Mutex<LruInner>,
+}
+
+#[derive(Debug, Default)]
+struct LruInner {
+    counter: u64,
+    ids: HashMap<String, u64>,
+    order: BTreeMap<u64, String>,
+    claimed: HashSet<String>,
+}
+
+impl LruMessageIdStore {
+    /// Default amount of message IDs to remember
+    pub const DEFAULT_CAPACITY: usize = 10_000;
+
+    /// Create a store remembering at most `capacity` message IDs
+    pub fn new(capacity: usize) -> LruMessageIdStore {
+        LruMessageIdStore {
+            capacity,
+            inner: Mutex::default(),
+        }
+    }
+}
+
+impl Default for LruMessageIdStore {
+    fn default() -> Self { LruMessageIdStore::new(LruMessageIdStore::DEFAULT_CAPACITY) }
+}
+
+impl MessageIdStore for LruMessageIdStore {
+    fn contains(&self, message_id: &str) -> bool {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.ids.contains_key(message_id)
+    }
+
+    fn try_claim(&self, message_id: &str) -> bool {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        !inner.ids.contains_key(message_id) && inner.claimed.insert(message_id.to_string())
+    }
+
+    fn release(&self, message_id: &str) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.claimed.remove(message_id);
+    }
+
+    fn insert(&self, message_id: &str) -> bool {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let LruInner {
+            counter,
+            ids,
+            order,
+            claimed,
+        } = &mut *inner;
+        claimed.remove(message_id);
+        *counter += 1;
+        if let Some(last) = ids.get_mut(message_id) {
+            order.remove(last);
+            *last = *counter;
+            order.insert(*counter, message_id.to_string());
+            return true;
+        }
+        ids.insert(message_id.to_string(), *counter);
+        order.insert(*counter, message_id.to_string());
+        while ids.len() > self.capacity {
+            let oldest = *order.keys().next().expect("order should not be empty");
+            if let Some(id) = order.remove(&oldest) {
+                ids.remove(&id);
+            }
+        }
+        false
+    }
+}
+
+/// Verifies webhook messages for authenticity, age and duplicates
+#[derive(Debug)]
+pub struct Verifier<S: MessageIdStore = LruMessageIdStore> {
+    secret: types::Secret,
+    window: Duration,
+    store: S,
+}
+
+impl Verifier {
+    /// Create a verifier for messages signed with `secret`, using an in-memory store of processed messages
+    pub fn new(secret: impl Into<String>) -> Verifier {
+        Verifier::with_store(secret, LruMessageIdStore::default())
+    }
+}
+
+impl<S: MessageIdStore> Verifier<S> {
+    /// Create a verifier for messages signed with `secret`, using `store` to remember processed messages
+    pub fn with_store(secret: impl Into<String>, store: S) -> Verifier<S> {
+        Verifier {
+            secret: types::Secret::new(secret.into()),
+            window: DEFAULT_WINDOW,
+            store,
+        }
+    }
+
+    /// Set the maximum age of a message, defaults to [10 minutes](DEFAULT_WINDOW)
+    pub fn window(mut self, window: Duration) -> Verifier<S> {
+        self.window = window;
+        self
+    }
+
+    /// The store of processed messages
+    pub fn store(&self) -> &S { &self.store }
+
+    /// Verify a request
+    ///
+    /// A [valid](Verdict::Valid) message is claimed, call [`mark_processed`](Verifier::mark_processed) once it has been handled
+    /// or [`release`](Verifier::release) if handling failed.
+    pub fn verify(&self, request: &http::Request<Vec<u8>>) -> Verdict {
+        self.verify_at(request, SystemTime::now())
+    }
+
+    /// Verify a request received at `now`
+    pub fn verify_at(&self, request: &http::Request<Vec<u8>>, now: SystemTime) -> Verdict {
+        let headers = match WebhookHeaders::from_headers(request.headers()) {
+            Ok(headers) => headers,
+            Err(_) => return Verdict::InvalidHeaders,
+        };
+        if !Payload::verify_payload(request, self.secret.secret().as_bytes()) {
+            return Verdict::InvalidSignature;
+        }
+        let sent = match parse_timestamp(&headers.message_timestamp) {
+            Some(sent) => sent,
+            None => return Verdict::InvalidHeaders,
+        };
+        // Messages from the future are let through, the signature guarantees they are from Twitch
+        let age = now.duration_since(sent).unwrap_or_default();
+        if age > self.window {
+            return Verdict::Expired { age };
+        }
+        if !self.store.try_claim(&headers.message_id) {
+            return Verdict::Duplicate;
+        }
+        Verdict::Valid
+    }
+
+    /// Record the message in a request as processed, so that it is a [duplicate](Verdict::Duplicate) from now on
+    ///
+    /// Call this after successfully handling a [valid](Verdict::Valid) message.
+    pub fn mark_processed(&self, request: &http::Request<Vec<u8>>) {
+        if let Ok(headers) = WebhookHeaders::from_headers(request.headers()) {
+            self.store.insert(&headers.message_id);
+        }
+    }
+
+    /// Release the claim on the message in a request, so that it is [valid](Verdict::Valid) again
+    ///
+    /// Call this when handling a [valid](Verdict::Valid) message failed.
+    pub fn release(&self, request: &http::Request<Vec<u8>>) {
+        if let Ok(headers) = WebhookHeaders::from_headers(request.headers()) {
+            self.store.release(&headers.message_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> http::Request<Vec<u8>> {
+        let body = r#"{"subscription":{"id":"ae2ff348-e102-16be-a3eb-6830c1bf38d2","status":"enabled","type":"channel.follow","version":"1","condition":{"broadcaster_user_id":"44429626"},"transport":{"method":"webhook","callback":"null"},"created_at":"2021-02-19T23:47:00.7621315Z"},"event":{"user_id":"28408015","user_login":"testFromUser","user_name":"testFromUser","broadcaster_user_id":"44429626","broadcaster_user_login":"44429626","broadcaster_user_name":"testBroadcaster"}}"#;
+        http::Request::builder()
+            .header(
+                "Twitch-Eventsub-Message-Id",
+                "ae2ff348-e102-16be-a3eb-6830c1bf38d2",
+            )
+            .header("Twitch-Eventsub-Message-Retry", "0")
+            .header(
+                "Twitch-Eventsub-Message-Signature",
+                "sha256=d10f5bd9474b7ac7bd7105eb79c2d52768b4d0cd2a135982c3bf5a1d59a78823",
+            )
+            .header(
+                "Twitch-Eventsub-Message-Timestamp",
+                "2021-02-19T23:47:00.8091512Z",
+            )
+            .header("Twitch-Eventsub-Message-Type", "notification")
+            .header("Twitch-Eventsub-Subscription-Type", "channel.follow")
+            .header("Twitch-Eventsub-Subscription-Version", "1")
+            .body(body.as_bytes().to_vec())
+            .unwrap()
+    }
+
+    #[test]
+    fn verify() {
+        let sent = parse_timestamp("2021-02-19T23:47:00.8091512Z").unwrap();
+        let verifier = Verifier::new("secretabcd");
+
+        assert_eq!(
+            verifier.verify_at(&request(), sent + Duration::from_secs(60)),
+            Verdict::Valid
+        );
+        // Still being handled
+        assert_eq!(
+            verifier.verify_at(&request(), sent + Duration::from_secs(60)),
+            Verdict::Duplicate
+        );
+        // Handling failed
+        verifier.release(&request());
+        assert_eq!(
+            verifier.verify_at(&request(), sent + Duration::from_secs(60)),
+            Verdict::Valid
+        );
+        verifier.mark_processed(&request());
+        assert_eq!(
+            verifier.verify_at(&request(), sent + Duration::from_secs(120)),
+            Verdict::Duplicate
+        );
+        assert_eq!(
+            Verifier::new("secretabcd").verify_at(&request(), sent + Duration::from_secs(11 * 60)),
+            Verdict::Expired {
+                age: Duration::from_secs(11 * 60)
+            }
+        );
+        assert!(Verifier::new("secretabcd")
+            .window(Duration::from_secs(20 * 60))
+            .verify_at(&request(), sent + Duration::from_secs(11 * 60))
+            .is_valid());
+        assert_eq!(
+            Verifier::new("wrongsecret").verify_at(&request(), sent),
+            Verdict::InvalidSignature
+        );
+
+        let mut request = request();
+        request.headers_mut().remove("Twitch-Eventsub-Message-Id");
+        assert_eq!(verifier.verify_at(&request, sent), Verdict::InvalidHeaders);
+    }
+
+    #[test]
+    fn verify_concurrently() {
+        let sent = parse_timestamp("2021-02-19T23:47:00.8091512Z").unwrap();
+        let verifier = std::sync::Arc::new(Verifier::new("secretabcd"));
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let verifier = verifier.clone();
+                std::thread::spawn(move || {
+                    verifier.verify_at(&request(), sent + Duration::from_secs(60))
+                })
+            })
+            .collect();
+        let verdicts: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+        assert_eq!(verdicts.iter().filter(|v| v.is_valid()).count(), 1);
+        assert_eq!(
+            verdicts
+                .iter()
+                .filter(|v| **v == Verdict::Duplicate)
+                .count(),
+            7
+        );
+    }
+
+    #[test]
+    fn lru_store() {
+        let store = LruMessageIdStore::new(2);
+        assert!(!store.contains("a"));
+        assert!(!store.insert("a"));
+        assert!(store.contains("a"));
+        assert!(!store.insert("b"));
+        assert!(store.insert("a"));
+        // "b" is the least recently seen
+        assert!(!store.insert("c"));
+        assert!(store.insert("a"));
+        assert!(!store.insert("b"));
+
+        assert!(store.try_claim("d"));
+        assert!(!store.try_claim("d"));
+        store.release("d");
+        assert!(store.try_claim("d"));
+        assert!(!store.insert("d"));
+        assert!(!store.try_claim("d"));
+    }
+}
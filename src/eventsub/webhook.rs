@@ -0,0 +1,548 @@
+//! Ready-made handlers for EventSub webhooks
+//!
+//! [`WebhookHandler`] takes care of everything a webhook callback needs to do: [verify](super::verifier) the message,
+//! answer [challenges](super::VerificationRequest::challenge), respond quickly with a `2xx` status and hand the [`Payload`] to your application.
+//!
+//! The handler itself works on [`http::Request`]s, adapters for web frameworks are enabled with their own features
+//!
+//! | Feature | Adapter |
+//! | -------: | :------- |
+//! | `hyper_webhook` | [`WebhookHandler::handle_hyper`] |
+//! | `axum_webhook` | [`WebhookHandler::axum_route`] |
+//! | `warp_webhook` | [`WebhookHandler::warp_filter`] |
+//! | `actix_webhook` | [`WebhookHandler::handle_actix`] |
+//!
+//! # Example
+//!
+//! ```rust
+//! use twitch_api2::eventsub::{verifier::Verifier, webhook::WebhookHandler, Payload};
+//!
+//! let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<Payload>();
+//! let handler = WebhookHandler::new(Verifier::new("secretabcd"), move |payload| {
+//!     // Twitch retries the message if the receiver is gone
+//!     sender.send(payload)?;
+//!     Ok(())
+//! });
+//! # let request = http::Request::new(vec![]);
+//! // For every request to the callback
+//! let response = handler.handle(&request);
+//! ```
+
+use super::{
+    verifier::{LruMessageIdStore, MessageIdStore, Verdict, Verifier},
+    Payload,
+};
+use std::sync::Arc;
+
+/// Maximum size of a request body, larger requests are rejected with `413 Payload Too Large`
+pub const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// Error returned by the callback of a [`WebhookHandler`]
+pub type CallbackError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Handler for EventSub webhook requests
+///
+/// Every authentic message is passed to the callback once, duplicates are acknowledged but not passed on.
+/// Cloning the handler is cheap, clones share the callback and the store of seen messages.
+pub struct WebhookHandler<S: MessageIdStore = LruMessageIdStore> {
+    inner: Arc<Inner<S>>,
+}
+
+struct Inner<S: MessageIdStore> {
+    verifier: Verifier<S>,
+    callback: Box<dyn Fn(Payload) -> Result<(), CallbackError> + Send + Sync>,
+}
+
+impl<S: MessageIdStore> Clone for WebhookHandler<S> {
+    fn clone(&self) -> Self {
+        WebhookHandler {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<S: MessageIdStore + std::fmt::Debug> std::fmt::Debug for WebhookHandler<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookHandler")
+            .field("verifier", &self.inner.verifier)
+            .finish()
+    }
+}
+
+impl<S: MessageIdStore + 'static> WebhookHandler<S> {
+    /// Create a handler verifying messages with `verifier` and passing them to `callback`
+    ///
+    /// The callback is called before the response is sent, it should not block. Send the payload to a channel or spawn a task to do any real work.
+    /// If the callback returns an error, Twitch is asked to retry the message.
+    pub fn new(
+        verifier: Verifier<S>,
+        callback: impl Fn(Payload) -> Result<(), CallbackError> + Send + Sync + 'static,
+    ) -> WebhookHandler<S> {
+        WebhookHandler {
+            inner: Arc::new(Inner {
+                verifier,
+                callback: Box::new(callback),
+            }),
+        }
+    }
+
+    /// The verifier used by this handler
+    pub fn verifier(&self) -> &Verifier<S> { &self.inner.verifier }
+
+    /// Handle a request to the webhook callback
+    ///
+    /// | Request | Response |
+    /// | ------- | -------- |
+    /// | Not a `POST` | `405 Method Not Allowed` |
+    /// | Body larger than [`MAX_BODY_SIZE`] | `413 Payload Too Large` |
+    /// | Missing or invalid headers, or a body that is not a [`Payload`] | `400 Bad Request` |
+    /// | Invalid signature or [expired](Verdict::Expired) | `403 Forbidden` |
+    /// | [Verification request](Payload::VerificationRequest) | `200 OK` with the challenge as body |
+    /// | Notification or revocation | `204 No Content` |
+    /// | The callback returned an error | `500 Internal Server Error` |
+    ///
+    /// A message is only [recorded as processed](Verifier::mark_processed) after the callback handled it.
+    /// While it is being handled, the same message is acknowledged as a duplicate. If it could not be parsed or the callback failed,
+    /// it is [released](Verifier::release) so that Twitch's retry is passed on.
+    pub fn handle(&self, request: &http::Request<Vec<u8>>) -> http::Response<Vec<u8>> {
+        if request.method() != http::Method::POST {
+            return response(http::StatusCode::METHOD_NOT_ALLOWED, vec![]);
+        }
+        if request.body().len() > MAX_BODY_SIZE {
+            return response(http::StatusCode::PAYLOAD_TOO_LARGE, vec![]);
+        }
+        let dispatch = match self.inner.verifier.verify(request) {
+            Verdict::Valid => true,
+            // Already handled, but still answer challenges in case our first response was lost
+            Verdict::Duplicate => false,
+            Verdict::InvalidHeaders => return response(http::StatusCode::BAD_REQUEST, vec![]),
+            _ => return response(http::StatusCode::FORBIDDEN, vec![]),
+        };
+        let payload = match Payload::parse_http(request) {
+            Ok(payload) => payload,
+            Err(_) => {
+                if dispatch {
+                    self.inner.verifier.release(request);
+                }
+                return response(http::StatusCode::BAD_REQUEST, vec![]);
+            }
+        };
+        let challenge = match &payload {
+            Payload::VerificationRequest(verification) => Some(verification.challenge.clone()),
+            _ => None,
+        };
+        if dispatch {
+            if (self.inner.callback)(payload).is_err() {
+                self.inner.verifier.release(request);
+                return response(http::StatusCode::INTERNAL_SERVER_ERROR, vec![]);
+            }
+            self.inner.verifier.mark_processed(request);
+        }
+        match challenge {
+            Some(challenge) => {
+                let mut response = response(http::StatusCode::OK, challenge.into_bytes());
+                response.headers_mut().insert(
+                    http::header::CONTENT_TYPE,
+                    http::HeaderValue::from_static("text/plain"),
+                );
+                response
+            }
+            None => response(http::StatusCode::NO_CONTENT, vec![]),
+        }
+    }
+}
+
+#[cfg(feature = "hyper_webhook")]
+#[cfg_attr(nightly, doc(cfg(feature = "hyper_webhook")))]
+impl<S: MessageIdStore + 'static> WebhookHandler<S> {
+    /// Handle a [`hyper`] request, see [`handle`](WebhookHandler::handle)
+    ///
+    /// Reading the body stops once it is larger than [`MAX_BODY_SIZE`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use twitch_api2::eventsub::{verifier::Verifier, webhook::WebhookHandler};
+    /// # let handler = WebhookHandler::new(Verifier::new("secretabcd"), |_| Ok(()));
+    /// let service = hyper::service::service_fn(move |request| {
+    ///     let handler = handler.clone();
+    ///     async move { handler.handle_hyper(request).await }
+    /// });
+    /// ```
+    pub async fn handle_hyper(
+        &self,
+        request: hyper::Request<hyper::Body>,
+    ) -> Result<hyper::Response<hyper::Body>, hyper::Error> {
+        use hyper::body::HttpBody;
+
+        let (parts, mut body) = request.into_parts();
+        let mut bytes = Vec::new();
+        while let Some(chunk) = body.data().await {
+            let chunk = chunk?;
+            if bytes.len() + chunk.len() > MAX_BODY_SIZE {
+                return Ok(
+                    response(http::StatusCode::PAYLOAD_TOO_LARGE, vec![]).map(hyper::Body::from)
+                );
+            }
+            bytes.extend_from_slice(&chunk);
+        }
+        Ok(self
+            .handle(&http::Request::from_parts(parts, bytes))
+            .map(hyper::Body::from))
+    }
+}
+
+#[cfg(feature = "axum_webhook")]
+#[cfg_attr(nightly, doc(cfg(feature = "axum_webhook")))]
+impl<S: MessageIdStore + 'static> WebhookHandler<S> {
+    /// Create an [`axum`] route handling `POST` requests, see [`handle`](WebhookHandler::handle)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use twitch_api2::eventsub::{verifier::Verifier, webhook::WebhookHandler};
+    /// # let handler = WebhookHandler::new(Verifier::new("secretabcd"), |_| Ok(()));
+    /// let app: axum::Router = axum::Router::new().route("/eventsub", handler.axum_route());
+    /// ```
+    pub fn axum_route<St>(&self) -> axum::routing::MethodRouter<St>
+    where St: Clone + Send + Sync + 'static {
+        use axum::response::IntoResponse;
+
+        let handler = self.clone();
+        axum::routing::post(
+            move |headers: http::HeaderMap, body: axum::body::Bytes| async move {
+                let (parts, body) = handler
+                    .handle(&request(headers, body.to_vec()))
+                    .into_parts();
+                (parts, body).into_response()
+            },
+        )
+    }
+}
+
+#[cfg(feature = "warp_webhook")]
+#[cfg_attr(nightly, doc(cfg(feature = "warp_webhook")))]
+impl<S: MessageIdStore + 'static> WebhookHandler<S> {
+    /// Create a [`warp`] filter handling `POST` requests, see [`handle`](WebhookHandler::handle)
+    ///
+    /// Requests without a `Content-Length` header or with a body larger than [`MAX_BODY_SIZE`] are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use twitch_api2::eventsub::{verifier::Verifier, webhook::WebhookHandler};
+    /// use warp::Filter;
+    /// # let handler = WebhookHandler::new(Verifier::new("secretabcd"), |_| Ok(()));
+    /// let routes = warp::path("eventsub").and(handler.warp_filter());
+    /// ```
+    pub fn warp_filter(
+        &self,
+    ) -> impl warp::Filter<Extract = (http::Response<Vec<u8>>,), Error = warp::Rejection> + Clone
+    {
+        use warp::Filter;
+
+        let handler = self.clone();
+        warp::post()
+            .and(warp::header::headers_cloned())
+            .and(warp::body::content_length_limit(MAX_BODY_SIZE as u64))
+            .and(warp::body::bytes())
+            .map(move |headers, body: warp::hyper::body::Bytes| {
+                handler.handle(&request(headers, body.to_vec()))
+            })
+    }
+}
+
+#[cfg(feature = "actix_webhook")]
+#[cfg_attr(nightly, doc(cfg(feature = "actix_webhook")))]
+impl<S: MessageIdStore + 'static> WebhookHandler<S> {
+    /// Handle an [`actix_web`] request, see [`handle`](WebhookHandler::handle)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use twitch_api2::eventsub::{verifier::Verifier, webhook::WebhookHandler};
+    /// use actix_web::{web, App, HttpRequest};
+    /// # let handler = WebhookHandler::new(Verifier::new("secretabcd"), |_| Ok(()));
+    /// let app = App::new().route(
+    ///     "/eventsub",
+    ///     web::post().to(move |request: HttpRequest, body: web::Bytes| {
+    ///         let handler = handler.clone();
+    ///         async move { handler.handle_actix(&request, &body) }
+    ///     }),
+    /// );
+    /// ```
+    pub fn handle_actix(
+        &self,
+        request: &actix_web::HttpRequest,
+        body: &[u8],
+    ) -> actix_web::HttpResponse {
+        let mut req = http::Request::new(body.to_vec());
+        *req.method_mut() = request.method().clone();
+        for (name, value) in request.headers() {
+            req.headers_mut().append(name.clone(), value.clone());
+        }
+        let (parts, body) = self.handle(&req).into_parts();
+        let mut response = actix_web::HttpResponse::build(parts.status);
+        for (name, value) in &parts.headers {
+            response.insert_header((name.clone(), value.clone()));
+        }
+        response.body(body)
+    }
+}
+
+fn response(status: http::StatusCode, body: Vec<u8>) -> http::Response<Vec<u8>> {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = status;
+    response
+}
+
+/// Build a `POST` request from the parts that frameworks extract for us
+#[cfg(any(feature = "axum_webhook", feature = "warp_webhook"))]
+fn request(headers: http::HeaderMap, body: Vec<u8>) -> http::Request<Vec<u8>> {
+    let mut request = http::Request::new(body);
+    *request.method_mut() = http::Method::POST;
+    *request.headers_mut() = headers;
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    const NOTIFICATION: &str = r#"{"subscription":{"id":"ae2ff348-e102-16be-a3eb-6830c1bf38d2","status":"enabled","type":"channel.follow","version":"1","cost":0,"condition":{"broadcaster_user_id":"44429626"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2021-02-19T23:47:00.7621315Z"},"event":{"user_id":"28408015","user_login":"testFromUser","user_name":"testFromUser","broadcaster_user_id":"44429626","broadcaster_user_login":"44429626","broadcaster_user_name":"testBroadcaster","followed_at":"2021-02-19T23:47:00.7621315Z"}}"#;
+    const VERIFICATION: &str = r#"{"challenge":"pogchamp-kappa-360noscope-vohiyo","subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","status":"webhook_callback_verification_pending","type":"channel.follow","version":"1","cost":1,"condition":{"broadcaster_user_id":"12826"},"transport":{"method":"webhook","callback":"https://example.com/webhooks/callback"},"created_at":"2019-11-16T10:11:12.123Z"}}"#;
+
+    /// A signed request, with a timestamp far in the past
+    fn signed_request(message_id: &str, message_type: &str, body: &str) -> http::Request<Vec<u8>> {
+        use crypto_hmac::{Hmac, Mac, NewMac};
+
+        let timestamp = "2021-02-19T23:47:00.8091512Z";
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(b"secretabcd").unwrap();
+        mac.update(message_id.as_bytes());
+        mac.update(timestamp.as_bytes());
+        mac.update(body.as_bytes());
+        let signature: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        http::Request::builder()
+            .method("POST")
+            .header("Twitch-Eventsub-Message-Id", message_id)
+            .header("Twitch-Eventsub-Message-Retry", "0")
+            .header(
+                "Twitch-Eventsub-Message-Signature",
+                format!("sha256={}", signature),
+            )
+            .header("Twitch-Eventsub-Message-Timestamp", timestamp)
+            .header("Twitch-Eventsub-Message-Type", message_type)
+            .header("Twitch-Eventsub-Subscription-Type", "channel.follow")
+            .header("Twitch-Eventsub-Subscription-Version", "1")
+            .body(body.as_bytes().to_vec())
+            .unwrap()
+    }
+
+    fn handler() -> (WebhookHandler, Arc<Mutex<Vec<Payload>>>) {
+        let received = Arc::new(Mutex::new(vec![]));
+        let handler = WebhookHandler::new(
+            Verifier::new("secretabcd").window(Duration::from_secs(u64::MAX)),
+            {
+                let received = received.clone();
+                move |payload| {
+                    received.lock().unwrap().push(payload);
+                    Ok(())
+                }
+            },
+        );
+        (handler, received)
+    }
+
+    #[test]
+    fn handle() {
+        let (handler, received) = handler();
+
+        let response = handler.handle(&signed_request(
+            "1",
+            "webhook_callback_verification",
+            VERIFICATION,
+        ));
+        assert_eq!(response.status(), http::StatusCode::OK);
+        assert_eq!(response.body(), b"pogchamp-kappa-360noscope-vohiyo");
+
+        let response = handler.handle(&signed_request("2", "notification", NOTIFICATION));
+        assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+        // Redelivery is acknowledged, but not passed on
+        let response = handler.handle(&signed_request("2", "notification", NOTIFICATION));
+        assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+
+        let mut tampered = signed_request("3", "notification", NOTIFICATION);
+        tampered.body_mut().push(b' ');
+        assert_eq!(
+            handler.handle(&tampered).status(),
+            http::StatusCode::FORBIDDEN
+        );
+
+        let mut get = signed_request("4", "notification", NOTIFICATION);
+        *get.method_mut() = http::Method::GET;
+        assert_eq!(
+            handler.handle(&get).status(),
+            http::StatusCode::METHOD_NOT_ALLOWED
+        );
+
+        let large = " ".repeat(MAX_BODY_SIZE + 1);
+        assert_eq!(
+            handler
+                .handle(&signed_request("5", "notification", &large))
+                .status(),
+            http::StatusCode::PAYLOAD_TOO_LARGE
+        );
+
+        // A message that could not be parsed is not recorded, a retry is passed on
+        assert_eq!(
+            handler
+                .handle(&signed_request("6", "notification", "{}"))
+                .status(),
+            http::StatusCode::BAD_REQUEST
+        );
+        let response = handler.handle(&signed_request("6", "notification", NOTIFICATION));
+        assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 3);
+        assert!(matches!(received[0], Payload::VerificationRequest(_)));
+        assert!(matches!(received[1], Payload::ChannelFollowV1(_)));
+        assert!(matches!(received[2], Payload::ChannelFollowV1(_)));
+    }
+
+    #[test]
+    fn callback_error() {
+        let calls = Arc::new(Mutex::new(0));
+        let handler = WebhookHandler::new(
+            Verifier::new("secretabcd").window(Duration::from_secs(u64::MAX)),
+            {
+                let calls = calls.clone();
+                move |_| {
+                    let mut calls = calls.lock().unwrap();
+                    *calls += 1;
+                    if *calls == 1 {
+                        return Err("receiver is gone".into());
+                    }
+                    Ok(())
+                }
+            },
+        );
+
+        let request = signed_request("1", "notification", NOTIFICATION);
+        assert_eq!(
+            handler.handle(&request).status(),
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+        // The retry is passed on again, then acknowledged as a duplicate
+        assert_eq!(
+            handler.handle(&request).status(),
+            http::StatusCode::NO_CONTENT
+        );
+        assert_eq!(
+            handler.handle(&request).status(),
+            http::StatusCode::NO_CONTENT
+        );
+        assert_eq!(*calls.lock().unwrap(), 2);
+    }
+
+    #[cfg(feature = "hyper_webhook")]
+    #[tokio::test]
+    async fn hyper() {
+        let (handler, received) = handler();
+        let response = handler
+            .handle_hyper(signed_request("1", "notification", NOTIFICATION).map(hyper::Body::from))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+        assert_eq!(received.lock().unwrap().len(), 1);
+
+        let large = " ".repeat(MAX_BODY_SIZE + 1);
+        let response = handler
+            .handle_hyper(signed_request("2", "notification", &large).map(hyper::Body::from))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[cfg(feature = "axum_webhook")]
+    #[tokio::test]
+    async fn axum() {
+        use tower::ServiceExt;
+
+        let (handler, received) = handler();
+        let app = axum::Router::new().route("/eventsub", handler.axum_route());
+        let request = signed_request("1", "webhook_callback_verification", VERIFICATION);
+        let (mut parts, body) = request.into_parts();
+        parts.uri = "/eventsub".parse().unwrap();
+        let response = app
+            .oneshot(http::Request::from_parts(
+                parts,
+                axum::body::Body::from(body),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(&body[..], b"pogchamp-kappa-360noscope-vohiyo");
+        assert_eq!(received.lock().unwrap().len(), 1);
+    }
+
+    #[cfg(feature = "warp_webhook")]
+    #[tokio::test]
+    async fn warp() {
+        let (handler, received) = handler();
+        let request = signed_request("1", "notification", NOTIFICATION);
+        let mut test = warp::test::request().method("POST").path("/");
+        for (name, value) in request.headers() {
+            test = test.header(name, value);
+        }
+        let response = test
+            .body(request.body())
+            .reply(&handler.warp_filter())
+            .await;
+        assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+        assert_eq!(received.lock().unwrap().len(), 1);
+
+        let response = warp::test::request()
+            .method("POST")
+            .path("/")
+            .body(" ".repeat(MAX_BODY_SIZE + 1))
+            .reply(&handler.warp_filter())
+            .await;
+        assert_eq!(response.status(), http::StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[cfg(feature = "actix_webhook")]
+    #[test]
+    fn actix() {
+        use actix_web::{test, web, App, HttpRequest};
+
+        let (handler, received) = handler();
+        actix_web::rt::System::new().block_on(async move {
+            let app = test::init_service(App::new().route(
+                "/eventsub",
+                web::post().to(move |request: HttpRequest, body: web::Bytes| {
+                    let handler = handler.clone();
+                    async move { handler.handle_actix(&request, &body) }
+                }),
+            ))
+            .await;
+            let request = signed_request("1", "notification", NOTIFICATION);
+            let mut test = test::TestRequest::post().uri("/eventsub");
+            for (name, value) in request.headers() {
+                test = test.insert_header((name.clone(), value.clone()));
+            }
+            let response =
+                test::call_service(&app, test.set_payload(request.into_body()).to_request()).await;
+            assert_eq!(response.status(), http::StatusCode::NO_CONTENT);
+        });
+        assert_eq!(received.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,933 @@
+//! EventSub over WebSockets
+//!
+//! Instead of a public HTTPS callback, notifications can be received over a WebSocket connection to [`EVENTSUB_WEBSOCKET_URL`].
+//! After connecting, Twitch sends a [welcome message](WebsocketMessage::Welcome) containing the session ID.
+//! Subscriptions are then created with [`CreateEventSubSubscription`](crate::helix::eventsub::CreateEventSubSubscription)
+//! using [`Transport::websocket`] with that session ID, within 10 seconds of the welcome message.
+//!
+//! [`WebsocketSession`] keeps track of a session from the messages received on it, and [`WebsocketClient`] drives a session
+//! over a WebSocket implementation you provide with [`WebsocketConnector`].
+//! With the feature `tungstenite_websocket`, `TungsteniteConnector` connects with [`tokio-tungstenite`](https://docs.rs/tokio-tungstenite).
+//!
+//! # Example
+//!
+//! ```rust
+//! use twitch_api2::eventsub::websocket::{SessionEvent, WebsocketSession};
+//! use std::time::Instant;
+//!
+//! let mut session = WebsocketSession::new();
+//! let welcome = r#"{
+//!     "metadata": {
+//!         "message_id": "96a3f3b5-5dec-4eed-908e-e11ee657618e",
+//!         "message_type": "session_welcome",
+//!         "message_timestamp": "2022-11-16T10:11:12.464757833Z"
+//!     },
+//!     "payload": {
+//!         "session": {
+//!             "id": "AQoQILE98gtqShGmLD7AM6yJThAB",
+//!             "status": "connected",
+//!             "connected_at": "2022-11-16T10:11:12.464757833Z",
+//!             "keepalive_timeout_seconds": 10,
+//!             "reconnect_url": null
+//!         }
+//!     }
+//! }"#;
+//!
+//! match session.handle_frame(welcome, Instant::now())? {
+//!     SessionEvent::Connected(_) => {
+//!         // Create subscriptions with this transport
+//!         let transport = session.transport().unwrap();
+//!         assert_eq!(transport.session_id.as_deref(), Some("AQoQILE98gtqShGmLD7AM6yJThAB"));
+//!     }
+//!     _ => unreachable!(),
+//! }
+//! # Ok::<(), twitch_api2::eventsub::websocket::SessionError>(())
+//! ```
+
+use super::{EventSubSubscription, EventType, Payload, PayloadParseError, Transport};
+use crate::types;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// URL of the Twitch EventSub WebSocket server
+pub const EVENTSUB_WEBSOCKET_URL: &str = "wss://eventsub.wss.twitch.tv/ws";
+
+/// How many message IDs are remembered to detect messages received twice
+const SEEN_MESSAGES: usize = 100;
+
+type BoxedFuture<'a, T> = std::pin::Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Metadata of a [message](WebsocketMessage)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct MessageMetadata {
+    /// An ID that uniquely identifies the message.
+    pub message_id: String,
+    /// The type of message.
+    pub message_type: MessageType,
+    /// The UTC date and time that the message was sent.
+    pub message_timestamp: types::Timestamp,
+    /// The type of event sent in the message, only for notifications and revocations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_type: Option<EventType>,
+    /// The version number of the subscription type's definition, only for notifications and revocations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_version: Option<String>,
+}
+
+/// Type of a [message](WebsocketMessage)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MessageType {
+    /// First message after connecting, contains the session ID
+    SessionWelcome,
+    /// Sent when no notification has been sent for the keepalive timeout
+    SessionKeepalive,
+    /// An event notification
+    Notification,
+    /// The server is about to close the connection, reconnect to the given URL
+    SessionReconnect,
+    /// A subscription was revoked
+    Revocation,
+}
+
+/// A WebSocket session
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Session {
+    /// An ID that uniquely identifies this WebSocket connection. Use this ID to set the `session_id` of the [transport](Transport::websocket).
+    pub id: String,
+    /// The connection's status.
+    pub status: SessionStatus,
+    /// The UTC date and time that the connection was created.
+    pub connected_at: types::Timestamp,
+    /// The maximum number of seconds that you should expect silence before receiving a keepalive message.
+    #[serde(default)]
+    pub keepalive_timeout_seconds: Option<u64>,
+    /// The URL to reconnect to, only set in [reconnect messages](WebsocketMessage::Reconnect).
+    #[serde(default)]
+    pub reconnect_url: Option<String>,
+    /// The URL to reconnect to if the connection is lost.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery_url: Option<String>,
+}
+
+/// Status of a [session](Session)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SessionStatus {
+    /// The session is connected
+    Connected,
+    /// The session is about to be moved to another connection
+    Reconnecting,
+}
+
+/// A message received on an EventSub WebSocket connection.
+///
+/// Use [`WebsocketMessage::parse`] to construct
+#[derive(PartialEq, Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+#[non_exhaustive]
+pub enum WebsocketMessage {
+    /// `session_welcome`: the connection is ready, subscribe to events with the session ID.
+    Welcome {
+        /// Metadata of the message
+        metadata: MessageMetadata,
+        /// The session
+        session: Session,
+    },
+    /// `session_keepalive`: the connection is healthy.
+    Keepalive {
+        /// Metadata of the message
+        metadata: MessageMetadata,
+    },
+    /// `notification`: an event you subscribed to.
+    Notification {
+        /// Metadata of the message
+        metadata: MessageMetadata,
+        /// The event
+        payload: Payload,
+    },
+    /// `session_reconnect`: the connection is about to be closed, connect to the [reconnect URL](Session::reconnect_url).
+    Reconnect {
+        /// Metadata of the message
+        metadata: MessageMetadata,
+        /// The session
+        session: Session,
+    },
+    /// `revocation`: a subscription was revoked, see [`status`](EventSubSubscription::status) for the reason.
+    Revocation {
+        /// Metadata of the message
+        metadata: MessageMetadata,
+        /// The revoked subscription
+        subscription: EventSubSubscription,
+    },
+}
+
+impl WebsocketMessage {
+    /// Parse a text frame as a [WebsocketMessage]
+    pub fn parse(frame: &str) -> Result<WebsocketMessage, PayloadParseError> {
+        #[derive(Deserialize)]
+        #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+        struct IMessage {
+            metadata: MessageMetadata,
+            payload: serde_json::Value,
+        }
+
+        #[derive(Deserialize)]
+        #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+        struct ISession {
+            session: Session,
+        }
+
+        #[derive(Deserialize)]
+        #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+        struct IRevocation {
+            subscription: EventSubSubscription,
+        }
+
+        let IMessage { metadata, payload } = serde_json::from_str(frame)?;
+        Ok(match metadata.message_type {
+            MessageType::SessionWelcome => {
+                let ISession { session } = serde_json::from_value(payload)?;
+                WebsocketMessage::Welcome { metadata, session }
+            }
+            MessageType::SessionKeepalive => WebsocketMessage::Keepalive { metadata },
+            MessageType::Notification => WebsocketMessage::Notification {
+                payload: serde_json::from_value(payload)?,
+                metadata,
+            },
+            MessageType::SessionReconnect => {
+                let ISession { session } = serde_json::from_value(payload)?;
+                WebsocketMessage::Reconnect { metadata, session }
+            }
+            MessageType::Revocation => {
+                let IRevocation { subscription } = serde_json::from_value(payload)?;
+                WebsocketMessage::Revocation {
+                    metadata,
+                    subscription,
+                }
+            }
+        })
+    }
+
+    /// Metadata of the message
+    pub fn metadata(&self) -> &MessageMetadata {
+        match self {
+            WebsocketMessage::Welcome { metadata, .. }
+            | WebsocketMessage::Keepalive { metadata }
+            | WebsocketMessage::Notification { metadata, .. }
+            | WebsocketMessage::Reconnect { metadata, .. }
+            | WebsocketMessage::Revocation { metadata, .. } => metadata,
+        }
+    }
+}
+
+/// What happened on a session after [handling a message](WebsocketSession::handle_message)
+#[derive(PartialEq, Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+#[non_exhaustive]
+pub enum SessionEvent {
+    /// The session is connected, create subscriptions with [`WebsocketSession::transport`].
+    Connected(Session),
+    /// The server asked to move the session to this URL.
+    ///
+    /// Connect to the URL and read both connections until the session is [reconnected](SessionEvent::Reconnected).
+    /// [`WebsocketClient`] does this for you.
+    Reconnect(String),
+    /// The session was moved to a new connection, subscriptions are kept.
+    Reconnected(Session),
+    /// The connection is healthy.
+    Keepalive,
+    /// An event notification.
+    Notification(Payload),
+    /// A subscription was revoked.
+    Revocation(EventSubSubscription),
+    /// The message was already handled, e.g. when it was sent on both connections during a reconnect.
+    Duplicate,
+}
+
+/// Errors that can happen when handling a message on a [session](WebsocketSession)
+#[derive(thiserror::Error, displaydoc::Display, Debug)]
+#[non_exhaustive]
+pub enum SessionError {
+    /// could not parse message
+    Parse(#[from] PayloadParseError),
+    /// reconnect message did not include a reconnect url
+    MissingReconnectUrl,
+}
+
+/// State of an EventSub WebSocket session, built from the messages received on it.
+///
+/// The session does not do any IO, feed it every text frame received with [`handle_frame`](WebsocketSession::handle_frame).
+/// During a reconnect, frames from both the old and the new connection should be fed to the same session.
+#[derive(Debug, Clone, Default)]
+pub struct WebsocketSession {
+    session: Option<Session>,
+    reconnect_url: Option<String>,
+    last_message: Option<Instant>,
+    seen: VecDeque<String>,
+}
+
+impl WebsocketSession {
+    /// Create a new session, waiting for a welcome message
+    pub fn new() -> WebsocketSession { WebsocketSession::default() }
+
+    /// The session, if a welcome message has been received
+    pub fn session(&self) -> Option<&Session> { self.session.as_ref() }
+
+    /// The session ID, if a welcome message has been received
+    pub fn session_id(&self) -> Option<&str> { self.session.as_ref().map(|s| s.id.as_str()) }
+
+    /// Transport to use for subscriptions on this session, if a welcome message has been received
+    pub fn transport(&self) -> Option<Transport> { self.session_id().map(Transport::websocket) }
+
+    /// The URL the session is moving to, if a reconnect message has been received and the new connection has not been welcomed yet
+    pub fn reconnect_url(&self) -> Option<&str> { self.reconnect_url.as_deref() }
+
+    /// The maximum time to expect silence before receiving a keepalive message
+    pub fn keepalive_timeout(&self) -> Option<Duration> {
+        self.session
+            .as_ref()?
+            .keepalive_timeout_seconds
+            .map(Duration::from_secs)
+    }
+
+    /// When a message should have been received by, if not the connection should be considered lost
+    pub fn keepalive_deadline(&self) -> Option<Instant> {
+        Some(self.last_message? + self.keepalive_timeout()?)
+    }
+
+    /// Whether no message has been received within the keepalive timeout
+    pub fn is_keepalive_missed(&self, now: Instant) -> bool {
+        matches!(self.keepalive_deadline(), Some(deadline) if now > deadline)
+    }
+
+    /// Parse and handle a text frame received at `now`
+    pub fn handle_frame(
+        &mut self,
+        frame: &str,
+        now: Instant,
+    ) -> Result<SessionEvent, SessionError> {
+        self.handle_message(WebsocketMessage::parse(frame)?, now)
+    }
+
+    /// Handle a message received at `now`
+    pub fn handle_message(
+        &mut self,
+        message: WebsocketMessage,
+        now: Instant,
+    ) -> Result<SessionEvent, SessionError> {
+        self.last_message = Some(now);
+        let id = &message.metadata().message_id;
+        if self.seen.contains(id) {
+            return Ok(SessionEvent::Duplicate);
+        }
+        if self.seen.len() == SEEN_MESSAGES {
+            self.seen.pop_front();
+        }
+        self.seen.push_back(id.clone());
+
+        Ok(match message {
+            WebsocketMessage::Welcome { session, .. } => {
+                self.session = Some(session.clone());
+                if self.reconnect_url.take().is_some() {
+                    SessionEvent::Reconnected(session)
+                } else {
+                    SessionEvent::Connected(session)
+                }
+            }
+            WebsocketMessage::Keepalive { .. } => SessionEvent::Keepalive,
+            WebsocketMessage::Notification { payload, .. } => SessionEvent::Notification(payload),
+            WebsocketMessage::Reconnect { mut session, .. } => {
+                let url = session
+                    .reconnect_url
+                    .clone()
+                    .ok_or(SessionError::MissingReconnectUrl)?;
+                // Reconnect messages have no keepalive timeout, keep the current one until the new connection is welcomed
+                if session.keepalive_timeout_seconds.is_none() {
+                    session.keepalive_timeout_seconds = self
+                        .session
+                        .as_ref()
+                        .and_then(|s| s.keepalive_timeout_seconds);
+                }
+                self.reconnect_url = Some(url.clone());
+                self.session = Some(session);
+                SessionEvent::Reconnect(url)
+            }
+            WebsocketMessage::Revocation { subscription, .. } => {
+                SessionEvent::Revocation(subscription)
+            }
+        })
+    }
+}
+
+/// A frame received on a [`WebsocketConnection`]
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[non_exhaustive]
+pub enum Frame {
+    /// A text frame
+    Text(String),
+    /// No frame was received within the timeout
+    Timeout,
+    /// The connection was closed
+    Closed,
+}
+
+/// A WebSocket implementation that can connect to the EventSub WebSocket server
+///
+/// Implement this for your WebSocket library of choice, like you would implement [`HttpClient`](crate::HttpClient) for HTTP requests.
+/// With the feature `tungstenite_websocket`, `TungsteniteConnector` implements this with [`tokio-tungstenite`](https://docs.rs/tokio-tungstenite).
+pub trait WebsocketConnector: Send + Sync {
+    /// Error returned by the connector and its connections
+    type Error: std::error::Error + Send + Sync + 'static;
+    /// A connection
+    type Connection: WebsocketConnection<Error = Self::Error>;
+
+    /// Connect to `url`
+    fn connect<'a>(
+        &'a self,
+        url: &'a str,
+    ) -> BoxedFuture<'a, Result<Self::Connection, Self::Error>>;
+}
+
+/// A WebSocket connection made by a [`WebsocketConnector`]
+pub trait WebsocketConnection: Send {
+    /// Error returned by the connection
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Receive the next text frame, waiting at most `timeout` if set.
+    ///
+    /// Ping frames should be answered with a pong by the implementation, and should not be returned.
+    ///
+    /// During a reconnect, [`WebsocketClient`] reads two connections at once and drops the future of the one that did not receive a frame,
+    /// no frame should be lost when the returned future is dropped before completing.
+    fn recv(&mut self, timeout: Option<Duration>) -> BoxedFuture<'_, Result<Frame, Self::Error>>;
+}
+
+/// Errors that can happen in a [`WebsocketClient`]
+#[derive(thiserror::Error, displaydoc::Display, Debug)]
+#[non_exhaustive]
+pub enum WebsocketClientError<E: std::error::Error + Send + Sync + 'static> {
+    /// connection failed
+    Connection(#[source] E),
+    /// session failed
+    Session(#[from] SessionError),
+    /// no message was received within the keepalive timeout, the connection is lost
+    KeepaliveTimeout,
+    /// the connection was closed
+    Closed,
+}
+
+/// Client for an EventSub WebSocket session
+///
+/// Connects on the first call to [`next_event`](WebsocketClient::next_event) and follows reconnect messages.
+/// When the session is moved to a new connection, both connections are read until the new connection is welcomed
+/// so that no events are dropped, events received on both connections are only returned once.
+///
+/// If the connection is lost, either by [`KeepaliveTimeout`](WebsocketClientError::KeepaliveTimeout) or [`Closed`](WebsocketClientError::Closed),
+/// the subscriptions of the session are gone. Create a new client and subscribe again.
+pub struct WebsocketClient<C: WebsocketConnector> {
+    connector: C,
+    url: String,
+    connection: Option<C::Connection>,
+    draining: Option<C::Connection>,
+    session: WebsocketSession,
+}
+
+impl<C: WebsocketConnector> WebsocketClient<C> {
+    /// Create a client connecting to [`EVENTSUB_WEBSOCKET_URL`]
+    pub fn new(connector: C) -> WebsocketClient<C> {
+        WebsocketClient::with_url(connector, EVENTSUB_WEBSOCKET_URL)
+    }
+
+    /// Create a client connecting to `url`, e.g. the Twitch CLI mock server
+    pub fn with_url(connector: C, url: impl Into<String>) -> WebsocketClient<C> {
+        WebsocketClient {
+            connector,
+            url: url.into(),
+            connection: None,
+            draining: None,
+            session: WebsocketSession::new(),
+        }
+    }
+
+    /// The session
+    pub fn session(&self) -> &WebsocketSession { &self.session }
+
+    /// Receive the next event on the session
+    pub async fn next_event(&mut self) -> Result<SessionEvent, WebsocketClientError<C::Error>> {
+        loop {
+            if self.connection.is_none() {
+                let connection = self
+                    .connector
+                    .connect(&self.url)
+                    .await
+                    .map_err(WebsocketClientError::Connection)?;
+                self.connection = Some(connection);
+            }
+            let timeout = self
+                .session
+                .keepalive_deadline()
+                .map(|deadline| deadline.saturating_duration_since(Instant::now()));
+            let connection = self.connection.as_mut().expect("connection should be set");
+            let (from_old, frame) = match self.draining.as_mut() {
+                Some(old) => {
+                    Select {
+                        first: old.recv(timeout),
+                        second: connection.recv(timeout),
+                    }
+                    .await
+                }
+                None => (false, connection.recv(timeout).await),
+            };
+            match frame.map_err(WebsocketClientError::Connection)? {
+                Frame::Text(text) => match self.session.handle_frame(&text, Instant::now())? {
+                    SessionEvent::Duplicate => continue,
+                    SessionEvent::Reconnect(url) => {
+                        let connection = self
+                            .connector
+                            .connect(&url)
+                            .await
+                            .map_err(WebsocketClientError::Connection)?;
+                        self.draining = self.connection.replace(connection);
+                        return Ok(SessionEvent::Reconnect(url));
+                    }
+                    SessionEvent::Reconnected(session) => {
+                        // The old connection is closed by the server once the new one is welcomed
+                        self.draining = None;
+                        return Ok(SessionEvent::Reconnected(session));
+                    }
+                    event => return Ok(event),
+                },
+                Frame::Timeout => {
+                    if self.session.is_keepalive_missed(Instant::now()) {
+                        return Err(WebsocketClientError::KeepaliveTimeout);
+                    }
+                }
+                Frame::Closed if from_old => self.draining = None,
+                Frame::Closed => return Err(WebsocketClientError::Closed),
+            }
+        }
+    }
+}
+
+/// Waits for two futures, returning the output of the first one to complete and whether it was `first`
+struct Select<'a, T> {
+    first: BoxedFuture<'a, T>,
+    second: BoxedFuture<'a, T>,
+}
+
+impl<T> Future for Select<'_, T> {
+    type Output = (bool, T);
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        if let std::task::Poll::Ready(output) = self.first.as_mut().poll(cx) {
+            return std::task::Poll::Ready((true, output));
+        }
+        self.second
+            .as_mut()
+            .poll(cx)
+            .map(|output| (false, output))
+    }
+}
+
+/// [`WebsocketConnector`] using [`tokio_tungstenite`], with TLS by rustls
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use twitch_api2::eventsub::websocket::{SessionEvent, TungsteniteConnector, WebsocketClient};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// let mut client = WebsocketClient::new(TungsteniteConnector::new());
+/// loop {
+///     match client.next_event().await? {
+///         SessionEvent::Connected(_) => {
+///             // Create subscriptions with client.session().transport()
+///         }
+///         SessionEvent::Notification(payload) => println!("{:?}", payload),
+///         _ => {}
+///     }
+/// }
+/// # }
+/// ```
+#[cfg(feature = "tungstenite_websocket")]
+#[cfg_attr(nightly, doc(cfg(feature = "tungstenite_websocket")))]
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct TungsteniteConnector {}
+
+#[cfg(feature = "tungstenite_websocket")]
+impl TungsteniteConnector {
+    /// Create a new connector
+    pub fn new() -> TungsteniteConnector { TungsteniteConnector {} }
+}
+
+#[cfg(feature = "tungstenite_websocket")]
+impl WebsocketConnector for TungsteniteConnector {
+    type Connection = TungsteniteConnection;
+    type Error = tokio_tungstenite::tungstenite::Error;
+
+    fn connect<'a>(
+        &'a self,
+        url: &'a str,
+    ) -> BoxedFuture<'a, Result<Self::Connection, Self::Error>> {
+        Box::pin(async move {
+            let (stream, _) = tokio_tungstenite::connect_async(url).await?;
+            Ok(TungsteniteConnection { stream })
+        })
+    }
+}
+
+/// A connection made by [`TungsteniteConnector`]
+#[cfg(feature = "tungstenite_websocket")]
+#[cfg_attr(nightly, doc(cfg(feature = "tungstenite_websocket")))]
+#[derive(Debug)]
+pub struct TungsteniteConnection {
+    stream: tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >,
+}
+
+#[cfg(feature = "tungstenite_websocket")]
+impl WebsocketConnection for TungsteniteConnection {
+    type Error = tokio_tungstenite::tungstenite::Error;
+
+    fn recv(&mut self, timeout: Option<Duration>) -> BoxedFuture<'_, Result<Frame, Self::Error>> {
+        use futures_util::StreamExt;
+        use tokio_tungstenite::tungstenite::{Error, Message};
+
+        let deadline = timeout.map(|timeout| tokio::time::Instant::now() + timeout);
+        Box::pin(async move {
+            loop {
+                let message = match deadline {
+                    Some(deadline) => {
+                        match tokio::time::timeout_at(deadline, self.stream.next()).await {
+                            Ok(message) => message,
+                            Err(_) => return Ok(Frame::Timeout),
+                        }
+                    }
+                    None => self.stream.next().await,
+                };
+                match message {
+                    Some(Ok(Message::Text(text))) => return Ok(Frame::Text(text)),
+                    Some(Ok(Message::Close(_)))
+                    | Some(Err(Error::ConnectionClosed))
+                    | Some(Err(Error::AlreadyClosed))
+                    | None => return Ok(Frame::Closed),
+                    // Pings are answered by tungstenite
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => return Err(e),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    /// Stand-in for the EventSub WebSocket server, serving queued frames per URL
+    #[derive(Clone, Default)]
+    struct StandIn {
+        connections: Arc<Mutex<HashMap<String, StandInConnection>>>,
+    }
+
+    impl StandIn {
+        fn queue(&self, url: &str, frames: Vec<Frame>) {
+            self.connections
+                .lock()
+                .unwrap()
+                .entry(url.to_string())
+                .or_default()
+                .frames
+                .extend(frames);
+        }
+
+        /// Keep the connection to `url` open and silent once its frames are served, instead of closing it
+        fn keep_open(&self, url: &str) {
+            self.connections
+                .lock()
+                .unwrap()
+                .entry(url.to_string())
+                .or_default()
+                .keep_open = true;
+        }
+    }
+
+    #[derive(Default)]
+    struct StandInConnection {
+        frames: VecDeque<Frame>,
+        keep_open: bool,
+    }
+
+    impl WebsocketConnector for StandIn {
+        type Connection = StandInConnection;
+        type Error = std::io::Error;
+
+        fn connect<'a>(
+            &'a self,
+            url: &'a str,
+        ) -> BoxedFuture<'a, Result<Self::Connection, Self::Error>> {
+            let connection = self.connections.lock().unwrap().remove(url);
+            Box::pin(async move {
+                connection.ok_or_else(|| std::io::ErrorKind::ConnectionRefused.into())
+            })
+        }
+    }
+
+    impl WebsocketConnection for StandInConnection {
+        type Error = std::io::Error;
+
+        fn recv(
+            &mut self,
+            timeout: Option<Duration>,
+        ) -> BoxedFuture<'_, Result<Frame, Self::Error>> {
+            Box::pin(async move {
+                match self.frames.pop_front() {
+                    Some(frame) => Ok(frame),
+                    None if self.keep_open => {
+                        assert!(timeout.is_some(), "waiting forever on an open connection");
+                        std::future::pending().await
+                    }
+                    None => Ok(Frame::Closed),
+                }
+            })
+        }
+    }
+
+    fn welcome(id: &str) -> Frame { welcome_with_keepalive(id, 10) }
+
+    fn welcome_with_keepalive(id: &str, keepalive_timeout_seconds: u64) -> Frame {
+        Frame::Text(format!(
+            r#"{{"metadata":{{"message_id":"{}","message_type":"session_welcome","message_timestamp":"2022-11-16T10:11:12.464757833Z"}},"payload":{{"session":{{"id":"AQoQexAWVYKSTIu4ec_2VAxyuhAB","status":"connected","connected_at":"2022-11-16T10:11:12.464757833Z","keepalive_timeout_seconds":{},"reconnect_url":null}}}}}}"#,
+            id, keepalive_timeout_seconds
+        ))
+    }
+
+    fn reconnect(id: &str, url: &str) -> Frame {
+        Frame::Text(format!(
+            r#"{{"metadata":{{"message_id":"{}","message_type":"session_reconnect","message_timestamp":"2022-11-18T09:10:11.634234626Z"}},"payload":{{"session":{{"id":"AQoQexAWVYKSTIu4ec_2VAxyuhAB","status":"reconnecting","keepalive_timeout_seconds":null,"reconnect_url":"{}","connected_at":"2022-11-16T10:11:12.464757833Z"}}}}}}"#,
+            id, url
+        ))
+    }
+
+    fn notification(id: &str) -> Frame {
+        Frame::Text(format!(
+            r#"{{"metadata":{{"message_id":"{}","message_type":"notification","message_timestamp":"2022-11-16T10:11:12.464757833Z","subscription_type":"channel.follow","subscription_version":"1"}},"payload":{{"subscription":{{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","status":"enabled","type":"channel.follow","version":"1","cost":1,"condition":{{"broadcaster_user_id":"12826"}},"transport":{{"method":"websocket","session_id":"AQoQexAWVYKSTIu4ec_2VAxyuhAB"}},"created_at":"2022-11-16T10:11:12.464757833Z"}},"event":{{"user_id":"1337","user_login":"awesome_user","user_name":"Awesome_User","broadcaster_user_id":"12826","broadcaster_user_login":"twitch","broadcaster_user_name":"Twitch","followed_at":"2020-07-15T18:16:11.17106713Z"}}}}}}"#,
+            id
+        ))
+    }
+
+    fn text(frame: Frame) -> String {
+        match frame {
+            Frame::Text(text) => text,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn parse_messages() {
+        let keepalive = r#"{"metadata":{"message_id":"84c1e79a-2a4b-4c13-ba0b-4312293e9308","message_type":"session_keepalive","message_timestamp":"2022-11-16T10:11:12.464757833Z"},"payload":{}}"#;
+        let revocation = r#"{"metadata":{"message_id":"84c1e79a-2a4b-4c13-ba0b-4312293e9308","message_type":"revocation","message_timestamp":"2022-11-16T10:11:12.464757833Z","subscription_type":"channel.follow","subscription_version":"1"},"payload":{"subscription":{"id":"f1c2a387-161a-49f9-a165-0f21d7a4e1c4","status":"authorization_revoked","type":"channel.follow","version":"1","cost":1,"condition":{"broadcaster_user_id":"12826"},"transport":{"method":"websocket","session_id":"AQoQexAWVYKSTIu4ec_2VAxyuhAB"},"created_at":"2022-11-16T10:11:12.464757833Z"}}}"#;
+
+        assert!(matches!(
+            WebsocketMessage::parse(&text(welcome("1"))).unwrap(),
+            WebsocketMessage::Welcome { session, .. } if session.keepalive_timeout_seconds == Some(10)
+        ));
+        assert!(matches!(
+            WebsocketMessage::parse(keepalive).unwrap(),
+            WebsocketMessage::Keepalive { .. }
+        ));
+        assert!(matches!(
+            WebsocketMessage::parse(&text(notification("2"))).unwrap(),
+            WebsocketMessage::Notification {
+                payload: Payload::ChannelFollowV1(_),
+                ..
+            }
+        ));
+        assert!(matches!(
+            WebsocketMessage::parse(&text(reconnect("3", "wss://eventsub.wss.twitch.tv?id=1"))).unwrap(),
+            WebsocketMessage::Reconnect { session, .. } if session.status == SessionStatus::Reconnecting
+        ));
+        assert!(matches!(
+            WebsocketMessage::parse(revocation).unwrap(),
+            WebsocketMessage::Revocation { subscription, .. } if subscription.status == crate::eventsub::Status::AuthorizationRevoked
+        ));
+    }
+
+    #[test]
+    fn keepalive() {
+        let mut session = WebsocketSession::new();
+        let start = Instant::now();
+        session.handle_frame(&text(welcome("1")), start).unwrap();
+        assert_eq!(
+            session.transport().unwrap().session_id.as_deref(),
+            Some("AQoQexAWVYKSTIu4ec_2VAxyuhAB")
+        );
+        assert!(!session.is_keepalive_missed(start + Duration::from_secs(10)));
+        assert!(session.is_keepalive_missed(start + Duration::from_secs(11)));
+        session
+            .handle_frame(&text(notification("2")), start + Duration::from_secs(5))
+            .unwrap();
+        assert!(!session.is_keepalive_missed(start + Duration::from_secs(11)));
+    }
+
+    #[tokio::test]
+    async fn reconnect_without_dropping_events() {
+        let server = StandIn::default();
+        let new_url = "wss://eventsub.wss.twitch.tv?id=1";
+        server.queue(
+            EVENTSUB_WEBSOCKET_URL,
+            vec![
+                welcome("1"),
+                notification("2"),
+                reconnect("3", new_url),
+                notification("4"),
+                notification("5"),
+            ],
+        );
+        server.queue(
+            new_url,
+            vec![welcome("6"), notification("5"), notification("7")],
+        );
+
+        let mut client = WebsocketClient::new(server);
+        let mut events = vec![];
+        loop {
+            match client.next_event().await {
+                Ok(event) => events.push(event),
+                Err(WebsocketClientError::Closed) => break,
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        assert!(matches!(events[0], SessionEvent::Connected(_)));
+        assert!(matches!(events[1], SessionEvent::Notification(_)));
+        assert_eq!(events[2], SessionEvent::Reconnect(new_url.to_string()));
+        assert!(matches!(events[3], SessionEvent::Notification(_)));
+        assert!(matches!(events[4], SessionEvent::Notification(_)));
+        assert!(matches!(events[5], SessionEvent::Reconnected(_)));
+        assert!(matches!(events[6], SessionEvent::Notification(_)));
+        assert_eq!(events.len(), 7);
+        assert_eq!(client.session().reconnect_url(), None);
+    }
+
+    #[tokio::test]
+    async fn reconnect_with_old_connection_open() {
+        let server = StandIn::default();
+        let new_url = "wss://eventsub.wss.twitch.tv?id=1";
+        server.queue(
+            EVENTSUB_WEBSOCKET_URL,
+            vec![welcome("1"), reconnect("2", new_url), notification("3")],
+        );
+        server.keep_open(EVENTSUB_WEBSOCKET_URL);
+        server.queue(new_url, vec![welcome("4"), notification("5")]);
+
+        let mut client = WebsocketClient::new(server);
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Connected(_)
+        ));
+        assert_eq!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Reconnect(new_url.to_string())
+        );
+        assert_eq!(
+            client.session().keepalive_timeout(),
+            Some(Duration::from_secs(10))
+        );
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Notification(_)
+        ));
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Reconnected(_)
+        ));
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Notification(_)
+        ));
+        assert!(matches!(
+            client.next_event().await,
+            Err(WebsocketClientError::Closed)
+        ));
+    }
+
+    #[cfg(feature = "tungstenite_websocket")]
+    #[tokio::test]
+    async fn tungstenite_local_server() {
+        use futures_util::SinkExt;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/", listener.local_addr().unwrap());
+        let new_url = format!("{}?id=1", url);
+
+        let server_url = new_url.clone();
+        tokio::spawn(async move {
+            let accept = || async {
+                let (stream, _) = listener.accept().await.unwrap();
+                tokio_tungstenite::accept_async(stream).await.unwrap()
+            };
+            let mut old = accept().await;
+            for frame in [
+                welcome_with_keepalive("1", 1),
+                notification("2"),
+                reconnect("3", &server_url),
+            ]
+            .iter()
+            {
+                old.send(Message::Text(text(frame.clone()))).await.unwrap();
+            }
+            let mut new = accept().await;
+            for frame in [welcome_with_keepalive("4", 1), notification("5")].iter() {
+                new.send(Message::Text(text(frame.clone()))).await.unwrap();
+            }
+            old.close(None).await.unwrap();
+            // Keep the new connection open and silent
+            std::future::pending::<()>().await;
+            drop(new);
+        });
+
+        let mut client = WebsocketClient::with_url(TungsteniteConnector::new(), url);
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Connected(_)
+        ));
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Notification(_)
+        ));
+        assert_eq!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Reconnect(new_url)
+        );
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Reconnected(_)
+        ));
+        assert!(matches!(
+            client.next_event().await.unwrap(),
+            SessionEvent::Notification(_)
+        ));
+        assert!(matches!(
+            client.next_event().await,
+            Err(WebsocketClientError::KeepaliveTimeout)
+        ));
+    }
+}
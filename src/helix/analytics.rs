@@ -0,0 +1,136 @@
+#![doc(alias = "analytic")]
+//! Helix endpoints regarding analytics
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, analytics::GetGameAnalyticsRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetGameAnalyticsRequest::builder()
+//!     .game_id("493057".to_string())
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Reports
+//!
+//! The analytics endpoints return a URL to a CSV report. With the `csv` feature enabled,
+//! [`parse_report`] deserializes such a report into your own records, and
+//! [`HelixClient::get_analytics_report`](helix::HelixClient::get_analytics_report) downloads and parses it in one go.
+
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod get_extension_analytics;
+pub mod get_game_analytics;
+
+#[doc(inline)]
+pub use get_extension_analytics::{ExtensionAnalytics, GetExtensionAnalyticsRequest};
+#[doc(inline)]
+pub use get_game_analytics::{GameAnalytics, GetGameAnalyticsRequest};
+
+/// Type of analytics report
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AnalyticsType {
+    /// Overview report, version 1. Deprecated by Twitch.
+    OverviewV1,
+    /// Overview report, version 2.
+    OverviewV2,
+}
+
+/// Period that a report covers
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DateRange {
+    /// Report start date/time.
+    pub started_at: types::Timestamp,
+    /// Report end date/time.
+    pub ended_at: types::Timestamp,
+}
+
+/// Errors from parsing an analytics report
+#[cfg(feature = "csv")]
+#[cfg_attr(nightly, doc(cfg(feature = "csv")))]
+#[derive(thiserror::Error, Debug, displaydoc::Display)]
+pub enum ReportError {
+    /// could not parse report as csv
+    Csv(#[from] csv_crate::Error),
+    /// could not download report: {0}
+    Status(http::StatusCode),
+}
+
+/// Parse a CSV analytics report into records of type `T`.
+///
+/// Columns are matched by their header, so `T` only needs to contain the columns you care about.
+///
+/// # Examples
+///
+/// ```rust
+/// # use twitch_api2::helix::analytics;
+/// #[derive(serde::Deserialize)]
+/// struct Row {
+///     #[serde(rename = "Date")]
+///     date: String,
+///     #[serde(rename = "Installs")]
+///     installs: u64,
+/// }
+///
+/// let report = b"Date,Extension Name,Installs\n2018-03-01T00:00:00Z,My Extension,42\n";
+/// let rows: Vec<Row> = analytics::parse_report(&report[..])?;
+/// assert_eq!(rows[0].installs, 42);
+/// # Ok::<(), analytics::ReportError>(())
+/// ```
+#[cfg(feature = "csv")]
+#[cfg_attr(nightly, doc(cfg(feature = "csv")))]
+pub fn parse_report<T>(report: &[u8]) -> Result<Vec<T>, ReportError>
+where T: serde::de::DeserializeOwned {
+    csv_crate::Reader::from_reader(report)
+        .deserialize()
+        .collect::<Result<_, _>>()
+        .map_err(Into::into)
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn test_parse_report() {
+    #[derive(PartialEq, Deserialize, Debug)]
+    struct Row {
+        #[serde(rename = "Date")]
+        date: String,
+        #[serde(rename = "Game Name")]
+        game_name: String,
+        #[serde(rename = "Live Views")]
+        live_views: u64,
+    }
+
+    let report = b"Date,Game ID,Game Name,Live Views,Unique Live Viewers
+2018-03-01T00:00:00Z,493057,\"PLAYERUNKNOWN'S BATTLEGROUNDS\",12,3
+2018-03-02T00:00:00Z,493057,\"PLAYERUNKNOWN'S BATTLEGROUNDS\",0,0
+";
+    let rows: Vec<Row> = parse_report(&report[..]).unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(
+        rows[0],
+        Row {
+            date: "2018-03-01T00:00:00Z".to_string(),
+            game_name: "PLAYERUNKNOWN'S BATTLEGROUNDS".to_string(),
+            live_views: 12,
+        }
+    );
+
+    assert!(parse_report::<Row>(b"Date,Game Name,Live Views\nnot,a,number\n").is_err());
+}
@@ -0,0 +1,143 @@
+//! Gets a URL that game developers can use to download analytics reports (CSV files) for their games. The URL is valid for 5 minutes.
+//! [`get-game-analytics`](https://dev.twitch.tv/docs/api/reference#get-game-analytics)
+//!
+//! # Notes
+//!
+//! If you specify a future date, the response will be “Report Not Found For Date Range.” If you leave both `started_at` and `ended_at` blank, the API returns the most recent date of data.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetGameAnalyticsRequest]
+//!
+//! To use this endpoint, construct a [`GetGameAnalyticsRequest`] with the [`GetGameAnalyticsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::analytics::get_game_analytics;
+//! let request = get_game_analytics::GetGameAnalyticsRequest::builder()
+//!     .game_id("493057".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [GameAnalytics]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, analytics::get_game_analytics};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_game_analytics::GetGameAnalyticsRequest::builder()
+//!     .game_id("493057".to_string())
+//!     .build();
+//! let response: Vec<get_game_analytics::GameAnalytics> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetGameAnalyticsRequest::parse_response(None, &request.get_uri(), response)`](GetGameAnalyticsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Game Analytics](super::get_game_analytics)
+///
+/// [`get-game-analytics`](https://dev.twitch.tv/docs/api/reference#get-game-analytics)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct GetGameAnalyticsRequest {
+    /// Game ID. If this is specified, the returned URL points to an analytics report for just the specified game. If this is not specified, the response includes multiple URLs (paginated), pointing to separate analytics reports for each of the authenticated user’s games.
+    #[builder(default, setter(into))]
+    pub game_id: Option<types::CategoryId>,
+    /// Type of analytics report that is returned. If this is specified, the response includes one URL, for the specified report type. If this is not specified, the response includes multiple URLs (paginated), one for each report type available for the authenticated user’s games.
+    #[builder(default, setter(into))]
+    #[serde(rename = "type")]
+    pub type_: Option<AnalyticsType>,
+    /// Starting date/time for returned reports. This must be on or after January 31, 2018. If this is provided, `ended_at` also must be specified.
+    #[builder(default, setter(into))]
+    pub started_at: Option<types::Timestamp>,
+    /// Ending date/time for returned reports. If this is provided, `started_at` also must be specified.
+    #[builder(default, setter(into))]
+    pub ended_at: Option<types::Timestamp>,
+    /// Cursor for forward pagination. This applies only to queries without `game_id`.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+    /// Maximum number of objects to return. Maximum: 100. Default: 20.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+}
+
+/// Return Values for [Get Game Analytics](super::get_game_analytics)
+///
+/// [`get-game-analytics`](https://dev.twitch.tv/docs/api/reference#get-game-analytics)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct GameAnalytics {
+    /// ID of the game whose analytics data is being provided.
+    pub game_id: types::CategoryId,
+    /// URL to the downloadable CSV file containing analytics data. Valid for 5 minutes.
+    #[serde(rename = "URL")]
+    pub url: String,
+    /// Type of report.
+    #[serde(rename = "type")]
+    pub type_: AnalyticsType,
+    /// Period that the report covers.
+    pub date_range: DateRange,
+}
+
+impl Request for GetGameAnalyticsRequest {
+    type Response = Vec<GameAnalytics>;
+
+    const PATH: &'static str = "analytics/games";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::AnalyticsReadGames];
+}
+
+impl RequestGet for GetGameAnalyticsRequest {}
+
+impl helix::Paginated for GetGameAnalyticsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetGameAnalyticsRequest::builder()
+        .game_id("493057".to_string())
+        .started_at("2018-01-01T00:00:00Z".to_string())
+        .ended_at("2018-03-01T00:00:00Z".to_string())
+        .build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "game_id": "493057",
+            "URL": "https://twitch-piper-reports.s3-us-west-2.amazonaws.com/games/66170/overview/15183360",
+            "type": "overview_v2",
+            "date_range": {
+                "started_at": "2018-01-01T00:00:00Z",
+                "ended_at": "2018-03-01T00:00:00Z"
+            }
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/analytics/games?game_id=493057&started_at=2018-01-01T00%3A00%3A00Z&ended_at=2018-03-01T00%3A00%3A00Z"
+    );
+
+    dbg!(GetGameAnalyticsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
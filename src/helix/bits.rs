@@ -24,11 +24,152 @@ use crate::{
     types,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 pub mod get_bits_leaderboard;
 pub mod get_cheermotes;
+pub mod get_extension_bits_products;
+pub mod update_extension_bits_product;
 
+#[doc(no_inline)]
+pub use crate::helix::extensions::{Cost, CostType, ExtensionTransaction};
 #[doc(inline)]
 pub use get_bits_leaderboard::{BitsLeaderboard, GetBitsLeaderboardRequest};
 #[doc(inline)]
 pub use get_cheermotes::{Cheermote, GetCheermotesRequest};
+#[doc(inline)]
+pub use get_extension_bits_products::{ExtensionBitsProduct, GetExtensionBitsProductsRequest};
+#[doc(inline)]
+pub use update_extension_bits_product::{
+    UpdateExtensionBitsProductBody, UpdateExtensionBitsProductRequest,
+};
+
+/// Transactions of a single product SKU, see [`reconcile_transactions`]
+#[derive(PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub struct ProductTransactions<'a> {
+    /// The product in the catalog with this SKU, `None` if the SKU is not in the catalog.
+    pub product: Option<&'a ExtensionBitsProduct>,
+    /// Transactions of this SKU.
+    pub transactions: Vec<&'a ExtensionTransaction>,
+    /// Total amount of bits spent in the transactions, using the cost at the time of each transaction.
+    pub bits: i64,
+}
+
+/// Match [extension transactions](ExtensionTransaction) with the [products](ExtensionBitsProduct) in the catalog by their SKU.
+///
+/// Every product in `products` gets an entry, also when there are no transactions for it.
+/// Transactions for SKUs that are not in `products`, e.g. because the product was removed or `should_include_all` was not set
+/// in [Get Extension Bits Products](get_extension_bits_products), get an entry without a product.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use twitch_api2::helix::{self, bits, extensions};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+/// # let client: helix::HelixClient<'static, twitch_api2::client::DummyHttpClient> = helix::HelixClient::default();
+/// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+/// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+/// let products = client
+///     .req_get(
+///         bits::GetExtensionBitsProductsRequest::builder()
+///             .should_include_all(true)
+///             .build(),
+///         &token,
+///     )
+///     .await?
+///     .data;
+/// let transactions = client
+///     .req_get(
+///         extensions::GetExtensionTransactionsRequest::builder()
+///             .extension_id("1234".to_string())
+///             .build(),
+///         &token,
+///     )
+///     .await?
+///     .data;
+/// for (sku, reconciled) in bits::reconcile_transactions(&products, &transactions) {
+///     println!("{}: {} transactions, {} bits", sku, reconciled.transactions.len(), reconciled.bits);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn reconcile_transactions<'a>(
+    products: &'a [ExtensionBitsProduct],
+    transactions: &'a [ExtensionTransaction],
+) -> HashMap<&'a str, ProductTransactions<'a>> {
+    let mut map: HashMap<&'a str, ProductTransactions<'a>> = products
+        .iter()
+        .map(|product| {
+            (
+                product.sku.as_str(),
+                ProductTransactions {
+                    product: Some(product),
+                    transactions: vec![],
+                    bits: 0,
+                },
+            )
+        })
+        .collect();
+    for transaction in transactions {
+        let entry = map
+            .entry(transaction.product_data.sku.as_str())
+            .or_insert_with(|| ProductTransactions {
+                product: None,
+                transactions: vec![],
+                bits: 0,
+            });
+        entry.transactions.push(transaction);
+        entry.bits += transaction.product_data.cost.amount;
+    }
+    map
+}
+
+#[test]
+fn test_reconcile_transactions() {
+    let products: Vec<ExtensionBitsProduct> = serde_json::from_str(
+        r#"[
+        {"sku": "1010", "cost": {"amount": 990, "type": "bits"}, "in_development": false, "display_name": "Rusty Crate 2", "is_broadcast": false},
+        {"sku": "1011", "cost": {"amount": 100, "type": "bits"}, "in_development": false, "display_name": "Rusty Crate 3", "is_broadcast": false}
+    ]"#,
+    )
+    .unwrap();
+    let transaction = |id: &str, sku: &str, amount: i64| {
+        serde_json::from_value::<ExtensionTransaction>(serde_json::json!({
+            "id": id,
+            "timestamp": "2019-01-28T04:15:53.325Z",
+            "broadcaster_id": "439964613",
+            "broadcaster_login": "chikuseuma",
+            "broadcaster_name": "chikuseuma",
+            "user_id": "424596340",
+            "user_login": "quotrok",
+            "user_name": "quotrok",
+            "product_type": "BITS_IN_EXTENSION",
+            "product_data": {
+                "domain": "twitch.ext.uo6dggojyb8d6soh92zknwmi5ej1q2",
+                "sku": sku,
+                "cost": {"amount": amount, "type": "bits"},
+                "inDevelopment": false,
+                "displayName": "Rusty Crate",
+                "expiration": "",
+                "broadcast": false
+            }
+        }))
+        .unwrap()
+    };
+    let transactions = vec![
+        transaction("1", "1010", 990),
+        transaction("2", "1010", 500),
+        transaction("3", "old", 10),
+    ];
+
+    let reconciled = reconcile_transactions(&products, &transactions);
+    assert_eq!(reconciled.len(), 3);
+    assert_eq!(reconciled["1010"].product, Some(&products[0]));
+    assert_eq!(reconciled["1010"].transactions.len(), 2);
+    assert_eq!(reconciled["1010"].bits, 1490);
+    assert!(reconciled["1011"].transactions.is_empty());
+    assert_eq!(reconciled["old"].product, None);
+    assert_eq!(reconciled["old"].bits, 10);
+}
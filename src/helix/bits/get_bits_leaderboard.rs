@@ -155,6 +155,8 @@ impl RequestGet for GetBitsLeaderboardRequest {
             },
             pagination: None,
             request,
+            total: Some(response.total),
+            other: None,
         })
     }
 }
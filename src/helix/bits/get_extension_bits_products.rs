@@ -0,0 +1,124 @@
+//! Gets the list of Bits products that belongs to an Extension.
+//! [`get-extension-bits-products`](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionBitsProductsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionBitsProductsRequest`] with the [`GetExtensionBitsProductsRequest::builder()`] method.
+//! The request must be made with an app access token for the extension's client ID.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::bits::get_extension_bits_products;
+//! let request = get_extension_bits_products::GetExtensionBitsProductsRequest::builder()
+//!     .should_include_all(true)
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionBitsProduct]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, bits::get_extension_bits_products};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_extension_bits_products::GetExtensionBitsProductsRequest::builder().build();
+//! let response: Vec<get_extension_bits_products::ExtensionBitsProduct> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionBitsProductsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionBitsProductsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Bits Products](super::get_extension_bits_products)
+///
+/// [`get-extension-bits-products`](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct GetExtensionBitsProductsRequest {
+    /// Whether to include disabled or expired Bits products in the response. Default: false.
+    #[builder(default, setter(into))]
+    pub should_include_all: Option<bool>,
+}
+
+/// Return Values for [Get Extension Bits Products](super::get_extension_bits_products)
+///
+/// [`get-extension-bits-products`](https://dev.twitch.tv/docs/api/reference#get-extension-bits-products)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionBitsProduct {
+    /// The product's SKU, unique across the extension.
+    pub sku: String,
+    /// The cost of the product.
+    pub cost: Cost,
+    /// Indicates if the product is in development and not available for public use.
+    pub in_development: bool,
+    /// Name of the product to be displayed in the extension.
+    pub display_name: String,
+    /// Expiration time for the product.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<types::Timestamp>,
+    /// Indicates whether Bits product purchase events are broadcast to all instances of the extension on a channel.
+    pub is_broadcast: bool,
+}
+
+impl Request for GetExtensionBitsProductsRequest {
+    type Response = Vec<ExtensionBitsProduct>;
+
+    const PATH: &'static str = "bits/extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetExtensionBitsProductsRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionBitsProductsRequest::builder()
+        .should_include_all(true)
+        .build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "sku": "1010",
+            "cost": {
+                "amount": 990,
+                "type": "bits"
+            },
+            "in_development": true,
+            "display_name": "Rusty Crate 2",
+            "expiration": "2021-05-18T09:10:13.397Z",
+            "is_broadcast": false
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/bits/extensions?should_include_all=true"
+    );
+
+    let res = GetExtensionBitsProductsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res[0].cost, Cost::bits(990));
+}
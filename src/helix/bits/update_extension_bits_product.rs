@@ -0,0 +1,195 @@
+//! Adds or updates a Bits product that the extension created.
+//! [`update-extension-bits-product`](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+//!
+//! If the SKU doesn’t exist, the product is added. You may update all fields except the `sku` field.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateExtensionBitsProductRequest]
+//!
+//! To use this endpoint, construct a [`UpdateExtensionBitsProductRequest`] with the [`UpdateExtensionBitsProductRequest::new()`] method.
+//! The request must be made with an app access token for the extension's client ID.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::bits::update_extension_bits_product;
+//! let request = update_extension_bits_product::UpdateExtensionBitsProductRequest::new();
+//! ```
+//!
+//! ## Body: [UpdateExtensionBitsProductBody]
+//!
+//! We also need to provide a body to the request containing what we want to change.
+//!
+//! ```
+//! # use twitch_api2::helix::bits::{update_extension_bits_product, Cost};
+//! let body = update_extension_bits_product::UpdateExtensionBitsProductBody::builder()
+//!     .sku("1010")
+//!     .cost(Cost::bits(990))
+//!     .display_name("Rusty Crate 2")
+//!     .in_development(true)
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionBitsProduct]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, bits::{update_extension_bits_product, Cost}};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = update_extension_bits_product::UpdateExtensionBitsProductRequest::new();
+//! let body = update_extension_bits_product::UpdateExtensionBitsProductBody::builder()
+//!     .sku("1010")
+//!     .cost(Cost::bits(990))
+//!     .display_name("Rusty Crate 2")
+//!     .build();
+//! let response: helix::bits::ExtensionBitsProduct = client.req_put(request, body, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`UpdateExtensionBitsProductRequest::parse_response(&request.get_uri(), response)`](UpdateExtensionBitsProductRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Update Extension Bits Product](super::update_extension_bits_product)
+///
+/// [`update-extension-bits-product`](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateExtensionBitsProductRequest {}
+
+impl UpdateExtensionBitsProductRequest {
+    /// Create a new [`UpdateExtensionBitsProductRequest`]
+    pub fn new() -> Self {
+        UpdateExtensionBitsProductRequest {}
+    }
+}
+
+/// Body Parameters for [Update Extension Bits Product](super::update_extension_bits_product)
+///
+/// [`update-extension-bits-product`](https://dev.twitch.tv/docs/api/reference#update-extension-bits-product)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct UpdateExtensionBitsProductBody {
+    /// The product's SKU. The SKU must be unique within an extension. Maximum: 255 characters, no spaces.
+    #[builder(setter(into))]
+    pub sku: String,
+    /// The product's cost.
+    pub cost: Cost,
+    /// The product's name as displayed in the extension. Maximum: 255 characters.
+    #[builder(setter(into))]
+    pub display_name: String,
+    /// Whether the product is in development. Default: false.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_development: Option<bool>,
+    /// The date and time when the product expires. If not set, the product does not expire.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<types::Timestamp>,
+    /// Whether Bits product purchase events are broadcast to all instances of the extension on a channel. Default: false.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_broadcast: Option<bool>,
+}
+
+impl helix::private::SealedSerialize for UpdateExtensionBitsProductBody {}
+
+impl Request for UpdateExtensionBitsProductRequest {
+    type Response = ExtensionBitsProduct;
+
+    const PATH: &'static str = "bits/extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPut for UpdateExtensionBitsProductRequest {
+    type Body = UpdateExtensionBitsProductBody;
+
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        let resp: helix::InnerResponse<Vec<ExtensionBitsProduct>> = helix::parse_json(response)
+            .map_err(|e| {
+                helix::HelixRequestPutError::DeserializeError(
+                    response.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        resp.data
+            .into_iter()
+            .next()
+            .ok_or_else(|| helix::HelixRequestPutError::Error {
+                error: String::new(),
+                status,
+                message: "response included no data".to_string(),
+                uri: uri.clone(),
+                body: response.as_bytes().to_vec(),
+            })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateExtensionBitsProductRequest::new();
+
+    let body = UpdateExtensionBitsProductBody::builder()
+        .sku("1010")
+        .cost(Cost::bits(990))
+        .display_name("Rusty Crate 2")
+        .in_development(true)
+        .build();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"sku":"1010","cost":{"amount":990,"type":"bits"},"display_name":"Rusty Crate 2","in_development":true}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "sku": "1010",
+            "cost": {
+                "amount": 990,
+                "type": "bits"
+            },
+            "in_development": true,
+            "display_name": "Rusty Crate 2",
+            "expiration": "2021-05-18T09:10:13.397Z",
+            "is_broadcast": false
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/bits/extensions?"
+    );
+
+    let product = UpdateExtensionBitsProductRequest::parse_response(&uri, http_response).unwrap();
+    assert_eq!(product.sku, "1010");
+}
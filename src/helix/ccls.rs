@@ -0,0 +1,32 @@
+//! Helix endpoints regarding content classification labels
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, ccls::GetContentClassificationLabelsRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetContentClassificationLabelsRequest::builder()
+//!     .locale("en-US".to_string())
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod get_content_classification_labels;
+
+#[doc(inline)]
+pub use get_content_classification_labels::{
+    ContentClassificationLabel, GetContentClassificationLabelsRequest,
+};
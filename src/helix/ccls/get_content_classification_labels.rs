@@ -0,0 +1,128 @@
+//! Gets information about Twitch content classification labels.
+//! [`get-content-classification-labels`](https://dev.twitch.tv/docs/api/reference#get-content-classification-labels)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetContentClassificationLabelsRequest]
+//!
+//! To use this endpoint, construct a [`GetContentClassificationLabelsRequest`] with the [`GetContentClassificationLabelsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::ccls::get_content_classification_labels;
+//! let request =
+//!     get_content_classification_labels::GetContentClassificationLabelsRequest::builder()
+//!         .locale("en-US".to_string())
+//!         .build();
+//! ```
+//!
+//! ## Response: [ContentClassificationLabel]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, ccls::get_content_classification_labels};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request =
+//!     get_content_classification_labels::GetContentClassificationLabelsRequest::builder()
+//!         .build();
+//! let response: Vec<get_content_classification_labels::ContentClassificationLabel> =
+//!     client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetContentClassificationLabelsRequest::parse_response(None, &request.get_uri(), response)`](GetContentClassificationLabelsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Content Classification Labels](super::get_content_classification_labels)
+///
+/// [`get-content-classification-labels`](https://dev.twitch.tv/docs/api/reference#get-content-classification-labels)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetContentClassificationLabelsRequest {
+    /// Locale for the Content Classification Labels, e.g. `en-US`. Default: `en-US`.
+    #[builder(default, setter(into))]
+    pub locale: Option<String>,
+}
+
+/// Return Values for [Get Content Classification Labels](super::get_content_classification_labels)
+///
+/// [`get-content-classification-labels`](https://dev.twitch.tv/docs/api/reference#get-content-classification-labels)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ContentClassificationLabel {
+    /// Unique identifier for the CCL.
+    pub id: types::ContentClassificationId,
+    /// Localized description of the CCL.
+    pub description: String,
+    /// Localized name of the CCL.
+    pub name: String,
+}
+
+impl Request for GetContentClassificationLabelsRequest {
+    type Response = Vec<ContentClassificationLabel>;
+
+    const PATH: &'static str = "content_classification_labels";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetContentClassificationLabelsRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetContentClassificationLabelsRequest::builder()
+        .locale("en-US".to_string())
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "DrugsIntoxication",
+                "description": "Excessive tobacco glorification or promotion, any marijuana consumption/use, legal drug and alcohol induced intoxication, discussions of illegal drugs.",
+                "name": "Drugs, Intoxication, or Excessive Tobacco Use"
+            },
+            {
+                "id": "SexualThemes",
+                "description": "Content that focuses on sexualized physical attributes and activities, sexual topics, or experiences.",
+                "name": "Sexual Themes"
+            },
+            {
+                "id": "HypotheticalNewLabel",
+                "description": "A label this crate does not know about yet.",
+                "name": "New Label"
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/content_classification_labels?locale=en-US"
+    );
+
+    let res = GetContentClassificationLabelsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res[0].id, types::ContentClassificationId::DrugsIntoxication);
+    assert_eq!(
+        res[2].id,
+        types::ContentClassificationId::Other("HypotheticalNewLabel".to_string())
+    );
+}
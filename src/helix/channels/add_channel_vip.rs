@@ -0,0 +1,174 @@
+//! Adds the specified user as a VIP in the broadcaster’s channel.
+//! [`add-channel-vip`](https://dev.twitch.tv/docs/api/reference#add-channel-vip)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [AddChannelVipRequest]
+//!
+//! To use this endpoint, construct an [`AddChannelVipRequest`] with the [`AddChannelVipRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::channels::add_channel_vip;
+//! let request = add_channel_vip::AddChannelVipRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .user_id("5678")
+//!     .build();
+//! ```
+//!
+//! ## Response: [AddChannelVip]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, channels::add_channel_vip};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = add_channel_vip::AddChannelVipRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .user_id("5678")
+//!     .build();
+//! let response: add_channel_vip::AddChannelVip = client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`AddChannelVipRequest::parse_response(None, &request.get_uri(), response)`](AddChannelVipRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Add Channel VIP](super::add_channel_vip)
+///
+/// [`add-channel-vip`](https://dev.twitch.tv/docs/api/reference#add-channel-vip)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct AddChannelVipRequest {
+    /// The ID of the broadcaster that’s adding the user as a VIP. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the user to give VIP status to.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+}
+
+/// Return Values for [Add Channel VIP](super::add_channel_vip)
+///
+/// [`add-channel-vip`](https://dev.twitch.tv/docs/api/reference#add-channel-vip)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum AddChannelVip {
+    /// 204 - Successfully added the VIP.
+    Success,
+    /// 400 - One of the IDs is missing or invalid.
+    BadRequest(String),
+    /// 401 - Authorization failed.
+    AuthFailed(String),
+    /// 403 - The user in `broadcaster_id` doesn’t have permission to assign VIPs.
+    Forbidden(String),
+    /// 404 - The user in `user_id` was not found.
+    NotFound(String),
+    /// 409 - The user is a moderator. To make them a VIP, they must first be removed as a moderator.
+    UserIsModerator(String),
+    /// 422 - The user is already a VIP.
+    AlreadyVip(String),
+    /// 425 - The broadcaster must complete the Build a Community requirement before they may assign VIPs.
+    TooEarly(String),
+    /// 429 - The broadcaster doesn’t have available VIP slots or has exceeded the rate limit.
+    TooManyRequests(String),
+}
+
+impl Request for AddChannelVipRequest {
+    type Response = AddChannelVip;
+
+    const PATH: &'static str = "channels/vips";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:vips"),
+    )];
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status.as_u16() {
+            400 => Some(AddChannelVip::BadRequest(message)),
+            401 => Some(AddChannelVip::AuthFailed(message)),
+            403 => Some(AddChannelVip::Forbidden(message)),
+            404 => Some(AddChannelVip::NotFound(message)),
+            409 => Some(AddChannelVip::UserIsModerator(message)),
+            422 => Some(AddChannelVip::AlreadyVip(message)),
+            425 => Some(AddChannelVip::TooEarly(message)),
+            429 => Some(AddChannelVip::TooManyRequests(message)),
+            _ => None,
+        }
+    }
+}
+
+impl RequestPost for AddChannelVipRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let data = match status {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => AddChannelVip::Success,
+            _ => {
+                return Err(helix::HelixRequestPostError::InvalidResponse {
+                    reason: "unexpected status code",
+                    response: response.to_string(),
+                    status,
+                    uri: uri.clone(),
+                })
+            }
+        };
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = AddChannelVipRequest::builder()
+        .broadcaster_id("123")
+        .user_id("456")
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/channels/vips?broadcaster_id=123&user_id=456"
+    );
+
+    let res = AddChannelVipRequest::parse_response(Some(req.clone()), &uri, http_response).unwrap();
+    assert_eq!(res.data, AddChannelVip::Success);
+
+    let data =
+        br#"{"error":"Unprocessable Entity","status":422,"message":"The user is already a VIP."}"#
+            .to_vec();
+    let http_response = http::Response::builder().status(422).body(data).unwrap();
+    let res = AddChannelVipRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        res.data,
+        AddChannelVip::AlreadyVip("The user is already a VIP.".to_string())
+    );
+}
@@ -0,0 +1,191 @@
+//! Gets a list of users that follow the specified broadcaster.
+//! [`get-channel-followers`](https://dev.twitch.tv/docs/api/reference#get-channel-followers)
+//!
+//! # Notes
+//!
+//! The token must be the broadcaster's or one of their moderators' with the `moderator:read:followers` scope to get the list of followers.
+//! Otherwise only [`total`](ChannelFollowers::total) is populated.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetChannelFollowersRequest]
+//!
+//! To use this endpoint, construct a [`GetChannelFollowersRequest`] with the [`GetChannelFollowersRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::channels::get_channel_followers;
+//! let request = get_channel_followers::GetChannelFollowersRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [ChannelFollowers]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, channels::get_channel_followers};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_channel_followers::GetChannelFollowersRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let response: get_channel_followers::ChannelFollowers = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetChannelFollowersRequest::parse_response(None, &request.get_uri(), response)`](GetChannelFollowersRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Channel Followers](super::get_channel_followers)
+///
+/// [`get-channel-followers`](https://dev.twitch.tv/docs/api/reference#get-channel-followers)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetChannelFollowersRequest {
+    /// A user’s ID. Use this parameter to see whether the user follows this broadcaster.
+    #[builder(default, setter(into))]
+    pub user_id: Option<types::UserId>,
+    /// The broadcaster’s ID. Returns the list of users that follow this broadcaster.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// Maximum number of objects to return. Maximum: 100. Default: 20.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+}
+
+/// Return Values for [Get Channel Followers](super::get_channel_followers)
+///
+/// [`get-channel-followers`](https://dev.twitch.tv/docs/api/reference#get-channel-followers)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ChannelFollowers {
+    /// The total number of users that follow this broadcaster.
+    ///
+    /// If [`user_id`](GetChannelFollowersRequest::user_id) was in the request, this is 1 if the user follows the broadcaster, otherwise 0.
+    pub total: i64,
+    /// The followers returned on this page. See [Response::get_next](helix::Response::get_next) for getting more pages
+    pub followers: Vec<Follower>,
+}
+
+/// A user following a broadcaster
+///
+/// Used in [ChannelFollowers]
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Follower {
+    /// The UTC timestamp when the user started following the broadcaster.
+    pub followed_at: types::Timestamp,
+    /// An ID that uniquely identifies the user that’s following the broadcaster.
+    pub user_id: types::UserId,
+    /// The user’s login name.
+    pub user_login: types::UserName,
+    /// The user’s display name.
+    pub user_name: types::DisplayName,
+}
+
+impl Request for GetChannelFollowersRequest {
+    type Response = ChannelFollowers;
+
+    const PATH: &'static str = "channels/followers";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:read:followers"),
+    )];
+}
+
+impl RequestGet for GetChannelFollowersRequest {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        #[derive(PartialEq, Deserialize, Debug, Clone)]
+        struct InnerResponse {
+            data: Vec<Follower>,
+            total: i64,
+            #[serde(default)]
+            pagination: helix::Pagination,
+        }
+
+        let response: InnerResponse = helix::parse_json(response).map_err(|e| {
+            helix::HelixRequestGetError::DeserializeError(
+                response.to_string(),
+                e,
+                uri.clone(),
+                status,
+            )
+        })?;
+        Ok(helix::Response {
+            data: ChannelFollowers {
+                total: response.total,
+                followers: response.data,
+            },
+            pagination: response.pagination.cursor,
+            request,
+            total: Some(response.total),
+            other: None,
+        })
+    }
+}
+
+impl helix::Paginated for GetChannelFollowersRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChannelFollowersRequest::builder()
+        .broadcaster_id("123456")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "total": 8,
+        "data": [
+          {
+            "user_id": "11111",
+            "user_name": "UserDisplayName",
+            "user_login": "userloginname",
+            "followed_at": "2022-05-24T22:22:08Z"
+          }
+        ],
+        "pagination": {
+          "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
+        }
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/channels/followers?broadcaster_id=123456"
+    );
+
+    let res = GetChannelFollowersRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data.total, 8);
+    assert_eq!(res.data.followers.len(), 1);
+    assert!(res.pagination.is_some());
+}
@@ -74,6 +74,18 @@ pub struct ChannelInformation {
     /// Description of the stream
     #[serde(default)]
     pub description: String,
+    /// Stream delay in seconds. Only returned for the broadcaster with a token that has the `channel:manage:broadcast` scope, otherwise `0`.
+    #[serde(default)]
+    pub delay: i64,
+    /// The tags applied to the channel.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The CCLs applied to the channel.
+    #[serde(default)]
+    pub content_classification_labels: Vec<types::ContentClassificationId>,
+    /// Boolean flag indicating if the channel has branded content.
+    #[serde(default)]
+    pub is_branded_content: bool,
 }
 
 impl Request for GetChannelInformationRequest {
@@ -107,6 +119,8 @@ impl RequestGet for GetChannelInformationRequest {
             data: response.data.into_iter().next(),
             pagination: response.pagination.cursor,
             request,
+            total: None,
+            other: None,
         })
     }
 }
@@ -128,7 +142,11 @@ fn test_request() {
               "broadcaster_language": "en",
               "game_id": "21779",
               "game_name": "League of Legends",
-              "title": "title"
+              "title": "title",
+              "delay": 0,
+              "tags": ["LevelingUp"],
+              "content_classification_labels": ["Gambling", "DrugsIntoxication", "MatureGame"],
+              "is_branded_content": false
             }
           ]
         }
@@ -143,5 +161,13 @@ fn test_request() {
         "https://api.twitch.tv/helix/channels?broadcaster_id=44445592"
     );
 
-    dbg!(GetChannelInformationRequest::parse_response(Some(req), &uri, http_response).unwrap());
+    let res = GetChannelInformationRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(res.tags, vec!["LevelingUp".to_string()]);
+    assert_eq!(
+        res.content_classification_labels[0],
+        types::ContentClassificationId::Gambling
+    );
 }
@@ -0,0 +1,183 @@
+//! Gets a list of broadcasters that the specified user follows.
+//! [`get-followed-channels`](https://dev.twitch.tv/docs/api/reference#get-followed-channels)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetFollowedChannelsRequest]
+//!
+//! To use this endpoint, construct a [`GetFollowedChannelsRequest`] with the [`GetFollowedChannelsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::channels::get_followed_channels;
+//! let request = get_followed_channels::GetFollowedChannelsRequest::builder()
+//!     .user_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [FollowedChannels]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, channels::get_followed_channels};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_followed_channels::GetFollowedChannelsRequest::builder()
+//!     .user_id("1234")
+//!     .build();
+//! let response: get_followed_channels::FollowedChannels = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetFollowedChannelsRequest::parse_response(None, &request.get_uri(), response)`](GetFollowedChannelsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Followed Channels](super::get_followed_channels)
+///
+/// [`get-followed-channels`](https://dev.twitch.tv/docs/api/reference#get-followed-channels)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetFollowedChannelsRequest {
+    /// A user’s ID. Returns the list of broadcasters that this user follows. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+    /// A broadcaster’s ID. Use this parameter to see whether the user follows this broadcaster.
+    #[builder(default, setter(into))]
+    pub broadcaster_id: Option<types::UserId>,
+    /// Maximum number of objects to return. Maximum: 100. Default: 20.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+}
+
+/// Return Values for [Get Followed Channels](super::get_followed_channels)
+///
+/// [`get-followed-channels`](https://dev.twitch.tv/docs/api/reference#get-followed-channels)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct FollowedChannels {
+    /// The total number of broadcasters that the user follows.
+    ///
+    /// If [`broadcaster_id`](GetFollowedChannelsRequest::broadcaster_id) was in the request, this is 1 if the user follows the broadcaster, otherwise 0.
+    pub total: i64,
+    /// The followed broadcasters returned on this page. See [Response::get_next](helix::Response::get_next) for getting more pages
+    pub channels: Vec<FollowedChannel>,
+}
+
+/// A broadcaster followed by a user
+///
+/// Used in [FollowedChannels]
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct FollowedChannel {
+    /// An ID that uniquely identifies the broadcaster that this user is following.
+    pub broadcaster_id: types::UserId,
+    /// The broadcaster’s login name.
+    pub broadcaster_login: types::UserName,
+    /// The broadcaster’s display name.
+    pub broadcaster_name: types::DisplayName,
+    /// The UTC timestamp when the user started following the broadcaster.
+    pub followed_at: types::Timestamp,
+}
+
+impl Request for GetFollowedChannelsRequest {
+    type Response = FollowedChannels;
+
+    const PATH: &'static str = "channels/followed";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadFollows];
+}
+
+impl RequestGet for GetFollowedChannelsRequest {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        #[derive(PartialEq, Deserialize, Debug, Clone)]
+        struct InnerResponse {
+            data: Vec<FollowedChannel>,
+            total: i64,
+            #[serde(default)]
+            pagination: helix::Pagination,
+        }
+
+        let response: InnerResponse = helix::parse_json(response).map_err(|e| {
+            helix::HelixRequestGetError::DeserializeError(
+                response.to_string(),
+                e,
+                uri.clone(),
+                status,
+            )
+        })?;
+        Ok(helix::Response {
+            data: FollowedChannels {
+                total: response.total,
+                channels: response.data,
+            },
+            pagination: response.pagination.cursor,
+            request,
+            total: Some(response.total),
+            other: None,
+        })
+    }
+}
+
+impl helix::Paginated for GetFollowedChannelsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetFollowedChannelsRequest::builder()
+        .user_id("123456")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "total": 8,
+        "data": [
+          {
+            "broadcaster_id": "11111",
+            "broadcaster_login": "userloginname",
+            "broadcaster_name": "UserDisplayName",
+            "followed_at": "2022-05-24T22:22:08Z"
+          }
+        ],
+        "pagination": {
+          "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
+        }
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/channels/followed?user_id=123456"
+    );
+
+    let res = GetFollowedChannelsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data.total, 8);
+    assert_eq!(res.data.channels[0].broadcaster_login, "userloginname");
+}
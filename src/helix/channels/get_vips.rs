@@ -0,0 +1,132 @@
+//! Gets a list of the broadcaster’s VIPs.
+//! [`get-vips`](https://dev.twitch.tv/docs/api/reference#get-vips)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetVipsRequest]
+//!
+//! To use this endpoint, construct a [`GetVipsRequest`] with the [`GetVipsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::channels::get_vips;
+//! let request = get_vips::GetVipsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [Vip]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, channels::get_vips};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_vips::GetVipsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let response: Vec<get_vips::Vip> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetVipsRequest::parse_response(None, &request.get_uri(), response)`](GetVipsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get VIPs](super::get_vips)
+///
+/// [`get-vips`](https://dev.twitch.tv/docs/api/reference#get-vips)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetVipsRequest {
+    /// The ID of the broadcaster whose list of VIPs you want to get. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// Filters the list for specific VIPs. Maximum: 100
+    #[builder(default, setter(into))]
+    pub user_id: Vec<types::UserId>,
+    /// Maximum number of objects to return. Maximum: 100. Default: 20.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+}
+
+/// Return Values for [Get VIPs](super::get_vips)
+///
+/// [`get-vips`](https://dev.twitch.tv/docs/api/reference#get-vips)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Vip {
+    /// An ID that uniquely identifies the VIP user.
+    pub user_id: types::UserId,
+    /// The user’s display name.
+    pub user_name: types::DisplayName,
+    /// The user’s login name.
+    pub user_login: types::UserName,
+}
+
+impl Request for GetVipsRequest {
+    type Response = Vec<Vip>;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:vips"),
+    )];
+    const PATH: &'static str = "channels/vips";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:read:vips"),
+    )];
+}
+
+impl RequestGet for GetVipsRequest {}
+
+impl helix::Paginated for GetVipsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetVipsRequest::builder()
+        .broadcaster_id("123")
+        .user_id(vec!["456".to_string(), "678".to_string()])
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+          {
+            "user_id": "11111",
+            "user_name": "UserDisplayName",
+            "user_login": "userloginname"
+          }
+        ],
+        "pagination": {
+          "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
+        }
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/channels/vips?broadcaster_id=123&user_id=456&user_id=678"
+    );
+
+    dbg!(GetVipsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
@@ -82,6 +82,39 @@ pub struct ModifyChannelInformationBody {
     /// Title of the stream. Value must not be an empty string.
     #[builder(default, setter(into))]
     pub title: Option<String>,
+    /// Stream delay in seconds. Stream delay is a Twitch Partner feature, trying to set this value for other account types will return a 400 error. Maximum: 900.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<i64>,
+    /// A list of channel-defined tags to apply to the channel. To remove all tags from the channel, set tags to an empty array. Maximum: 10 tags, each at most 25 characters.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// List of labels that should be set as the Channel’s CCLs.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_classification_labels: Option<Vec<ContentClassificationLabelSetting>>,
+    /// Boolean flag indicating if the channel has branded content.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_branded_content: Option<bool>,
+}
+
+/// A content classification label to enable or disable in [`ModifyChannelInformationBody`]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct ContentClassificationLabelSetting {
+    /// ID of the Content Classification Labels that must be added/removed from the channel.
+    pub id: types::ContentClassificationId,
+    /// Boolean flag indicating whether the label should be enabled (true) or disabled for the channel.
+    pub is_enabled: bool,
+}
+
+impl ContentClassificationLabelSetting {
+    /// Create a new setting for a content classification label
+    pub fn new(id: types::ContentClassificationId, is_enabled: bool) -> Self {
+        ContentClassificationLabelSetting { id, is_enabled }
+    }
 }
 
 impl helix::private::SealedSerialize for ModifyChannelInformationBody {}
@@ -139,6 +172,8 @@ impl RequestPatch for ModifyChannelInformationRequest {
             },
             pagination: None,
             request,
+            total: None,
+            other: None,
         })
     }
 }
@@ -156,6 +191,32 @@ fn test_request() {
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
+    let body = ModifyChannelInformationBody::builder()
+        .tags(vec!["English".to_string()])
+        .content_classification_labels(vec![
+            ContentClassificationLabelSetting::new(types::ContentClassificationId::Gambling, true),
+            ContentClassificationLabelSetting::new(
+                types::ContentClassificationId::ProfanityVulgarity,
+                false,
+            ),
+        ])
+        .is_branded_content(true)
+        .build();
+    assert_eq!(
+        serde_json::to_value(&body).unwrap(),
+        serde_json::json!({
+            "game_id": null,
+            "broadcaster_language": null,
+            "title": null,
+            "tags": ["English"],
+            "content_classification_labels": [
+                {"id": "Gambling", "is_enabled": true},
+                {"id": "ProfanityVulgarity", "is_enabled": false}
+            ],
+            "is_branded_content": true
+        })
+    );
+
     // From twitch docs
     let data = br#""#.to_vec();
 
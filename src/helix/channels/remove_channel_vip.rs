@@ -0,0 +1,160 @@
+//! Removes the specified user as a VIP in the broadcaster’s channel.
+//! [`remove-channel-vip`](https://dev.twitch.tv/docs/api/reference#remove-channel-vip)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [RemoveChannelVipRequest]
+//!
+//! To use this endpoint, construct a [`RemoveChannelVipRequest`] with the [`RemoveChannelVipRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::channels::remove_channel_vip;
+//! let request = remove_channel_vip::RemoveChannelVipRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .user_id("5678")
+//!     .build();
+//! ```
+//!
+//! ## Response: [RemoveChannelVip]
+//!
+//! Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, channels::remove_channel_vip};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = remove_channel_vip::RemoveChannelVipRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .user_id("5678")
+//!     .build();
+//! let response: remove_channel_vip::RemoveChannelVip = client.req_delete(request, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+//! and parse the [`http::Response`] with [`RemoveChannelVipRequest::parse_response(&request.get_uri(), response)`](RemoveChannelVipRequest::parse_response)
+
+use super::*;
+use helix::RequestDelete;
+
+/// Query Parameters for [Remove Channel VIP](super::remove_channel_vip)
+///
+/// [`remove-channel-vip`](https://dev.twitch.tv/docs/api/reference#remove-channel-vip)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct RemoveChannelVipRequest {
+    /// The ID of the broadcaster who owns the channel where the user has VIP status. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the user to remove VIP status from.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+}
+
+/// Return Values for [Remove Channel VIP](super::remove_channel_vip)
+///
+/// [`remove-channel-vip`](https://dev.twitch.tv/docs/api/reference#remove-channel-vip)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum RemoveChannelVip {
+    /// 204 - Successfully removed the VIP status from the user.
+    Success,
+    /// 400 - One of the IDs is missing or invalid.
+    BadRequest(String),
+    /// 401 - Authorization failed.
+    AuthFailed(String),
+    /// 403 - The user in `broadcaster_id` doesn’t have permission to remove the user’s VIP status.
+    Forbidden(String),
+    /// 404 - The user in `user_id` was not found.
+    NotFound(String),
+    /// 422 - The user in `user_id` is not a VIP in the broadcaster’s channel.
+    NotVip(String),
+    /// 429 - The broadcaster exceeded the number of VIPs they may remove.
+    TooManyRequests(String),
+}
+
+impl std::convert::TryFrom<http::StatusCode> for RemoveChannelVip {
+    type Error = std::borrow::Cow<'static, str>;
+
+    fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+        match s {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => Ok(RemoveChannelVip::Success),
+            other => Err(other.canonical_reason().unwrap_or("").into()),
+        }
+    }
+}
+
+impl Request for RemoveChannelVipRequest {
+    type Response = RemoveChannelVip;
+
+    const PATH: &'static str = "channels/vips";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:vips"),
+    )];
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status {
+            http::StatusCode::BAD_REQUEST => Some(RemoveChannelVip::BadRequest(message)),
+            http::StatusCode::UNAUTHORIZED => Some(RemoveChannelVip::AuthFailed(message)),
+            http::StatusCode::FORBIDDEN => Some(RemoveChannelVip::Forbidden(message)),
+            http::StatusCode::NOT_FOUND => Some(RemoveChannelVip::NotFound(message)),
+            http::StatusCode::UNPROCESSABLE_ENTITY => Some(RemoveChannelVip::NotVip(message)),
+            http::StatusCode::TOO_MANY_REQUESTS => Some(RemoveChannelVip::TooManyRequests(message)),
+            _ => None,
+        }
+    }
+}
+
+impl RequestDelete for RemoveChannelVipRequest {
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestDeleteError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = RemoveChannelVipRequest::builder()
+        .broadcaster_id("123")
+        .user_id("456")
+        .build();
+
+    dbg!(req.create_request("token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/channels/vips?broadcaster_id=123&user_id=456"
+    );
+
+    assert_eq!(
+        RemoveChannelVipRequest::parse_response(&uri, http_response).unwrap(),
+        RemoveChannelVip::Success
+    );
+
+    let data =
+        br#"{"error":"Unprocessable Entity","status":422,"message":"The user is not a VIP."}"#
+            .to_vec();
+    let http_response = http::Response::builder().status(422).body(data).unwrap();
+    assert_eq!(
+        RemoveChannelVipRequest::parse_response(&uri, http_response).unwrap(),
+        RemoveChannelVip::NotVip("The user is not a VIP.".to_string())
+    );
+}
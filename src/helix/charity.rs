@@ -0,0 +1,37 @@
+//! Helix endpoints regarding charity campaigns
+//!
+//! Amounts are represented with [`types::Amount`].
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, charity::GetCharityCampaignRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetCharityCampaignRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//!
+//! for campaign in client.req_get(req, &token).await?.data {
+//!     println!("{}: {} raised", campaign.charity_name, campaign.current_amount);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod get_charity_campaign;
+pub mod get_charity_campaign_donations;
+
+#[doc(inline)]
+pub use get_charity_campaign::{CharityCampaign, GetCharityCampaignRequest};
+#[doc(inline)]
+pub use get_charity_campaign_donations::{CharityDonation, GetCharityCampaignDonationsRequest};
@@ -0,0 +1,147 @@
+//! Gets information about the charity campaign that a broadcaster is running.
+//! [`get-charity-campaign`](https://dev.twitch.tv/docs/api/reference#get-charity-campaign)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetCharityCampaignRequest]
+//!
+//! To use this endpoint, construct a [`GetCharityCampaignRequest`] with the [`GetCharityCampaignRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::charity::get_charity_campaign;
+//! let request = get_charity_campaign::GetCharityCampaignRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [CharityCampaign]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, charity::get_charity_campaign};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_charity_campaign::GetCharityCampaignRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let response: Vec<get_charity_campaign::CharityCampaign> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetCharityCampaignRequest::parse_response(None, &request.get_uri(), response)`](GetCharityCampaignRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Charity Campaign](super::get_charity_campaign)
+///
+/// [`get-charity-campaign`](https://dev.twitch.tv/docs/api/reference#get-charity-campaign)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetCharityCampaignRequest {
+    /// The ID of the broadcaster that’s currently running a charity campaign. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+}
+
+/// Return Values for [Get Charity Campaign](super::get_charity_campaign)
+///
+/// [`get-charity-campaign`](https://dev.twitch.tv/docs/api/reference#get-charity-campaign)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CharityCampaign {
+    /// An ID that identifies the charity campaign.
+    pub id: String,
+    /// An ID that identifies the broadcaster that’s running the campaign.
+    pub broadcaster_id: types::UserId,
+    /// The broadcaster’s login name.
+    pub broadcaster_login: types::UserName,
+    /// The broadcaster’s display name.
+    pub broadcaster_name: types::DisplayName,
+    /// The charity’s name.
+    pub charity_name: String,
+    /// A description of the charity.
+    pub charity_description: String,
+    /// A URL to an image of the charity’s logo. The image’s type is PNG and its size is 100px X 100px.
+    pub charity_logo: String,
+    /// A URL to the charity’s website.
+    pub charity_website: String,
+    /// The current amount of donations that the campaign has received.
+    pub current_amount: types::Amount,
+    /// The campaign’s fundraising goal. Is [`None`] if the broadcaster has not defined a fundraising goal.
+    pub target_amount: Option<types::Amount>,
+}
+
+impl Request for GetCharityCampaignRequest {
+    type Response = Vec<CharityCampaign>;
+
+    const PATH: &'static str = "charity/campaigns";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:read:charity"),
+    )];
+}
+
+impl RequestGet for GetCharityCampaignRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetCharityCampaignRequest::builder()
+        .broadcaster_id("123456")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+          {
+            "id": "123-abc-456-def",
+            "broadcaster_id": "123456",
+            "broadcaster_name": "SunnySideUp",
+            "broadcaster_login": "sunnysideup",
+            "charity_name": "Example name",
+            "charity_description": "Example description",
+            "charity_logo": "https://abc.cloudfront.net/ppgf/1000/100.png",
+            "charity_website": "https://www.example.com",
+            "current_amount": {
+              "value": 86000,
+              "decimal_places": 2,
+              "currency": "USD"
+            },
+            "target_amount": {
+              "value": 1500000,
+              "decimal_places": 2,
+              "currency": "USD"
+            }
+          }
+        ]
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/charity/campaigns?broadcaster_id=123456"
+    );
+
+    let res = GetCharityCampaignRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res[0].current_amount.to_string(), "860.00");
+    assert_eq!(
+        res[0].target_amount.as_ref().map(|a| a.to_string()),
+        Some("15000.00".to_string())
+    );
+}
@@ -0,0 +1,152 @@
+//! Gets the list of donations that users have made to the broadcaster’s active charity campaign.
+//! [`get-charity-campaign-donations`](https://dev.twitch.tv/docs/api/reference#get-charity-campaign-donations)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetCharityCampaignDonationsRequest]
+//!
+//! To use this endpoint, construct a [`GetCharityCampaignDonationsRequest`] with the [`GetCharityCampaignDonationsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::charity::get_charity_campaign_donations;
+//! let request = get_charity_campaign_donations::GetCharityCampaignDonationsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [CharityDonation]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, charity::get_charity_campaign_donations};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_charity_campaign_donations::GetCharityCampaignDonationsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let response: Vec<get_charity_campaign_donations::CharityDonation> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetCharityCampaignDonationsRequest::parse_response(None, &request.get_uri(), response)`](GetCharityCampaignDonationsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Charity Campaign Donations](super::get_charity_campaign_donations)
+///
+/// [`get-charity-campaign-donations`](https://dev.twitch.tv/docs/api/reference#get-charity-campaign-donations)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetCharityCampaignDonationsRequest {
+    /// The ID of the broadcaster that’s currently running a charity campaign. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+    /// Maximum number of objects to return. Maximum: 100. Default: 20.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+}
+
+/// Return Values for [Get Charity Campaign Donations](super::get_charity_campaign_donations)
+///
+/// [`get-charity-campaign-donations`](https://dev.twitch.tv/docs/api/reference#get-charity-campaign-donations)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CharityDonation {
+    /// An ID that identifies the donation. The ID is unique across campaigns.
+    pub id: String,
+    /// An ID that identifies the charity campaign that the donation applies to.
+    pub campaign_id: String,
+    /// An ID that identifies a user that donated money to the campaign.
+    pub user_id: types::UserId,
+    /// The user’s login name.
+    pub user_login: types::UserName,
+    /// The user’s display name.
+    pub user_name: types::DisplayName,
+    /// The amount of money that the user donated.
+    pub amount: types::Amount,
+}
+
+impl Request for GetCharityCampaignDonationsRequest {
+    type Response = Vec<CharityDonation>;
+
+    const PATH: &'static str = "charity/donations";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:read:charity"),
+    )];
+}
+
+impl RequestGet for GetCharityCampaignDonationsRequest {}
+
+impl helix::Paginated for GetCharityCampaignDonationsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetCharityCampaignDonationsRequest::builder()
+        .broadcaster_id("123456")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+          {
+            "id": "a1b2c3-aabb-4455-d1e2f3",
+            "campaign_id": "123-abc-456-def",
+            "user_id": "5678",
+            "user_login": "cool_user",
+            "user_name": "Cool_User",
+            "amount": {
+              "value": 500,
+              "decimal_places": 2,
+              "currency": "USD"
+            }
+          },
+          {
+            "id": "z1y2x3-ccdd-6677-d1e2f3",
+            "campaign_id": "123-abc-456-def",
+            "user_id": "8765",
+            "user_login": "cool_user2",
+            "user_name": "Cool_User2",
+            "amount": {
+              "value": 10000,
+              "decimal_places": 2,
+              "currency": "USD"
+            }
+          }
+        ],
+        "pagination": {
+          "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
+        }
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/charity/donations?broadcaster_id=123456"
+    );
+
+    let res =
+        GetCharityCampaignDonationsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert!(res.pagination.is_some());
+    assert_eq!(res.data[1].amount.to_string(), "100.00");
+}
@@ -0,0 +1,44 @@
+//! Helix endpoints regarding chat
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, chat::{AnnouncementColor, SendChatAnnouncementBody, SendChatAnnouncementRequest}};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = SendChatAnnouncementRequest::builder()
+//!     .broadcaster_id("11111")
+//!     .moderator_id("44444")
+//!     .build();
+//! let body = SendChatAnnouncementBody::builder()
+//!     .message("Hello chat!")
+//!     .color(AnnouncementColor::Purple)
+//!     .build();
+//!
+//! println!("{:?}", &client.req_post(req, body, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod get_chatters;
+pub mod send_a_shoutout;
+pub mod send_chat_announcement;
+
+#[doc(inline)]
+pub use get_chatters::{Chatter, GetChattersRequest};
+#[doc(inline)]
+pub use send_a_shoutout::{SendAShoutout, SendAShoutoutRequest};
+#[doc(inline)]
+pub use send_chat_announcement::{
+    AnnouncementColor, SendChatAnnouncement, SendChatAnnouncementBody, SendChatAnnouncementRequest,
+};
@@ -0,0 +1,139 @@
+//! Gets the list of users that are connected to the broadcaster’s chat session.
+//! [`get-chatters`](https://dev.twitch.tv/docs/api/reference#get-chatters)
+//!
+//! # Notes
+//!
+//! There is a delay between when users join and leave a chat and when the list is updated accordingly.
+//! The total number of connected users is returned in [`Response::total`](helix::Response::total).
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetChattersRequest]
+//!
+//! To use this endpoint, construct a [`GetChattersRequest`] with the [`GetChattersRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::chat::get_chatters;
+//! let request = get_chatters::GetChattersRequest::builder()
+//!     .broadcaster_id("123456")
+//!     .moderator_id("654321")
+//!     .build();
+//! ```
+//!
+//! ## Response: [Chatter]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, chat::get_chatters};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_chatters::GetChattersRequest::builder()
+//!     .broadcaster_id("123456")
+//!     .moderator_id("654321")
+//!     .build();
+//! let response: Vec<get_chatters::Chatter> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetChattersRequest::parse_response(None, &request.get_uri(), response)`](GetChattersRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Chatters](super::get_chatters)
+///
+/// [`get-chatters`](https://dev.twitch.tv/docs/api/reference#get-chatters)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetChattersRequest {
+    /// The ID of the broadcaster whose list of chatters you want to get.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or one of the broadcaster’s moderators. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+    /// The maximum number of items to return per page in the response. Maximum: 1000. Default: 100.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+}
+
+/// Return Values for [Get Chatters](super::get_chatters)
+///
+/// [`get-chatters`](https://dev.twitch.tv/docs/api/reference#get-chatters)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Chatter {
+    /// The ID of a user that’s connected to the broadcaster’s chat room.
+    pub user_id: types::UserId,
+    /// The user’s login name.
+    pub user_login: types::UserName,
+    /// The user’s display name.
+    pub user_name: types::DisplayName,
+}
+
+impl Request for GetChattersRequest {
+    type Response = Vec<Chatter>;
+
+    const PATH: &'static str = "chat/chatters";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:read:chatters"),
+    )];
+}
+
+impl RequestGet for GetChattersRequest {}
+
+impl helix::Paginated for GetChattersRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChattersRequest::builder()
+        .broadcaster_id("123456")
+        .moderator_id("654321")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "user_id": "128393656",
+                "user_login": "smittysmithers",
+                "user_name": "smittysmithers"
+            }
+        ],
+        "pagination": {
+            "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19"
+        },
+        "total": 8
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/chat/chatters?broadcaster_id=123456&moderator_id=654321"
+    );
+
+    let res = GetChattersRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.total, Some(8));
+    assert_eq!(res.data.len(), 1);
+    assert_eq!(res.data[0].user_login, "smittysmithers");
+}
@@ -0,0 +1,171 @@
+//! Sends a Shoutout to the specified broadcaster.
+//! [`send-a-shoutout`](https://dev.twitch.tv/docs/api/reference#send-a-shoutout)
+//!
+//! The broadcaster may send a Shoutout once every 2 minutes. They may send the same broadcaster a Shoutout once every 60 minutes.
+//! Exceeding either results in [`SendAShoutout::TooManyRequests`].
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SendAShoutoutRequest]
+//!
+//! To use this endpoint, construct a [`SendAShoutoutRequest`] with the [`SendAShoutoutRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::chat::send_a_shoutout;
+//! let request = send_a_shoutout::SendAShoutoutRequest::builder()
+//!     .from_broadcaster_id("12345")
+//!     .to_broadcaster_id("626262")
+//!     .moderator_id("98765")
+//!     .build();
+//! ```
+//!
+//! ## Response: [SendAShoutout]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, chat::send_a_shoutout};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = send_a_shoutout::SendAShoutoutRequest::builder()
+//!     .from_broadcaster_id("12345")
+//!     .to_broadcaster_id("626262")
+//!     .moderator_id("98765")
+//!     .build();
+//! let response: send_a_shoutout::SendAShoutout = client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`SendAShoutoutRequest::parse_response(None, &request.get_uri(), response)`](SendAShoutoutRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Send a Shoutout](super::send_a_shoutout)
+///
+/// [`send-a-shoutout`](https://dev.twitch.tv/docs/api/reference#send-a-shoutout)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct SendAShoutoutRequest {
+    /// The ID of the broadcaster that’s sending the Shoutout.
+    #[builder(setter(into))]
+    pub from_broadcaster_id: types::UserId,
+    /// The ID of the broadcaster that’s receiving the Shoutout.
+    #[builder(setter(into))]
+    pub to_broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that is one of the broadcaster’s moderators. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+}
+
+/// Return Values for [Send a Shoutout](super::send_a_shoutout)
+///
+/// [`send-a-shoutout`](https://dev.twitch.tv/docs/api/reference#send-a-shoutout)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum SendAShoutout {
+    /// 204 - Successfully sent the specified broadcaster a Shoutout.
+    Success,
+    /// 400 - The broadcaster may not give themselves a Shoutout, or the broadcaster is not streaming live or does not have one or more viewers.
+    BadRequest(String),
+    /// 401 - Authorization failed.
+    AuthFailed(String),
+    /// 403 - The user in `moderator_id` is not one of the broadcaster’s moderators, or the broadcaster may not send the specified broadcaster a Shoutout.
+    Forbidden(String),
+    /// 429 - The broadcaster is sending Shoutouts too quickly, or already sent this broadcaster a Shoutout in the last 60 minutes.
+    TooManyRequests(String),
+}
+
+impl Request for SendAShoutoutRequest {
+    type Response = SendAShoutout;
+
+    const PATH: &'static str = "chat/shoutouts";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:shoutouts"),
+    )];
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status {
+            http::StatusCode::BAD_REQUEST => Some(SendAShoutout::BadRequest(message)),
+            http::StatusCode::UNAUTHORIZED => Some(SendAShoutout::AuthFailed(message)),
+            http::StatusCode::FORBIDDEN => Some(SendAShoutout::Forbidden(message)),
+            http::StatusCode::TOO_MANY_REQUESTS => Some(SendAShoutout::TooManyRequests(message)),
+            _ => None,
+        }
+    }
+}
+
+impl RequestPost for SendAShoutoutRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let data = match status {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => SendAShoutout::Success,
+            _ => {
+                return Err(helix::HelixRequestPostError::InvalidResponse {
+                    reason: "unexpected status code",
+                    response: response.to_string(),
+                    status,
+                    uri: uri.clone(),
+                })
+            }
+        };
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SendAShoutoutRequest::builder()
+        .from_broadcaster_id("12345")
+        .to_broadcaster_id("626262")
+        .moderator_id("98765")
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/chat/shoutouts?from_broadcaster_id=12345&to_broadcaster_id=626262&moderator_id=98765"
+    );
+
+    let res = SendAShoutoutRequest::parse_response(Some(req.clone()), &uri, http_response).unwrap();
+    assert_eq!(res.data, SendAShoutout::Success);
+
+    let data = br#"{"error":"Forbidden","status":403,"message":"The broadcaster may not send the specified broadcaster a Shoutout."}"#.to_vec();
+    let http_response = http::Response::builder().status(403).body(data).unwrap();
+    let res = SendAShoutoutRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        res.data,
+        SendAShoutout::Forbidden(
+            "The broadcaster may not send the specified broadcaster a Shoutout.".to_string()
+        )
+    );
+}
@@ -0,0 +1,223 @@
+//! Sends an announcement to the broadcaster’s chat room.
+//! [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+//!
+//! The rate limit is one announcement every 2 seconds, exceeding it results in [`SendChatAnnouncement::TooManyRequests`].
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SendChatAnnouncementRequest]
+//!
+//! To use this endpoint, construct a [`SendChatAnnouncementRequest`] with the [`SendChatAnnouncementRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::chat::send_chat_announcement;
+//! let request = send_chat_announcement::SendChatAnnouncementRequest::builder()
+//!     .broadcaster_id("11111")
+//!     .moderator_id("44444")
+//!     .build();
+//! ```
+//!
+//! ## Body: [SendChatAnnouncementBody]
+//!
+//! We also need to provide a body to the request containing the announcement.
+//!
+//! ```
+//! # use twitch_api2::helix::chat::send_chat_announcement;
+//! let body = send_chat_announcement::SendChatAnnouncementBody::builder()
+//!     .message("Hello chat!")
+//!     .color(send_chat_announcement::AnnouncementColor::Purple)
+//!     .build();
+//! ```
+//!
+//! ## Response: [SendChatAnnouncement]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, chat::send_chat_announcement};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = send_chat_announcement::SendChatAnnouncementRequest::builder()
+//!     .broadcaster_id("11111")
+//!     .moderator_id("44444")
+//!     .build();
+//! let body = send_chat_announcement::SendChatAnnouncementBody::builder()
+//!     .message("Hello chat!")
+//!     .build();
+//! let response: send_chat_announcement::SendChatAnnouncement = client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`SendChatAnnouncementRequest::parse_response(None, &request.get_uri(), response)`](SendChatAnnouncementRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Send Chat Announcement](super::send_chat_announcement)
+///
+/// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct SendChatAnnouncementRequest {
+    /// The ID of the broadcaster that owns the chat room to send the announcement to.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of a user who has permission to moderate the broadcaster’s chat room, or the broadcaster’s ID if they’re sending the announcement. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+}
+
+/// Body Parameters for [Send Chat Announcement](super::send_chat_announcement)
+///
+/// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct SendChatAnnouncementBody {
+    /// The announcement to make in the broadcaster’s chat room. Announcements are limited to a maximum of 500 characters; announcements longer than 500 characters are truncated.
+    #[builder(setter(into))]
+    pub message: String,
+    /// The color used to highlight the announcement. If not set, the channel’s accent color is used.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<AnnouncementColor>,
+}
+
+impl helix::private::SealedSerialize for SendChatAnnouncementBody {}
+
+/// Color used to highlight an [announcement](SendChatAnnouncementBody)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AnnouncementColor {
+    /// Blue
+    Blue,
+    /// Green
+    Green,
+    /// Orange
+    Orange,
+    /// Purple
+    Purple,
+    /// The channel’s accent color
+    Primary,
+}
+
+/// Return Values for [Send Chat Announcement](super::send_chat_announcement)
+///
+/// [`send-chat-announcement`](https://dev.twitch.tv/docs/api/reference#send-chat-announcement)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum SendChatAnnouncement {
+    /// 204 - Successfully sent the announcement.
+    Success,
+    /// 400 - The message is missing or one of the IDs is invalid.
+    BadRequest(String),
+    /// 401 - Authorization failed, or the moderator is not a moderator in the broadcaster’s chat room.
+    AuthFailed(String),
+    /// 429 - The sender exceeded the number of announcements they may send.
+    TooManyRequests(String),
+}
+
+impl Request for SendChatAnnouncementRequest {
+    type Response = SendChatAnnouncement;
+
+    const PATH: &'static str = "chat/announcements";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:announcements"),
+    )];
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status {
+            http::StatusCode::BAD_REQUEST => Some(SendChatAnnouncement::BadRequest(message)),
+            http::StatusCode::UNAUTHORIZED => Some(SendChatAnnouncement::AuthFailed(message)),
+            http::StatusCode::TOO_MANY_REQUESTS => {
+                Some(SendChatAnnouncement::TooManyRequests(message))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl RequestPost for SendChatAnnouncementRequest {
+    type Body = SendChatAnnouncementBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let data = match status {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => SendChatAnnouncement::Success,
+            _ => {
+                return Err(helix::HelixRequestPostError::InvalidResponse {
+                    reason: "unexpected status code",
+                    response: response.to_string(),
+                    status,
+                    uri: uri.clone(),
+                })
+            }
+        };
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SendChatAnnouncementRequest::builder()
+        .broadcaster_id("11111")
+        .moderator_id("44444")
+        .build();
+
+    let body = SendChatAnnouncementBody::builder()
+        .message("Hello chat!")
+        .color(AnnouncementColor::Purple)
+        .build();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"message":"Hello chat!","color":"purple"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/chat/announcements?broadcaster_id=11111&moderator_id=44444"
+    );
+
+    let res = SendChatAnnouncementRequest::parse_response(Some(req.clone()), &uri, http_response)
+        .unwrap();
+    assert_eq!(res.data, SendChatAnnouncement::Success);
+
+    let data =
+        br#"{"error":"Too Many Requests","status":429,"message":"announcements are limited"}"#
+            .to_vec();
+    let http_response = http::Response::builder().status(429).body(data).unwrap();
+    let res = SendChatAnnouncementRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        res.data,
+        SendChatAnnouncement::TooManyRequests("announcements are limited".to_string())
+    );
+}
@@ -198,6 +198,96 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         Ok(result)
     }
 
+    /// Get all chatters in a channel, sorted by their "rank" like [`TmiClient::get_chatters`](crate::TmiClient::get_chatters)
+    ///
+    /// Combines [Get Chatters](helix::chat::get_chatters) with [Get Moderators](helix::moderation::get_moderators) and [Get VIPs](helix::channels::get_vips).
+    ///
+    /// # Notes
+    ///
+    /// Get Moderators and Get VIPs require a token for the broadcaster, so `moderator_id` should be the broadcaster.
+    /// Helix does not expose staff, admins or global moderators, these lists are always empty.
+    #[cfg(feature = "tmi")]
+    #[cfg_attr(nightly, doc(cfg(feature = "tmi")))]
+    pub async fn get_chatters<T>(
+        &'a self,
+        broadcaster_id: impl Into<types::UserId>,
+        moderator_id: impl Into<types::UserId>,
+        token: &T,
+    ) -> Result<crate::tmi::GetChatters, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+    {
+        let broadcaster_id = broadcaster_id.into();
+        let mut chatters = vec![];
+
+        let mut resp = self
+            .req_get(
+                helix::chat::GetChattersRequest::builder()
+                    .broadcaster_id(broadcaster_id.clone())
+                    .moderator_id(moderator_id)
+                    .first(1000)
+                    .build(),
+                token,
+            )
+            .await?;
+        let total = resp.total;
+        chatters.extend(std::mem::take(&mut resp.data));
+        while let Some(resp_new) = resp.get_next(&self, token).await? {
+            resp = resp_new;
+            chatters.extend(std::mem::take(&mut resp.data));
+        }
+
+        let moderators: std::collections::HashSet<types::UserId> = self
+            .get_moderators_in_channel_from_id(broadcaster_id.clone(), token)
+            .await?
+            .into_iter()
+            .map(|m| m.user_id)
+            .collect();
+
+        let mut vips = std::collections::HashSet::new();
+        let mut resp = self
+            .req_get(
+                helix::channels::GetVipsRequest::builder()
+                    .broadcaster_id(broadcaster_id.clone())
+                    .first(100)
+                    .build(),
+                token,
+            )
+            .await?;
+        vips.extend(resp.data.drain(..).map(|v| v.user_id));
+        while let Some(resp_new) = resp.get_next(&self, token).await? {
+            resp = resp_new;
+            vips.extend(resp.data.drain(..).map(|v| v.user_id));
+        }
+
+        let mut result = crate::tmi::Chatters {
+            broadcaster: vec![],
+            vips: vec![],
+            moderators: vec![],
+            staff: vec![],
+            admins: vec![],
+            global_mods: vec![],
+            viewers: vec![],
+        };
+        let chatter_count = total.map_or(chatters.len() as u64, |t| t as u64);
+        for chatter in chatters {
+            if chatter.user_id == broadcaster_id {
+                result.broadcaster.push(chatter.user_login);
+            } else if moderators.contains(&chatter.user_id) {
+                result.moderators.push(chatter.user_login);
+            } else if vips.contains(&chatter.user_id) {
+                result.vips.push(chatter.user_login);
+            } else {
+                result.viewers.push(chatter.user_login);
+            }
+        }
+
+        Ok(crate::tmi::GetChatters {
+            chatter_count,
+            chatters: result,
+        })
+    }
+
     /// Get a users, with login, follow count
     pub async fn get_total_followers_from_login<T>(
         &'a self,
@@ -218,6 +308,8 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
 
     /// Get a users, with id, follow count
     ///
+    /// Uses [Get Channel Followers](helix::channels::get_channel_followers).
+    ///
     /// # Notes
     ///
     /// This returns zero if the user doesn't exist
@@ -231,8 +323,9 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     {
         let resp = self
             .req_get(
-                helix::users::GetUsersFollowsRequest::builder()
-                    .from_id(Some(to_id.into()))
+                helix::channels::GetChannelFollowersRequest::builder()
+                    .broadcaster_id(to_id)
+                    .first(1)
                     .build(),
                 token,
             )
@@ -270,6 +363,61 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
             .collect())
     }
 
+    /// Wait for a [created clip](helix::clips::CreatedClip) to be processed and return it.
+    ///
+    /// Polls [Get Clips](helix::clips::get_clips) at most `attempts` times, awaiting `sleep()` before each try.
+    /// Returns `None` if the clip is still not available after that, in which case the clip creation should be assumed to have failed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// # use twitch_api2::helix::{self, clips::CreateClipRequest};
+    /// # use twitch_api2::client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    /// # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+    /// # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+    /// # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+    /// # async fn sleep(_: std::time::Duration) {}
+    /// let request = CreateClipRequest::builder().broadcaster_id("44322889").build();
+    /// let created = client.req_post(request, helix::EmptyBody, &token).await?.data;
+    /// // Twitch recommends waiting up to 15 seconds for the clip
+    /// let clip = client
+    ///     .wait_for_clip(created.id, &token, 15, || sleep(std::time::Duration::from_secs(1)))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_clip<T, S, F>(
+        &'a self,
+        id: impl Into<String>,
+        token: &T,
+        attempts: usize,
+        mut sleep: S,
+    ) -> Result<Option<helix::clips::Clip>, ClientError<'a, C>>
+    where
+        T: TwitchToken + ?Sized,
+        S: FnMut() -> F,
+        F: std::future::Future<Output = ()>,
+    {
+        let id = id.into();
+        for _ in 0..attempts {
+            sleep().await;
+            let resp = self
+                .req_get(
+                    helix::clips::GetClipsRequest::builder()
+                        .id(vec![id.clone()])
+                        .build(),
+                    token,
+                )
+                .await?;
+            if let Some(clip) = resp.data.into_iter().next() {
+                return Ok(Some(clip));
+            }
+        }
+        Ok(None)
+    }
+
     /// Block a user
     pub async fn block_user<T>(
         &'a self,
@@ -306,6 +454,31 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
         )
         .await
     }
+
+    /// Download an analytics report and parse its rows into records
+    ///
+    /// The `url` is the [`url`](helix::analytics::GameAnalytics::url) returned by [Get Game Analytics](helix::analytics::get_game_analytics)
+    /// or [Get Extension Analytics](helix::analytics::get_extension_analytics). It is only valid for 5 minutes.
+    /// See [`parse_report`](helix::analytics::parse_report) for how rows are matched to `R`.
+    #[cfg(feature = "csv")]
+    #[cfg_attr(nightly, doc(cfg(feature = "csv")))]
+    pub async fn get_analytics_report<R>(&'a self, url: &str) -> Result<Vec<R>, ClientError<'a, C>>
+    where R: serde::de::DeserializeOwned {
+        let req = http::Request::builder()
+            .method(http::Method::GET)
+            .uri(url)
+            .body(Vec::new())
+            .map_err(helix::CreateRequestError::from)?;
+        let response = self
+            .client
+            .req(req)
+            .await
+            .map_err(ClientRequestError::RequestError)?;
+        if !response.status().is_success() {
+            return Err(helix::analytics::ReportError::Status(response.status()).into());
+        }
+        Ok(helix::analytics::parse_report(response.body())?)
+    }
 }
 
 /*
@@ -26,7 +26,10 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+pub mod create_clip;
 pub mod get_clips;
 
+#[doc(inline)]
+pub use create_clip::{CreateClipRequest, CreatedClip};
 #[doc(inline)]
 pub use get_clips::{Clip, GetClipsRequest};
@@ -0,0 +1,157 @@
+//! Creates a clip programmatically. This returns both an ID and an edit URL for the new clip.
+//! [`create-clip`](https://dev.twitch.tv/docs/api/reference#create-clip)
+//!
+//! # Notes
+//!
+//! Clip creation takes time. The clip is not immediately available from [Get Clips](super::get_clips),
+//! if it has not shown up after 15 seconds, assume that the clip creation failed.
+//! [`HelixClient::wait_for_clip`](helix::HelixClient::wait_for_clip) can be used to poll for the clip.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [CreateClipRequest]
+//!
+//! To use this endpoint, construct a [`CreateClipRequest`] with the [`CreateClipRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::clips::create_clip;
+//! let request = create_clip::CreateClipRequest::builder()
+//!     .broadcaster_id("44322889")
+//!     .build();
+//! ```
+//!
+//! ## Response: [CreatedClip]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, clips::create_clip};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = create_clip::CreateClipRequest::builder()
+//!     .broadcaster_id("44322889")
+//!     .build();
+//! let response: create_clip::CreatedClip = client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`CreateClipRequest::parse_response(None, &request.get_uri(), response)`](CreateClipRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Create Clip](super::create_clip)
+///
+/// [`create-clip`](https://dev.twitch.tv/docs/api/reference#create-clip)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct CreateClipRequest {
+    /// ID of the stream from which the clip will be made.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// If `false`, the clip is captured from the live stream when the API is called; otherwise, a delay is added before the clip is captured (to account for the brief delay between the broadcaster’s stream and the viewer’s experience of that stream). Default: `false`.
+    #[builder(default, setter(into))]
+    pub has_delay: Option<bool>,
+}
+
+/// Return Values for [Create Clip](super::create_clip)
+///
+/// [`create-clip`](https://dev.twitch.tv/docs/api/reference#create-clip)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CreatedClip {
+    /// ID of the clip that was created.
+    pub id: String,
+    /// URL of the edit page for the clip.
+    pub edit_url: String,
+}
+
+impl Request for CreateClipRequest {
+    type Response = CreatedClip;
+
+    const PATH: &'static str = "clips";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ClipsEdit];
+}
+
+impl RequestPost for CreateClipRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<Self::Response>> = helix::parse_json(&response_str)
+            .map_err(|e| {
+                helix::HelixRequestPostError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            helix::HelixRequestPostError::InvalidResponse {
+                reason: "response included no data",
+                response: response_str.to_string(),
+                status,
+                uri: uri.clone(),
+            }
+        })?;
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = CreateClipRequest::builder()
+        .broadcaster_id("44322889")
+        .has_delay(true)
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "id": "FiveWordsForClipSlug",
+            "edit_url": "http://clips.twitch.tv/FiveWordsForClipSlug/edit"
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(202).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/clips?broadcaster_id=44322889&has_delay=true"
+    );
+
+    let res = CreateClipRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data.id, "FiveWordsForClipSlug");
+    dbg!(res);
+}
@@ -0,0 +1,55 @@
+#![doc(alias = "drops")]
+//! Helix endpoints regarding entitlements, such as drops and code redemption
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, entitlements::GetDropsEntitlementsRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetDropsEntitlementsRequest::builder()
+//!     .game_id("33214".to_string())
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod get_code_status;
+pub mod get_drops_entitlements;
+pub mod redeem_code;
+pub mod update_drops_entitlements;
+
+#[doc(inline)]
+pub use get_code_status::{CodeStatus, CodeStatusKind, GetCodeStatusRequest};
+#[doc(inline)]
+pub use get_drops_entitlements::{DropsEntitlement, GetDropsEntitlementsRequest};
+#[doc(inline)]
+pub use redeem_code::RedeemCodeRequest;
+#[doc(inline)]
+pub use update_drops_entitlements::{
+    DropsEntitlementUpdate, UpdateDropsEntitlementsBody, UpdateDropsEntitlementsRequest,
+    UpdateStatus,
+};
+
+/// Fulfillment status of a [drops entitlement](DropsEntitlement)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum FulfillmentStatus {
+    /// The entitlement has been claimed by the user, but not yet fulfilled by the game.
+    Claimed,
+    /// The entitlement has been fulfilled.
+    Fulfilled,
+}
@@ -0,0 +1,153 @@
+//! Gets the status of one or more provided codes.
+//! [`get-code-status`](https://dev.twitch.tv/docs/api/reference#get-code-status)
+//!
+//! # Notes
+//!
+//! This endpoint requires an app access token from the organization that owns the codes.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetCodeStatusRequest]
+//!
+//! To use this endpoint, construct a [`GetCodeStatusRequest`] with the [`GetCodeStatusRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::entitlements::get_code_status;
+//! let request = get_code_status::GetCodeStatusRequest::builder()
+//!     .code(vec!["KUHXV-4GXYP-YNAAB".to_string()])
+//!     .user_id("156900877".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [CodeStatus]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, entitlements::get_code_status};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_code_status::GetCodeStatusRequest::builder()
+//!     .code(vec!["KUHXV-4GXYP-YNAAB".to_string()])
+//!     .user_id("156900877".to_string())
+//!     .build();
+//! let response: Vec<get_code_status::CodeStatus> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetCodeStatusRequest::parse_response(None, &request.get_uri(), response)`](GetCodeStatusRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Code Status](super::get_code_status)
+///
+/// [`get-code-status`](https://dev.twitch.tv/docs/api/reference#get-code-status)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetCodeStatusRequest {
+    /// The codes to get the status of. Maximum: 20
+    pub code: Vec<String>,
+    /// Represents a numeric Twitch user ID. The user account which is going to receive the entitlement associated with the code.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+}
+
+/// Return Values for [Get Code Status](super::get_code_status) and [Redeem Code](super::redeem_code)
+///
+/// [`get-code-status`](https://dev.twitch.tv/docs/api/reference#get-code-status)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CodeStatus {
+    /// The code this status applies to.
+    pub code: String,
+    /// Status of the code.
+    pub status: CodeStatusKind,
+}
+
+/// Status of a [code](CodeStatus)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum CodeStatusKind {
+    /// Request successfully redeemed this code to the authenticated user’s account.
+    ///
+    /// This status will only ever be encountered when using [Redeem Code](super::redeem_code).
+    SuccessfullyRedeemed,
+    /// Code has already been claimed by a Twitch user.
+    AlreadyClaimed,
+    /// Code has expired and can no longer be claimed.
+    Expired,
+    /// User is not eligible to redeem this code.
+    UserNotEligible,
+    /// Code is not valid and/or does not exist in our database.
+    NotFound,
+    /// Code is not currently active.
+    Inactive,
+    /// Code has not been claimed.
+    ///
+    /// This status will only ever be encountered when using [Get Code Status](super::get_code_status).
+    Unused,
+    /// Code was not properly formatted.
+    IncorrectFormat,
+    /// Indicates some internal and/or unknown failure handling this code.
+    InternalError,
+}
+
+impl Request for GetCodeStatusRequest {
+    type Response = Vec<CodeStatus>;
+
+    const PATH: &'static str = "entitlements/codes";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetCodeStatusRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetCodeStatusRequest::builder()
+        .code(vec![
+            "KUHXV-4GXYP-YNAAB".to_string(),
+            "XZDDZ-5SIQR-RT5M3".to_string(),
+        ])
+        .user_id("156900877".to_string())
+        .build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "code": "KUHXV-4GXYP-YNAAB",
+            "status": "SUCCESSFULLY_REDEEMED"
+        },
+        {
+            "code": "XZDDZ-5SIQR-RT5M3",
+            "status": "ALREADY_CLAIMED"
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/entitlements/codes?code=KUHXV-4GXYP-YNAAB&code=XZDDZ-5SIQR-RT5M3&user_id=156900877"
+    );
+
+    let res = GetCodeStatusRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data[1].status, CodeStatusKind::AlreadyClaimed);
+    dbg!(res);
+}
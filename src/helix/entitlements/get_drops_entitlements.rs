@@ -0,0 +1,155 @@
+//! Gets a list of entitlements for a given organization that have been granted to a game, user, or both.
+//! [`get-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#get-drops-entitlements)
+//!
+//! # Notes
+//!
+//! With an app access token, `user_id` can be used to filter the entitlements of a specific user.
+//! With a user access token, only the entitlements of the user in the token are returned and `user_id` must not be set.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetDropsEntitlementsRequest]
+//!
+//! To use this endpoint, construct a [`GetDropsEntitlementsRequest`] with the [`GetDropsEntitlementsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::entitlements::{get_drops_entitlements, FulfillmentStatus};
+//! let request = get_drops_entitlements::GetDropsEntitlementsRequest::builder()
+//!     .game_id("33214".to_string())
+//!     .fulfillment_status(FulfillmentStatus::Claimed)
+//!     .build();
+//! ```
+//!
+//! ## Response: [DropsEntitlement]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, entitlements::get_drops_entitlements};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_drops_entitlements::GetDropsEntitlementsRequest::builder()
+//!     .game_id("33214".to_string())
+//!     .build();
+//! let response: Vec<get_drops_entitlements::DropsEntitlement> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetDropsEntitlementsRequest::parse_response(None, &request.get_uri(), response)`](GetDropsEntitlementsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Drops Entitlements](super::get_drops_entitlements)
+///
+/// [`get-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#get-drops-entitlements)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct GetDropsEntitlementsRequest {
+    /// IDs of the entitlements to get. Maximum: 100
+    #[builder(default)]
+    pub id: Vec<String>,
+    /// A Twitch user ID.
+    #[builder(default, setter(into))]
+    pub user_id: Option<types::UserId>,
+    /// A Twitch game ID.
+    #[builder(default, setter(into))]
+    pub game_id: Option<types::CategoryId>,
+    /// Only return entitlements with this fulfillment status.
+    #[builder(default, setter(into))]
+    pub fulfillment_status: Option<FulfillmentStatus>,
+    /// The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+    /// Maximum number of entitlements to return. Maximum: 1000. Default: 20.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+}
+
+/// Return Values for [Get Drops Entitlements](super::get_drops_entitlements)
+///
+/// [`get-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#get-drops-entitlements)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DropsEntitlement {
+    /// Unique identifier of the entitlement.
+    pub id: String,
+    /// Identifier of the benefit.
+    pub benefit_id: String,
+    /// UTC timestamp when this entitlement was granted on Twitch.
+    pub timestamp: types::Timestamp,
+    /// Twitch user ID of the user who was granted the entitlement.
+    pub user_id: types::UserId,
+    /// Twitch game ID of the game that was being played when this benefit was entitled.
+    pub game_id: types::CategoryId,
+    /// The fulfillment status of the entitlement.
+    pub fulfillment_status: FulfillmentStatus,
+    /// UTC timestamp when this entitlement was last updated.
+    #[serde(alias = "updated_at")]
+    pub last_updated: types::Timestamp,
+}
+
+impl Request for GetDropsEntitlementsRequest {
+    type Response = Vec<DropsEntitlement>;
+
+    const PATH: &'static str = "entitlements/drops";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetDropsEntitlementsRequest {}
+
+impl helix::Paginated for GetDropsEntitlementsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetDropsEntitlementsRequest::builder()
+        .user_id("25009227".to_string())
+        .game_id("33214".to_string())
+        .fulfillment_status(FulfillmentStatus::Claimed)
+        .build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "id": "fb78259e-fb81-4d1b-8333-34a06ffc24c0",
+            "benefit_id": "74c52265-e214-48a6-91b9-23b6014e8041",
+            "timestamp": "2019-01-28T04:17:53.325Z",
+            "user_id": "25009227",
+            "game_id": "33214",
+            "fulfillment_status": "CLAIMED",
+            "last_updated": "2019-01-28T04:17:53.325Z"
+        }
+    ],
+    "pagination": {
+        "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6ImV5SnBaQ0k2SW1aaU56Z3lOVGxsTFdaaU9ERXROR1F4WWkwNE16TXpMVE0wWVRBMlptWmpNalJqTUNJc0ltTnlaV0YwWldSZllYUWlPaUl5TURFNUxUQXhMVEk0VkRBME9qRTNPalV6TGpNeU5Wb2lmUSJ9fQ"
+    }
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/entitlements/drops?user_id=25009227&game_id=33214&fulfillment_status=CLAIMED"
+    );
+
+    let res = GetDropsEntitlementsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data[0].fulfillment_status, FulfillmentStatus::Claimed);
+    assert!(res.pagination.is_some());
+    dbg!(res);
+}
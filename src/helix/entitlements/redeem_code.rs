@@ -0,0 +1,116 @@
+//! Redeems one or more provided codes to the authenticated Twitch user.
+//! [`redeem-code`](https://dev.twitch.tv/docs/api/reference#redeem-code)
+//!
+//! # Notes
+//!
+//! This endpoint requires an app access token from the organization that owns the codes.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [RedeemCodeRequest]
+//!
+//! To use this endpoint, construct a [`RedeemCodeRequest`] with the [`RedeemCodeRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::entitlements::redeem_code;
+//! let request = redeem_code::RedeemCodeRequest::builder()
+//!     .code(vec!["KUHXV-4GXYP-YNAAB".to_string()])
+//!     .user_id("156900877".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [CodeStatus]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, entitlements::{redeem_code, CodeStatus}};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = redeem_code::RedeemCodeRequest::builder()
+//!     .code(vec!["KUHXV-4GXYP-YNAAB".to_string()])
+//!     .user_id("156900877".to_string())
+//!     .build();
+//! let response: Vec<CodeStatus> = client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`RedeemCodeRequest::parse_response(None, &request.get_uri(), response)`](RedeemCodeRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Redeem Code](super::redeem_code)
+///
+/// [`redeem-code`](https://dev.twitch.tv/docs/api/reference#redeem-code)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct RedeemCodeRequest {
+    /// The codes to redeem. Maximum: 20
+    pub code: Vec<String>,
+    /// Represents a numeric Twitch user ID. The user account which is going to receive the entitlement associated with the code.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+}
+
+impl Request for RedeemCodeRequest {
+    type Response = Vec<CodeStatus>;
+
+    const PATH: &'static str = "entitlements/codes";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPost for RedeemCodeRequest {
+    type Body = helix::EmptyBody;
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = RedeemCodeRequest::builder()
+        .code(vec![
+            "KUHXV-4GXYP-YNAAB".to_string(),
+            "XZDDZ-5SIQR-RT5M3".to_string(),
+        ])
+        .user_id("156900877".to_string())
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "code": "KUHXV-4GXYP-YNAAB",
+            "status": "SUCCESSFULLY_REDEEMED"
+        },
+        {
+            "code": "XZDDZ-5SIQR-RT5M3",
+            "status": "USER_NOT_ELIGIBLE"
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/entitlements/codes?code=KUHXV-4GXYP-YNAAB&code=XZDDZ-5SIQR-RT5M3&user_id=156900877"
+    );
+
+    let res = RedeemCodeRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data[0].status, CodeStatusKind::SuccessfullyRedeemed);
+    assert_eq!(res.data[1].status, CodeStatusKind::UserNotEligible);
+    dbg!(res);
+}
@@ -0,0 +1,221 @@
+//! Updates the fulfillment status on a set of Drops entitlements, specified by their entitlement IDs.
+//! [`update-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements)
+//!
+//! # Notes
+//!
+//! The update can partially succeed, the response contains one [`DropsEntitlementUpdate`] per [`UpdateStatus`] listing the entitlement IDs it applies to.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateDropsEntitlementsRequest]
+//!
+//! To use this endpoint, construct a [`UpdateDropsEntitlementsRequest`] with the [`UpdateDropsEntitlementsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::entitlements::update_drops_entitlements;
+//! let request = update_drops_entitlements::UpdateDropsEntitlementsRequest::builder().build();
+//! ```
+//!
+//! ## Body: [UpdateDropsEntitlementsBody]
+//!
+//! We also need to provide a body to the request containing what we want to change.
+//!
+//! ```
+//! # use twitch_api2::helix::entitlements::{update_drops_entitlements, FulfillmentStatus};
+//! let body = update_drops_entitlements::UpdateDropsEntitlementsBody::builder()
+//!     .entitlement_ids(vec!["fb78259e-fb81-4d1b-8333-34a06ffc24c0".to_string()])
+//!     .fulfillment_status(FulfillmentStatus::Fulfilled)
+//!     .build();
+//! ```
+//!
+//! ## Response: [DropsEntitlementUpdate]
+//!
+//! Send the request to receive the response with [`HelixClient::req_patch()`](helix::HelixClient::req_patch).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, entitlements::{update_drops_entitlements, FulfillmentStatus}};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = update_drops_entitlements::UpdateDropsEntitlementsRequest::builder().build();
+//! let body = update_drops_entitlements::UpdateDropsEntitlementsBody::builder()
+//!     .entitlement_ids(vec!["fb78259e-fb81-4d1b-8333-34a06ffc24c0".to_string()])
+//!     .fulfillment_status(FulfillmentStatus::Fulfilled)
+//!     .build();
+//! let response: Vec<update_drops_entitlements::DropsEntitlementUpdate> = client.req_patch(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPatch::create_request)
+//! and parse the [`http::Response`] with [`UpdateDropsEntitlementsRequest::parse_response(None, &request.get_uri(), response)`](UpdateDropsEntitlementsRequest::parse_response)
+
+use super::*;
+use helix::RequestPatch;
+
+/// Query Parameters for [Update Drops Entitlements](super::update_drops_entitlements)
+///
+/// [`update-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateDropsEntitlementsRequest {}
+
+/// Body Parameters for [Update Drops Entitlements](super::update_drops_entitlements)
+///
+/// [`update-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateDropsEntitlementsBody {
+    /// IDs of the entitlements to update. Maximum: 100
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub entitlement_ids: Vec<String>,
+    /// The fulfillment status to set the entitlements to.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_status: Option<FulfillmentStatus>,
+}
+
+impl helix::private::SealedSerialize for UpdateDropsEntitlementsBody {}
+
+/// Return Values for [Update Drops Entitlements](super::update_drops_entitlements)
+///
+/// [`update-drops-entitlements`](https://dev.twitch.tv/docs/api/reference#update-drops-entitlements)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct DropsEntitlementUpdate {
+    /// Status of the entitlements in [`ids`](DropsEntitlementUpdate::ids).
+    pub status: UpdateStatus,
+    /// IDs of the entitlements this status applies to.
+    pub ids: Vec<String>,
+}
+
+impl DropsEntitlementUpdate {
+    /// Returns `true` if the entitlements in this set were updated.
+    pub fn is_success(&self) -> bool { self.status == UpdateStatus::Success }
+}
+
+/// Status of a set of entitlements in a [`DropsEntitlementUpdate`]
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum UpdateStatus {
+    /// Entitlement was successfully updated.
+    Success,
+    /// Invalid format for the entitlement ID.
+    InvalidId,
+    /// Entitlement ID does not exist.
+    NotFound,
+    /// Entitlement is not owned by the organization or the user when called with a user access token.
+    Unauthorized,
+    /// Indicates the entitlement update operation failed. Errors in this state are expected to be transient and should be retried later.
+    UpdateFailed,
+}
+
+impl Request for UpdateDropsEntitlementsRequest {
+    type Response = Vec<DropsEntitlementUpdate>;
+
+    const PATH: &'static str = "entitlements/drops";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPatch for UpdateDropsEntitlementsRequest {
+    type Body = UpdateDropsEntitlementsBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    where
+        Self: Sized,
+    {
+        #[derive(PartialEq, Deserialize, Debug)]
+        struct InnerResponse {
+            data: Vec<DropsEntitlementUpdate>,
+        }
+
+        let response: InnerResponse = helix::parse_json(response).map_err(|e| {
+            helix::HelixRequestPatchError::DeserializeError(
+                response.to_string(),
+                e,
+                uri.clone(),
+                status,
+            )
+        })?;
+        Ok(helix::Response {
+            data: response.data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateDropsEntitlementsRequest::builder().build();
+
+    let body = UpdateDropsEntitlementsBody::builder()
+        .entitlement_ids(vec![
+            "fb78259e-fb81-4d1b-8333-34a06ffc24c0".to_string(),
+            "862750a5-265e-4ab6-9f0a-c64df3d54dd0".to_string(),
+            "d8879baa-3966-4d10-8856-15fdd62cce02".to_string(),
+            "9a290126-7e3b-4f66-a9ae-551537893b65".to_string(),
+        ])
+        .fulfillment_status(FulfillmentStatus::Fulfilled)
+        .build();
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "status": "SUCCESS",
+            "ids": [
+                "fb78259e-fb81-4d1b-8333-34a06ffc24c0",
+                "862750a5-265e-4ab6-9f0a-c64df3d54dd0"
+            ]
+        },
+        {
+            "status": "UNAUTHORIZED",
+            "ids": [
+                "d8879baa-3966-4d10-8856-15fdd62cce02"
+            ]
+        },
+        {
+            "status": "UPDATE_FAILED",
+            "ids": [
+                "9a290126-7e3b-4f66-a9ae-551537893b65"
+            ]
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(200).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/entitlements/drops?"
+    );
+
+    let res =
+        UpdateDropsEntitlementsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert!(res.data[0].is_success());
+    assert_eq!(res.data[1].status, UpdateStatus::Unauthorized);
+    assert_eq!(res.data[2].status, UpdateStatus::UpdateFailed);
+    dbg!(res);
+}
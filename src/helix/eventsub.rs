@@ -6,17 +6,64 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+pub mod create_conduit;
 pub mod create_eventsub_subscription;
+pub mod delete_conduit;
 pub mod delete_eventsub_subscription;
+pub mod get_conduit_shards;
+pub mod get_conduits;
 pub mod get_eventsub_subscriptions;
+pub mod manager;
+pub mod update_conduit;
+pub mod update_conduit_shards;
 
+#[doc(inline)]
+pub use create_conduit::{CreateConduitBody, CreateConduitRequest};
 #[doc(inline)]
 pub use create_eventsub_subscription::{
-    CreateEventSubSubscription, CreateEventSubSubscriptionBody, CreateEventSubSubscriptionRequest,
+    CreatableSubscription, CreateEventSubSubscription, CreateEventSubSubscriptionBody,
+    CreateEventSubSubscriptionRequest,
 };
 #[doc(inline)]
+pub use delete_conduit::{DeleteConduit, DeleteConduitRequest};
+#[doc(inline)]
 pub use delete_eventsub_subscription::{
     DeleteEventSubSubscription, DeleteEventSubSubscriptionRequest,
 };
 #[doc(inline)]
+pub use get_conduit_shards::GetConduitShardsRequest;
+#[doc(inline)]
+pub use get_conduits::GetConduitsRequest;
+#[doc(inline)]
 pub use get_eventsub_subscriptions::{EventSubSubscriptions, GetEventSubSubscriptionsRequest};
+#[doc(inline)]
+pub use update_conduit::{UpdateConduitBody, UpdateConduitRequest};
+#[doc(inline)]
+pub use update_conduit_shards::{
+    ShardError, ShardUpdate, UpdateConduitShards, UpdateConduitShardsBody,
+    UpdateConduitShardsRequest,
+};
+
+/// A conduit, a transport that distributes EventSub notifications over a number of shards
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Conduit {
+    /// Conduit ID.
+    pub id: String,
+    /// Number of shards associated with this conduit.
+    pub shard_count: usize,
+}
+
+/// A shard of a [conduit](Conduit)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Shard {
+    /// Shard ID.
+    pub id: String,
+    /// The shard status. The subscriber receives events only for enabled shards.
+    pub status: crate::eventsub::Status,
+    /// The transport details used to send the notifications.
+    pub transport: crate::eventsub::TransportResponse,
+}
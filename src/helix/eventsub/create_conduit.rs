@@ -0,0 +1,117 @@
+//! Creates a new conduit.
+//! [`create-conduits`](https://dev.twitch.tv/docs/api/reference#create-conduits)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Create Conduit](super::create_conduit)
+///
+/// [`create-conduits`](https://dev.twitch.tv/docs/api/reference#create-conduits)
+#[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct CreateConduitRequest {}
+
+impl CreateConduitRequest {
+    /// Create a new [`CreateConduitRequest`]
+    pub fn new() -> Self { CreateConduitRequest {} }
+}
+
+/// Body Parameters for [Create Conduit](super::create_conduit)
+///
+/// [`create-conduits`](https://dev.twitch.tv/docs/api/reference#create-conduits)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct CreateConduitBody {
+    /// The number of shards to create for this conduit.
+    pub shard_count: usize,
+}
+
+impl helix::private::SealedSerialize for CreateConduitBody {}
+
+impl Request for CreateConduitRequest {
+    type Response = Conduit;
+
+    const PATH: &'static str = "eventsub/conduits";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPost for CreateConduitRequest {
+    type Body = CreateConduitBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<Self::Response>> = helix::parse_json(&response_str)
+            .map_err(|e| {
+                helix::HelixRequestPostError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            helix::HelixRequestPostError::InvalidResponse {
+                reason: "response included no data",
+                response: response_str.to_string(),
+                status,
+                uri: uri.clone(),
+            }
+        })?;
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = CreateConduitRequest::new();
+
+    let body = CreateConduitBody::builder().shard_count(5).build();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"shard_count":5}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "bfcfc993-26b1-b876-44d9-afe75a379dac",
+                "shard_count": 5
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/eventsub/conduits?"
+    );
+
+    let res = CreateConduitRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.id, "bfcfc993-26b1-b876-44d9-afe75a379dac");
+}
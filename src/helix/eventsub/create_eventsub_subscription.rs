@@ -1,19 +1,47 @@
 //! Creates an EventSub subscription.
 use super::*;
+use serde::de::DeserializeOwned;
 use crate::eventsub::{EventSubscription, EventType, Status, Transport, TransportResponse};
 
+/// A subscription that can be created with [Create EventSub Subscription](super::create_eventsub_subscription)
+///
+/// Implemented for every [`EventSubscription`], and for [`DesiredSubscription`](super::manager::DesiredSubscription) where the subscription is only known at runtime.
+pub trait CreatableSubscription: DeserializeOwned + Serialize + PartialEq + Clone {
+    /// Condition returned for the created subscription
+    type Condition: DeserializeOwned + Serialize + PartialEq + Clone;
+
+    /// The category of the subscription
+    fn event_type(&self) -> EventType;
+    /// The version of the subscription
+    fn version(&self) -> &str;
+    /// Creates the [`condition`](https://dev.twitch.tv/docs/eventsub/eventsub-reference#conditions) for this subscription
+    fn create_condition(&self) -> Result<serde_json::Value, serde_json::Error>;
+}
+
+impl<E: EventSubscription> CreatableSubscription for E {
+    type Condition = E;
+
+    fn event_type(&self) -> EventType { E::EVENT_TYPE }
+
+    fn version(&self) -> &str { E::VERSION }
+
+    fn create_condition(&self) -> Result<serde_json::Value, serde_json::Error> {
+        self.condition()
+    }
+}
+
 /// Query Parameters for [Create EventSub Subscription](super::create_eventsub_subscription)
 ///
 /// [`create-eventsub-subscription`](https://dev.twitch.tv/docs/api/reference#create-eventsub-subscription)
 #[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug, Default)]
 #[non_exhaustive]
-pub struct CreateEventSubSubscriptionRequest<E: EventSubscription> {
+pub struct CreateEventSubSubscriptionRequest<E: CreatableSubscription> {
     #[builder(setter(skip), default)]
     #[serde(skip)]
     phantom: std::marker::PhantomData<E>,
 }
 
-impl<E: EventSubscription> helix::Request for CreateEventSubSubscriptionRequest<E> {
+impl<E: CreatableSubscription> helix::Request for CreateEventSubSubscriptionRequest<E> {
     type Response = CreateEventSubSubscription<E>;
 
     const PATH: &'static str = "eventsub/subscriptions";
@@ -27,31 +55,31 @@ impl<E: EventSubscription> helix::Request for CreateEventSubSubscriptionRequest<
 ///
 /// # Notes
 ///
-/// This body is quite different from the official body. If you want the true representation in text, see [`helix::HelixRequestBody::try_to_body`] on [`CreateEventSubSubscriptionRequest<E: EventSubscription>`](CreateEventSubSubscriptionRequest)
+/// This body is quite different from the official body. If you want the true representation in text, see [`helix::HelixRequestBody::try_to_body`] on [`CreateEventSubSubscriptionRequest<E: CreatableSubscription>`](CreateEventSubSubscriptionRequest)
 #[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Clone, Debug)]
 #[non_exhaustive]
-pub struct CreateEventSubSubscriptionBody<E: EventSubscription> {
+pub struct CreateEventSubSubscriptionBody<E: CreatableSubscription> {
     /// Subscription that will be created
-    #[serde(bound(deserialize = "E: EventSubscription"))]
+    #[serde(bound(deserialize = "E: DeserializeOwned"))]
     pub subscription: E,
     /// The notification delivery specific information
     pub transport: Transport,
 }
 
-impl<E: EventSubscription> helix::HelixRequestBody for CreateEventSubSubscriptionBody<E> {
+impl<E: CreatableSubscription> helix::HelixRequestBody for CreateEventSubSubscriptionBody<E> {
     fn try_to_body(&self) -> Result<Vec<u8>, helix::BodyError> {
         #[derive(PartialEq, Serialize, Debug)]
         struct IEventSubRequestBody<'a> {
             r#type: EventType,
-            version: &'static str,
+            version: &'a str,
             condition: serde_json::Value,
             transport: &'a Transport,
         }
 
         let b = IEventSubRequestBody {
-            r#type: E::EVENT_TYPE,
-            version: E::VERSION,
-            condition: self.subscription.condition()?,
+            r#type: self.subscription.event_type(),
+            version: self.subscription.version(),
+            condition: self.subscription.create_condition()?,
             transport: &self.transport,
         };
         serde_json::to_vec(&b).map_err(Into::into)
@@ -59,7 +87,7 @@ impl<E: EventSubscription> helix::HelixRequestBody for CreateEventSubSubscriptio
 }
 
 // FIXME: Builder?
-impl<E: EventSubscription> CreateEventSubSubscriptionBody<E> {
+impl<E: CreatableSubscription> CreateEventSubSubscriptionBody<E> {
     /// Create a new [`CreateEventSubSubscriptionBody`]
     pub fn new(subscription: E, transport: Transport) -> CreateEventSubSubscriptionBody<E> {
         CreateEventSubSubscriptionBody {
@@ -74,7 +102,7 @@ impl<E: EventSubscription> CreateEventSubSubscriptionBody<E> {
 /// [`create-eventsub-subscription`](https://dev.twitch.tv/docs/api/reference#create-eventsub-subscription)
 #[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
 #[non_exhaustive]
-pub struct CreateEventSubSubscription<E: EventSubscription> {
+pub struct CreateEventSubSubscription<E: CreatableSubscription> {
     /// ID of the subscription created.
     pub id: types::EventSubId,
     /// Status of the subscription.
@@ -85,8 +113,11 @@ pub struct CreateEventSubSubscription<E: EventSubscription> {
     /// The version of the subscription type that was created.
     pub version: String,
     /// JSON object specifying custom parameters for the subscription.
-    #[serde(bound(deserialize = "E: EventSubscription"))]
-    pub condition: E,
+    #[serde(bound(
+        deserialize = "E::Condition: DeserializeOwned",
+        serialize = "E::Condition: Serialize"
+    ))]
+    pub condition: E::Condition,
     /// RFC3339 timestamp indicating when the subscription was created.
     pub created_at: types::Timestamp,
     /// JSON object indicating the notification delivery specific information. Includes the transport method and callback URL.
@@ -108,7 +139,7 @@ pub struct CreateEventSubSubscription<E: EventSubscription> {
     pub cost: usize,
 }
 
-impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequest<E> {
+impl<E: CreatableSubscription> helix::RequestPost for CreateEventSubSubscriptionRequest<E> {
     type Body = CreateEventSubSubscriptionBody<E>;
 
     fn parse_inner_response(
@@ -122,10 +153,10 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
     {
         #[derive(PartialEq, Deserialize, Debug)]
         #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
-        pub struct InnerResponseData<E: EventSubscription> {
+        pub struct InnerResponseData<C: DeserializeOwned> {
             cost: usize,
-            #[serde(bound(deserialize = "E: EventSubscription"))]
-            condition: E,
+            #[serde(bound(deserialize = "C: DeserializeOwned"))]
+            condition: C,
             created_at: types::Timestamp,
             id: types::EventSubId,
             status: Status,
@@ -136,15 +167,15 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
         }
         #[derive(PartialEq, Deserialize, Debug)]
         #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
-        struct InnerResponse<E: EventSubscription> {
-            #[serde(bound(deserialize = "E: EventSubscription"))]
-            data: Vec<InnerResponseData<E>>,
+        struct InnerResponse<C: DeserializeOwned> {
+            #[serde(bound(deserialize = "C: DeserializeOwned"))]
+            data: Vec<InnerResponseData<C>>,
             limit: Option<usize>,
             total: usize,
             total_cost: usize,
             max_total_cost: usize,
         }
-        let response: InnerResponse<E> = helix::parse_json(&text).map_err(|e| {
+        let response: InnerResponse<E::Condition> = helix::parse_json(&text).map_err(|e| {
             helix::HelixRequestPostError::DeserializeError(text.to_string(), e, uri.clone(), status)
         })?;
         let data = response.data.into_iter().next().ok_or_else(|| {
@@ -173,6 +204,8 @@ impl<E: EventSubscription> helix::RequestPost for CreateEventSubSubscriptionRequ
             },
             pagination: None,
             request,
+            total: None,
+            other: None,
         })
     }
 }
@@ -186,15 +219,20 @@ fn test_request() {
 
     let body = CreateEventSubSubscriptionBody::new(
         UserUpdateV1::builder().user_id("1234").build(),
-        eventsub::Transport {
-            method: eventsub::TransportMethod::Webhook,
-            callback: "example.com".to_string(),
-            secret: "heyhey13".to_string(),
-        },
+        eventsub::Transport::webhook("example.com", "heyhey13"),
     );
 
     dbg!(req.create_request(body, "token", "clientid").unwrap());
 
+    let conduit_body = CreateEventSubSubscriptionBody::new(
+        UserUpdateV1::builder().user_id("1234").build(),
+        eventsub::Transport::conduit("bfcfc993-26b1-b876-44d9-afe75a379dac"),
+    );
+    assert_eq!(
+        std::str::from_utf8(&conduit_body.try_to_body().unwrap()).unwrap(),
+        r#"{"type":"user.update","version":"1","condition":{"user_id":"1234"},"transport":{"method":"conduit","conduit_id":"bfcfc993-26b1-b876-44d9-afe75a379dac"}}"#
+    );
+
     // From twitch docs, FIXME: docs say `users.update` in example for Create EventSub Subscription, they also use kebab-case for status
     // "{"type":"users.update","version":"1","condition":{"user_id":"1234"},"transport":{"method":"webhook","callback":"https://this-is-a-callback.com","secret":"s3cre7"}}"
     let data = br#"{
@@ -0,0 +1,87 @@
+//! Deletes a specified conduit.
+//! [`delete-conduit`](https://dev.twitch.tv/docs/api/reference#delete-conduit)
+//!
+//! # Notes
+//!
+//! Deleting a conduit also deletes all of its subscriptions, their status will become [`ConduitDeleted`](crate::eventsub::Status::ConduitDeleted).
+
+use super::*;
+use helix::RequestDelete;
+
+/// Query Parameters for [Delete Conduit](super::delete_conduit)
+///
+/// [`delete-conduit`](https://dev.twitch.tv/docs/api/reference#delete-conduit)
+#[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct DeleteConduitRequest {
+    /// Conduit ID.
+    #[builder(setter(into))]
+    pub id: String,
+}
+
+impl Request for DeleteConduitRequest {
+    type Response = DeleteConduit;
+
+    const PATH: &'static str = "eventsub/conduits";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+/// Return Values for [Delete Conduit](super::delete_conduit)
+///
+/// [`delete-conduit`](https://dev.twitch.tv/docs/api/reference#delete-conduit)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum DeleteConduit {
+    /// 204 - Conduit deleted
+    Success,
+    /// 404 - Conduit not found
+    NotFound,
+}
+
+impl std::convert::TryFrom<http::StatusCode> for DeleteConduit {
+    type Error = std::borrow::Cow<'static, str>;
+
+    fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+        match s {
+            http::StatusCode::NO_CONTENT => Ok(DeleteConduit::Success),
+            http::StatusCode::NOT_FOUND => Ok(DeleteConduit::NotFound),
+            other => Err(other.canonical_reason().unwrap_or("").into()),
+        }
+    }
+}
+
+impl RequestDelete for DeleteConduitRequest {
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestDeleteError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = DeleteConduitRequest::builder()
+        .id("bfcfc993-26b1-b876-44d9-afe75a379dac")
+        .build();
+
+    let data = vec![];
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/eventsub/conduits?id=bfcfc993-26b1-b876-44d9-afe75a379dac"
+    );
+
+    assert_eq!(
+        DeleteConduitRequest::parse_response(&uri, http_response).unwrap(),
+        DeleteConduit::Success
+    );
+}
@@ -53,7 +53,18 @@ impl std::convert::TryFrom<http::StatusCode> for DeleteEventSubSubscription {
     }
 }
 
-impl RequestDelete for DeleteEventSubSubscriptionRequest {}
+impl RequestDelete for DeleteEventSubSubscriptionRequest {
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestDeleteError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
+}
 
 #[test]
 fn test_request() {
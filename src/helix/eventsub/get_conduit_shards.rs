@@ -0,0 +1,87 @@
+//! Gets a lists of all shards for a conduit.
+//! [`get-conduit-shards`](https://dev.twitch.tv/docs/api/reference#get-conduit-shards)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Conduit Shards](super::get_conduit_shards)
+///
+/// [`get-conduit-shards`](https://dev.twitch.tv/docs/api/reference#get-conduit-shards)
+#[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetConduitShardsRequest {
+    /// Conduit ID.
+    #[builder(setter(into))]
+    pub conduit_id: String,
+    /// Status to filter by.
+    #[builder(default, setter(into))]
+    pub status: Option<crate::eventsub::Status>,
+    /// Cursor for forward pagination
+    #[builder(default, setter(into))]
+    pub after: Option<helix::Cursor>,
+}
+
+impl Request for GetConduitShardsRequest {
+    type Response = Vec<Shard>;
+
+    const PATH: &'static str = "eventsub/conduits/shards";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetConduitShardsRequest {}
+
+impl helix::Paginated for GetConduitShardsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetConduitShardsRequest::builder()
+        .conduit_id("bfcfc993-26b1-b876-44d9-afe75a379dac")
+        .status(crate::eventsub::Status::Enabled)
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "0",
+                "status": "enabled",
+                "transport": {
+                    "method": "webhook",
+                    "callback": "https://this-is-a-callback.com"
+                }
+            },
+            {
+                "id": "1",
+                "status": "webhook_callback_verification_pending",
+                "transport": {
+                    "method": "webhook",
+                    "callback": "https://this-is-a-callback-2.com"
+                }
+            }
+        ],
+        "pagination": {}
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/eventsub/conduits/shards?conduit_id=bfcfc993-26b1-b876-44d9-afe75a379dac&status=enabled"
+    );
+
+    let res = GetConduitShardsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(
+        res[1].status,
+        crate::eventsub::Status::WebhookCallbackVerificationPending
+    );
+}
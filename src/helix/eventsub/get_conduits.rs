@@ -0,0 +1,64 @@
+//! Gets the conduits for a client ID.
+//! [`get-conduits`](https://dev.twitch.tv/docs/api/reference#get-conduits)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Conduits](super::get_conduits)
+///
+/// [`get-conduits`](https://dev.twitch.tv/docs/api/reference#get-conduits)
+#[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct GetConduitsRequest {}
+
+impl GetConduitsRequest {
+    /// Create a new [`GetConduitsRequest`]
+    pub fn new() -> Self { GetConduitsRequest {} }
+}
+
+impl Request for GetConduitsRequest {
+    type Response = Vec<Conduit>;
+
+    const PATH: &'static str = "eventsub/conduits";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetConduitsRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetConduitsRequest::new();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "26b1c993-bfcf-44d9-b876-379dacafe75a",
+                "shard_count": 15
+            },
+            {
+                "id": "bfcfc993-26b1-b876-44d9-afe75a379dac",
+                "shard_count": 5
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/eventsub/conduits?"
+    );
+
+    let res = GetConduitsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.len(), 2);
+    assert_eq!(res[0].shard_count, 15);
+}
@@ -97,6 +97,8 @@ impl RequestGet for GetEventSubSubscriptionsRequest {
             },
             pagination: response.pagination.cursor,
             request,
+            total: Some(response.total as i64),
+            other: None,
         })
     }
 }
@@ -160,8 +162,16 @@ fn test_request() {
         "https://api.twitch.tv/helix/eventsub/subscriptions?"
     );
 
-    dbg!(
-        "{:#?}",
+    let res = dbg!(
         GetEventSubSubscriptionsRequest::parse_response(Some(req), &uri, http_response).unwrap()
+    )
+    .data;
+    assert_eq!(
+        res.subscriptions[1].condition,
+        crate::eventsub::Condition::from(
+            crate::eventsub::user::UserUpdateV1::builder()
+                .user_id("1234")
+                .build()
+        )
     );
 }
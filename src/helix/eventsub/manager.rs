@@ -0,0 +1,559 @@
+//! Keep a set of EventSub subscriptions in sync with what Twitch has
+//!
+//! [`SubscriptionManager`] takes the subscriptions you want and [reconciles](SubscriptionManager::reconcile) them with the subscriptions that exist,
+//! using [Get EventSub Subscriptions](super::get_eventsub_subscriptions), [Create EventSub Subscription](super::create_eventsub_subscription)
+//! and [Delete EventSub Subscription](super::delete_eventsub_subscription).
+//!
+//! * Missing subscriptions are created.
+//! * Duplicate subscriptions are deleted, keeping the enabled or oldest one.
+//! * Failed subscriptions, with status [`WebhookCallbackVerificationFailed`](eventsub::Status::WebhookCallbackVerificationFailed)
+//!   or [`NotificationFailuresExceeded`](eventsub::Status::NotificationFailuresExceeded), are deleted and created again if wanted.
+//! * With [`prune`](SubscriptionManager::prune), subscriptions that are not wanted are deleted.
+//!
+//! # Example
+//!
+//! ```rust, no_run
+//! use twitch_api2::eventsub::{channel::ChannelFollowV1, Transport};
+//! use twitch_api2::helix::{self, eventsub::manager::{DesiredSubscription, SubscriptionManager}};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let mut manager = SubscriptionManager::new(Transport::webhook("https://example.com/eventsub", "secretabcd"));
+//! for broadcaster_id in &["1234", "5678"] {
+//!     manager.add(DesiredSubscription::new(
+//!         &ChannelFollowV1::builder().broadcaster_user_id(*broadcaster_id).build(),
+//!     )?);
+//! }
+//! let report = manager.reconcile(&client, &token).await?;
+//! println!("using {} of {}", report.total_cost, report.max_total_cost);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::*;
+use crate::eventsub::{self, EventSubscription, EventType, Status, Transport, TransportResponse};
+use std::collections::HashSet;
+
+/// A subscription that should exist, see [`SubscriptionManager`]
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[non_exhaustive]
+pub struct DesiredSubscription {
+    /// The category of the subscription.
+    #[serde(rename = "type")]
+    pub type_: EventType,
+    /// The version of the subscription.
+    pub version: String,
+    /// JSON object specifying custom parameters for the subscription.
+    pub condition: serde_json::Value,
+}
+
+impl DesiredSubscription {
+    /// Create a [`DesiredSubscription`] from an [`EventSubscription`]
+    pub fn new<E: EventSubscription>(subscription: &E) -> Result<Self, serde_json::Error> {
+        Ok(DesiredSubscription {
+            type_: E::EVENT_TYPE,
+            version: E::VERSION.to_string(),
+            condition: subscription.condition()?,
+        })
+    }
+
+    /// Whether `subscription` is for the same event, version and condition as this subscription
+    ///
+    /// Empty strings and `null` in conditions are treated as missing.
+    pub fn matches(&self, subscription: &eventsub::EventSubSubscription) -> bool {
+        self.type_ == subscription.type_
+            && self.version == subscription.version
+            && match serde_json::to_value(&subscription.condition) {
+                Ok(condition) => condition_matches(&self.condition, &condition),
+                Err(_) => false,
+            }
+    }
+}
+
+impl CreatableSubscription for DesiredSubscription {
+    type Condition = serde_json::Value;
+
+    fn event_type(&self) -> EventType { self.type_.clone() }
+
+    fn version(&self) -> &str { &self.version }
+
+    fn create_condition(&self) -> Result<serde_json::Value, serde_json::Error> {
+        Ok(self.condition.clone())
+    }
+}
+
+/// Manages a set of EventSub subscriptions on a single transport
+#[derive(Debug, Clone)]
+pub struct SubscriptionManager {
+    transport: Transport,
+    desired: Vec<DesiredSubscription>,
+    prune: bool,
+}
+
+impl SubscriptionManager {
+    /// Create a manager for subscriptions using `transport`
+    pub fn new(transport: Transport) -> SubscriptionManager {
+        SubscriptionManager {
+            transport,
+            desired: vec![],
+            prune: false,
+        }
+    }
+
+    /// Add a subscription that should exist
+    pub fn add(&mut self, subscription: DesiredSubscription) {
+        if !self.desired.contains(&subscription) {
+            self.desired.push(subscription)
+        }
+    }
+
+    /// The subscriptions that should exist
+    pub fn desired(&self) -> &[DesiredSubscription] { &self.desired }
+
+    /// The transport used for created subscriptions
+    pub fn transport(&self) -> &Transport { &self.transport }
+
+    /// Also delete subscriptions that have not been [added](SubscriptionManager::add). Defaults to `false`.
+    ///
+    /// Only subscriptions on the same transport are deleted.
+    pub fn prune(mut self, prune: bool) -> SubscriptionManager {
+        self.prune = prune;
+        self
+    }
+
+    /// Decide what to do with the `existing` subscriptions
+    pub fn plan(&self, existing: &[eventsub::EventSubSubscription]) -> ReconcilePlan {
+        let mut plan = ReconcilePlan::default();
+        let mut handled = HashSet::new();
+
+        for desired in &self.desired {
+            let mut matching: Vec<_> = existing
+                .iter()
+                .filter(|s| {
+                    is_active(&s.status)
+                        && transport_matches(&self.transport, &s.transport)
+                        && desired.matches(s)
+                })
+                .collect();
+            // Prefer enabled subscriptions, then the oldest
+            matching.sort_by_key(|s| {
+                let created_at = types::parse_timestamp(&s.created_at);
+                (s.status != Status::Enabled, created_at.is_none(), created_at)
+            });
+            let mut matching = matching.into_iter();
+            match matching.next() {
+                Some(keep) => {
+                    if handled.insert(&keep.id) {
+                        plan.keep.push(keep.clone());
+                    }
+                }
+                None => plan.create.push(desired.clone()),
+            }
+            for duplicate in matching {
+                if handled.insert(&duplicate.id) {
+                    plan.delete.push(duplicate.clone());
+                }
+            }
+        }
+
+        for subscription in existing {
+            if handled.contains(&subscription.id) {
+                continue;
+            }
+            let failed = matches!(
+                subscription.status,
+                Status::WebhookCallbackVerificationFailed | Status::NotificationFailuresExceeded
+            );
+            let unwanted = self.prune
+                && is_active(&subscription.status)
+                && transport_matches(&self.transport, &subscription.transport);
+            if failed || unwanted {
+                handled.insert(&subscription.id);
+                plan.delete.push(subscription.clone());
+            }
+        }
+        plan
+    }
+
+    /// List all subscriptions, then create and delete subscriptions according to the [plan](SubscriptionManager::plan)
+    ///
+    /// Errors from listing subscriptions are returned, errors from creating or deleting a subscription are collected in the [report](ReconcileReport).
+    #[cfg(feature = "client")]
+    #[cfg_attr(nightly, doc(cfg(feature = "client")))]
+    pub async fn reconcile<'a, C, T>(
+        &self,
+        client: &'a helix::HelixClient<'a, C>,
+        token: &T,
+    ) -> Result<
+        ReconcileReport<<C as crate::HttpClient<'a>>::Error>,
+        helix::ClientRequestError<<C as crate::HttpClient<'a>>::Error>,
+    >
+    where
+        C: crate::HttpClient<'a>,
+        T: twitch_oauth2::TwitchToken + ?Sized,
+    {
+        let mut resp = client
+            .req_get(
+                GetEventSubSubscriptionsRequest::builder().first(100).build(),
+                token,
+            )
+            .await?;
+        let mut total_cost = resp.data.total_cost;
+        let mut max_total_cost = resp.data.max_total_cost;
+        let mut existing = std::mem::take(&mut resp.data.subscriptions);
+        while let Some(resp_new) = resp.get_next(client, token).await? {
+            resp = resp_new;
+            existing.extend(std::mem::take(&mut resp.data.subscriptions));
+        }
+
+        let plan = self.plan(&existing);
+        let mut report = ReconcileReport {
+            created: vec![],
+            deleted: vec![],
+            kept: plan.keep,
+            failed_creates: vec![],
+            failed_deletes: vec![],
+            total_cost,
+            max_total_cost,
+        };
+
+        // Delete first, to make room for new subscriptions
+        for subscription in plan.delete {
+            let deleted = client
+                .req_delete(
+                    DeleteEventSubSubscriptionRequest::builder()
+                        .id(subscription.id.clone())
+                        .build(),
+                    token,
+                )
+                .await;
+            match deleted {
+                Ok(DeleteEventSubSubscription::Success)
+                | Ok(DeleteEventSubSubscription::NotFound) => {
+                    total_cost = total_cost.saturating_sub(subscription.cost);
+                    report.deleted.push(subscription);
+                }
+                Ok(_) => report.failed_deletes.push((
+                    subscription,
+                    helix::ClientRequestError::Custom("subscription was not deleted".into()),
+                )),
+                Err(e) => report.failed_deletes.push((subscription, e)),
+            }
+        }
+
+        for desired in plan.create {
+            let created = client
+                .req_post(
+                    CreateEventSubSubscriptionRequest::builder().build(),
+                    CreateEventSubSubscriptionBody::new(desired.clone(), self.transport.clone()),
+                    token,
+                )
+                .await;
+            match created {
+                Ok(resp) => {
+                    let created = resp.data;
+                    total_cost = created.total_cost;
+                    max_total_cost = created.max_total_cost;
+                    report.created.push(eventsub::EventSubSubscription {
+                        condition: eventsub::Condition::from_value(
+                            &created.type_,
+                            &created.version,
+                            created.condition,
+                        ),
+                        cost: created.cost,
+                        created_at: created.created_at,
+                        id: created.id,
+                        status: created.status,
+                        transport: created.transport,
+                        type_: created.type_,
+                        version: created.version,
+                    });
+                }
+                Err(e) => report.failed_creates.push((desired, e)),
+            }
+        }
+
+        report.total_cost = total_cost;
+        report.max_total_cost = max_total_cost;
+        Ok(report)
+    }
+}
+
+/// What [`SubscriptionManager::reconcile`] will do, see [`SubscriptionManager::plan`]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct ReconcilePlan {
+    /// Subscriptions to create.
+    pub create: Vec<DesiredSubscription>,
+    /// Duplicate, failed and unwanted subscriptions to delete.
+    pub delete: Vec<eventsub::EventSubSubscription>,
+    /// Subscriptions to keep.
+    pub keep: Vec<eventsub::EventSubSubscription>,
+}
+
+/// What [`SubscriptionManager::reconcile`] did
+#[cfg(feature = "client")]
+#[cfg_attr(nightly, doc(cfg(feature = "client")))]
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ReconcileReport<RE: std::error::Error + Send + Sync + 'static> {
+    /// Subscriptions that were created.
+    pub created: Vec<eventsub::EventSubSubscription>,
+    /// Subscriptions that were deleted.
+    pub deleted: Vec<eventsub::EventSubSubscription>,
+    /// Subscriptions that already existed.
+    pub kept: Vec<eventsub::EventSubSubscription>,
+    /// Subscriptions that could not be created.
+    pub failed_creates: Vec<(DesiredSubscription, helix::ClientRequestError<RE>)>,
+    /// Subscriptions that could not be deleted.
+    pub failed_deletes: Vec<(
+        eventsub::EventSubSubscription,
+        helix::ClientRequestError<RE>,
+    )>,
+    /// Total cost of all the subscriptions for the client ID after reconciling.
+    pub total_cost: usize,
+    /// The maximum total cost allowed for all of the subscriptions for the client ID.
+    pub max_total_cost: usize,
+}
+
+#[cfg(feature = "client")]
+impl<RE: std::error::Error + Send + Sync + 'static> ReconcileReport<RE> {
+    /// Whether all subscriptions were created and deleted successfully
+    pub fn is_success(&self) -> bool {
+        self.failed_creates.is_empty() && self.failed_deletes.is_empty()
+    }
+
+    /// Cost left before reaching [`max_total_cost`](ReconcileReport::max_total_cost)
+    pub fn remaining_cost(&self) -> usize { self.max_total_cost.saturating_sub(self.total_cost) }
+}
+
+/// Subscriptions that will deliver notifications, or will once verified
+fn is_active(status: &Status) -> bool {
+    matches!(
+        status,
+        Status::Enabled | Status::WebhookCallbackVerificationPending
+    )
+}
+
+fn transport_matches(transport: &Transport, response: &TransportResponse) -> bool {
+    transport.method == response.method
+        && transport.callback == response.callback
+        && transport.conduit_id == response.conduit_id
+        && transport.session_id == response.session_id
+}
+
+fn condition_matches(desired: &serde_json::Value, existing: &serde_json::Value) -> bool {
+    use serde_json::Value;
+
+    fn is_empty(value: &Value) -> bool {
+        match value {
+            Value::Null => true,
+            Value::String(s) => s.is_empty(),
+            _ => false,
+        }
+    }
+
+    match (desired, existing) {
+        (Value::Object(desired), Value::Object(existing)) => desired
+            .keys()
+            .chain(existing.keys())
+            .all(|key| match (desired.get(key), existing.get(key)) {
+                (Some(a), Some(b)) => a == b || (is_empty(a) && is_empty(b)),
+                (Some(v), None) | (None, Some(v)) => is_empty(v),
+                (None, None) => true,
+            }),
+        (desired, existing) => desired == existing,
+    }
+}
+
+#[test]
+fn test_plan() {
+    use crate::eventsub::channel::ChannelFollowV1;
+
+    let mut manager = SubscriptionManager::new(Transport::webhook(
+        "https://example.com/eventsub",
+        "secretabcd",
+    ));
+    for broadcaster_user_id in &["1", "2", "3"] {
+        manager.add(
+            DesiredSubscription::new(
+                &ChannelFollowV1::builder()
+                    .broadcaster_user_id(*broadcaster_user_id)
+                    .build(),
+            )
+            .unwrap(),
+        );
+    }
+    manager.add(
+        DesiredSubscription::new(&ChannelFollowV1::builder().broadcaster_user_id("1").build())
+            .unwrap(),
+    );
+    assert_eq!(manager.desired().len(), 3);
+
+    // "a", "b" and "c" are duplicates for "1", "d" for "2" has failed and "e" for "4" is unwanted
+    let existing: Vec<eventsub::EventSubSubscription> = serde_json::from_str(
+        r#"[
+    {
+        "id": "a",
+        "status": "webhook_callback_verification_pending",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {
+            "broadcaster_user_id": "1"
+        },
+        "transport": {
+            "method": "webhook",
+            "callback": "https://example.com/eventsub"
+        },
+        "created_at": "2021-05-01T00:00:00Z"
+    },
+    {
+        "id": "b",
+        "status": "enabled",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {
+            "broadcaster_user_id": "1"
+        },
+        "transport": {
+            "method": "webhook",
+            "callback": "https://example.com/eventsub"
+        },
+        "created_at": "2021-05-02T00:00:00Z"
+    },
+    {
+        "id": "c",
+        "status": "enabled",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {
+            "broadcaster_user_id": "1"
+        },
+        "transport": {
+            "method": "webhook",
+            "callback": "https://example.com/eventsub"
+        },
+        "created_at": "2021-05-03T00:00:00Z"
+    },
+    {
+        "id": "d",
+        "status": "notification_failures_exceeded",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {
+            "broadcaster_user_id": "2"
+        },
+        "transport": {
+            "method": "webhook",
+            "callback": "https://example.com/eventsub"
+        },
+        "created_at": "2021-05-01T00:00:00Z"
+    },
+    {
+        "id": "e",
+        "status": "enabled",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {
+            "broadcaster_user_id": "4"
+        },
+        "transport": {
+            "method": "webhook",
+            "callback": "https://example.com/eventsub"
+        },
+        "created_at": "2021-05-01T00:00:00Z"
+    }
+]"#,
+    )
+    .unwrap();
+
+    let plan = manager.plan(&existing);
+    let ids = |subscriptions: &[eventsub::EventSubSubscription]| -> Vec<String> {
+        subscriptions.iter().map(|s| s.id.to_string()).collect()
+    };
+    assert_eq!(ids(&plan.keep), vec!["b"]);
+    assert_eq!(ids(&plan.delete), vec!["c", "a", "d"]);
+    assert_eq!(
+        plan.create
+            .iter()
+            .map(|d| d.condition["broadcaster_user_id"].as_str().unwrap())
+            .collect::<Vec<_>>(),
+        vec!["2", "3"]
+    );
+
+    let plan = manager.prune(true).plan(&existing);
+    assert_eq!(ids(&plan.delete), vec!["c", "a", "d", "e"]);
+
+    let body = CreateEventSubSubscriptionBody::new(
+        plan.create[0].clone(),
+        Transport::webhook("https://example.com/eventsub", "secretabcd"),
+    );
+    assert_eq!(
+        std::str::from_utf8(&helix::HelixRequestBody::try_to_body(&body).unwrap()).unwrap(),
+        r#"{"type":"channel.follow","version":"1","condition":{"broadcaster_user_id":"2"},"transport":{"method":"webhook","callback":"https://example.com/eventsub","secret":"secretabcd"}}"#
+    );
+}
+
+#[test]
+fn test_plan_mixed_precision() {
+    use crate::eventsub::channel::ChannelFollowV1;
+
+    let mut manager = SubscriptionManager::new(Transport::webhook(
+        "https://example.com/eventsub",
+        "secretabcd",
+    ));
+    manager.add(
+        DesiredSubscription::new(&ChannelFollowV1::builder().broadcaster_user_id("1").build())
+            .unwrap(),
+    );
+
+    // "a" is newer than "b", even though it sorts first as a string
+    let existing: Vec<eventsub::EventSubSubscription> = serde_json::from_str(
+        r#"[
+    {
+        "id": "a",
+        "status": "enabled",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {
+            "broadcaster_user_id": "1"
+        },
+        "transport": {
+            "method": "webhook",
+            "callback": "https://example.com/eventsub"
+        },
+        "created_at": "2021-05-01T00:00:00.5Z"
+    },
+    {
+        "id": "b",
+        "status": "enabled",
+        "type": "channel.follow",
+        "version": "1",
+        "cost": 1,
+        "condition": {
+            "broadcaster_user_id": "1"
+        },
+        "transport": {
+            "method": "webhook",
+            "callback": "https://example.com/eventsub"
+        },
+        "created_at": "2021-05-01T00:00:00Z"
+    }
+]"#,
+    )
+    .unwrap();
+
+    let plan = manager.plan(&existing);
+    assert_eq!(plan.keep[0].id, "b");
+    assert_eq!(plan.delete[0].id, "a");
+    assert_eq!(plan.delete.len(), 1);
+}
@@ -0,0 +1,128 @@
+//! Updates a conduit’s shard count.
+//! [`update-conduits`](https://dev.twitch.tv/docs/api/reference#update-conduits)
+//!
+//! # Notes
+//!
+//! To delete shards, update the count to a lower number, and the shards above the count will be deleted.
+//! For example, if the existing shard count is 100, by resetting shard count to 50, shards 50-99 are disabled.
+
+use super::*;
+use helix::RequestPatch;
+
+/// Query Parameters for [Update Conduit](super::update_conduit)
+///
+/// [`update-conduits`](https://dev.twitch.tv/docs/api/reference#update-conduits)
+#[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateConduitRequest {}
+
+impl UpdateConduitRequest {
+    /// Create a new [`UpdateConduitRequest`]
+    pub fn new() -> Self { UpdateConduitRequest {} }
+}
+
+/// Body Parameters for [Update Conduit](super::update_conduit)
+///
+/// [`update-conduits`](https://dev.twitch.tv/docs/api/reference#update-conduits)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct UpdateConduitBody {
+    /// Conduit ID.
+    #[builder(setter(into))]
+    pub id: String,
+    /// The new number of shards for this conduit.
+    pub shard_count: usize,
+}
+
+impl helix::private::SealedSerialize for UpdateConduitBody {}
+
+impl Request for UpdateConduitRequest {
+    type Response = Conduit;
+
+    const PATH: &'static str = "eventsub/conduits";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPatch for UpdateConduitRequest {
+    type Body = UpdateConduitBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<Self::Response>> = helix::parse_json(&response_str)
+            .map_err(|e| {
+                helix::HelixRequestPatchError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            helix::HelixRequestPatchError::InvalidResponse {
+                reason: "response included no data",
+                response: response_str.to_string(),
+                status,
+                uri: uri.clone(),
+            }
+        })?;
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateConduitRequest::new();
+
+    let body = UpdateConduitBody::builder()
+        .id("bfcfc993-26b1-b876-44d9-afe75a379dac")
+        .shard_count(5)
+        .build();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"id":"bfcfc993-26b1-b876-44d9-afe75a379dac","shard_count":5}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "bfcfc993-26b1-b876-44d9-afe75a379dac",
+                "shard_count": 5
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/eventsub/conduits?"
+    );
+
+    let res = UpdateConduitRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.shard_count, 5);
+}
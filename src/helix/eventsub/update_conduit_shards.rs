@@ -0,0 +1,203 @@
+//! Updates shard(s) for a conduit.
+//! [`update-conduit-shards`](https://dev.twitch.tv/docs/api/reference#update-conduit-shards)
+//!
+//! # Notes
+//!
+//! Shard IDs are indexed starting at 0, so a conduit with a `shard_count` of 5 will have shards with IDs 0 through 4.
+
+use super::*;
+use helix::RequestPatch;
+
+/// Query Parameters for [Update Conduit Shards](super::update_conduit_shards)
+///
+/// [`update-conduit-shards`](https://dev.twitch.tv/docs/api/reference#update-conduit-shards)
+#[derive(PartialEq, typed_builder::TypedBuilder, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateConduitShardsRequest {}
+
+impl UpdateConduitShardsRequest {
+    /// Create a new [`UpdateConduitShardsRequest`]
+    pub fn new() -> Self { UpdateConduitShardsRequest {} }
+}
+
+/// Body Parameters for [Update Conduit Shards](super::update_conduit_shards)
+///
+/// [`update-conduit-shards`](https://dev.twitch.tv/docs/api/reference#update-conduit-shards)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct UpdateConduitShardsBody {
+    /// Conduit ID.
+    #[builder(setter(into))]
+    pub conduit_id: String,
+    /// List of shards to update.
+    pub shards: Vec<ShardUpdate>,
+}
+
+impl helix::private::SealedSerialize for UpdateConduitShardsBody {}
+
+/// A shard to update in [Update Conduit Shards](super::update_conduit_shards)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct ShardUpdate {
+    /// Shard ID.
+    #[builder(setter(into))]
+    pub id: String,
+    /// The transport details that you want Twitch to use when sending you notifications.
+    pub transport: crate::eventsub::Transport,
+}
+
+impl ShardUpdate {
+    /// Update shard `id` to use `transport`
+    pub fn new(id: impl Into<String>, transport: crate::eventsub::Transport) -> Self {
+        Self {
+            id: id.into(),
+            transport,
+        }
+    }
+}
+
+/// Return Values for [Update Conduit Shards](super::update_conduit_shards)
+///
+/// [`update-conduit-shards`](https://dev.twitch.tv/docs/api/reference#update-conduit-shards)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct UpdateConduitShards {
+    /// List of successful shard updates.
+    pub shards: Vec<Shard>,
+    /// List of unsuccessful updates.
+    pub errors: Vec<ShardError>,
+}
+
+/// A shard that could not be updated in [Update Conduit Shards](super::update_conduit_shards)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ShardError {
+    /// Shard ID.
+    pub id: String,
+    /// The error that occurred while updating the shard.
+    pub message: String,
+    /// Error codes used to represent a specific error condition while attempting to update shards.
+    pub code: String,
+}
+
+impl Request for UpdateConduitShardsRequest {
+    type Response = UpdateConduitShards;
+
+    const PATH: &'static str = "eventsub/conduits/shards";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestPatch for UpdateConduitShardsRequest {
+    type Body = UpdateConduitShardsBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    where
+        Self: Sized,
+    {
+        #[derive(PartialEq, Deserialize, Debug, Clone)]
+        struct InnerResponse {
+            data: Vec<Shard>,
+            #[serde(default)]
+            errors: Vec<ShardError>,
+        }
+
+        let response: InnerResponse = helix::parse_json(&response_str).map_err(|e| {
+            helix::HelixRequestPatchError::DeserializeError(
+                response_str.to_string(),
+                e,
+                uri.clone(),
+                status,
+            )
+        })?;
+        Ok(helix::Response {
+            data: UpdateConduitShards {
+                shards: response.data,
+                errors: response.errors,
+            },
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateConduitShardsRequest::new();
+
+    let body = UpdateConduitShardsBody::builder()
+        .conduit_id("bfcfc993-26b1-b876-44d9-afe75a379dac")
+        .shards(vec![
+            ShardUpdate::new(
+                "0",
+                crate::eventsub::Transport::webhook("https://this-is-a-callback.com", "s3cre7"),
+            ),
+            ShardUpdate::new(
+                "1",
+                crate::eventsub::Transport::webhook("https://this-is-a-callback-2.com", "s3cre7"),
+            ),
+            ShardUpdate::new(
+                "3",
+                crate::eventsub::Transport::webhook("https://this-is-a-callback-3.com", "s3cre7"),
+            ),
+        ])
+        .build();
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "0",
+                "status": "enabled",
+                "transport": {
+                    "method": "webhook",
+                    "callback": "https://this-is-a-callback.com"
+                }
+            },
+            {
+                "id": "1",
+                "status": "webhook_callback_verification_pending",
+                "transport": {
+                    "method": "webhook",
+                    "callback": "https://this-is-a-callback-2.com"
+                }
+            }
+        ],
+        "errors": [
+            {
+                "id": "3",
+                "message": "The shard id is outside the conduit's range",
+                "code": "invalid_parameter"
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(202).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/eventsub/conduits/shards?"
+    );
+
+    let res = UpdateConduitShardsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.shards.len(), 2);
+    assert_eq!(res.errors[0].id, "3");
+}
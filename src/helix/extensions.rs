@@ -0,0 +1,120 @@
+#![doc(alias = "extension")]
+//! Helix endpoints regarding extensions
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, extensions::GetUserActiveExtensionsRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetUserActiveExtensionsRequest::builder()
+//!     .user_id("1234".to_string())
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data.panel);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub mod get_extension_configuration_segment;
+pub mod get_extension_transactions;
+pub mod get_extensions_live_channels;
+pub mod get_user_active_extensions;
+pub mod get_user_extensions;
+pub mod update_user_extensions;
+
+#[doc(inline)]
+pub use get_extension_configuration_segment::{
+    ExtensionConfigurationSegment, GetExtensionConfigurationSegmentRequest, Segment,
+};
+#[doc(inline)]
+pub use get_extension_transactions::{
+    Cost, CostType, ExtensionTransaction, GetExtensionTransactionsRequest, ProductData,
+    ProductType,
+};
+#[doc(inline)]
+pub use get_extensions_live_channels::{ExtensionLiveChannel, GetExtensionsLiveChannelsRequest};
+#[doc(inline)]
+pub use get_user_active_extensions::{GetUserActiveExtensionsRequest, UserActiveExtensions};
+#[doc(inline)]
+pub use get_user_extensions::{ExtensionType, GetUserExtensionsRequest, UserExtension};
+#[doc(inline)]
+pub use update_user_extensions::{
+    UpdateUserExtensions, UpdateUserExtensionsBody, UpdateUserExtensionsRequest,
+};
+
+/// A slot where an extension can be activated. Panels can use slot 1 to 3, overlays and components only slot 1 and 2.
+#[derive(PartialEq, Eq, Hash, Deserialize, Serialize, Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum ExtensionSlot {
+    /// Slot 1
+    #[serde(rename = "1")]
+    Slot1,
+    /// Slot 2
+    #[serde(rename = "2")]
+    Slot2,
+    /// Slot 3
+    #[serde(rename = "3")]
+    Slot3,
+}
+
+/// Extension activated in a panel or overlay [slot](ExtensionSlot)
+#[derive(PartialEq, Eq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ActiveExtension {
+    /// Activation state of the extension.
+    pub active: bool,
+    /// ID of the extension. Not set when the slot is inactive.
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<types::ExtensionId>,
+    /// Version of the extension. Not set when the slot is inactive.
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Name of the extension. Not set when the slot is inactive.
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Extension activated in a component [slot](ExtensionSlot)
+#[derive(PartialEq, Eq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ActiveComponentExtension {
+    /// Activation state of the extension.
+    pub active: bool,
+    /// ID of the extension. Not set when the slot is inactive.
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<types::ExtensionId>,
+    /// Version of the extension. Not set when the slot is inactive.
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Name of the extension. Not set when the slot is inactive.
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// X-coordinate of the placement of the extension.
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x: Option<i64>,
+    /// Y-coordinate of the placement of the extension.
+    #[builder(default, setter(into))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<i64>,
+}
@@ -0,0 +1,141 @@
+//! Gets the specified configuration segment from the specified extension.
+//! [`get-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment)
+//!
+//! # Notes
+//!
+//! This endpoint requires a signed JWT created by an Extension Backend Service (EBS) instead of an OAuth token.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionConfigurationSegmentRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionConfigurationSegmentRequest`] with the [`GetExtensionConfigurationSegmentRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::extensions::get_extension_configuration_segment::{self, Segment};
+//! let request = get_extension_configuration_segment::GetExtensionConfigurationSegmentRequest::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2".to_string())
+//!     .segment(vec![Segment::Global])
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionConfigurationSegment]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, extensions::get_extension_configuration_segment::{self, Segment}};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_extension_configuration_segment::GetExtensionConfigurationSegmentRequest::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2".to_string())
+//!     .segment(vec![Segment::Global])
+//!     .build();
+//! let response: Vec<get_extension_configuration_segment::ExtensionConfigurationSegment> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionConfigurationSegmentRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionConfigurationSegmentRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Configuration Segment](super::get_extension_configuration_segment)
+///
+/// [`get-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetExtensionConfigurationSegmentRequest {
+    /// The ID of the broadcaster for the configuration returned. This parameter is required if you set the segment parameter to broadcaster or developer. Do not specify this parameter if you set segment to global.
+    #[builder(default, setter(into))]
+    pub broadcaster_id: Option<types::UserId>,
+    /// The ID of the extension that contains the configuration segment you want to get.
+    #[builder(setter(into))]
+    pub extension_id: types::ExtensionId,
+    /// The type of configuration segment to get.
+    #[builder(default)]
+    pub segment: Vec<Segment>,
+}
+
+/// Configuration segment type
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum Segment {
+    /// Configuration for a specific broadcaster, set by the broadcaster
+    Broadcaster,
+    /// Configuration for a specific broadcaster, set by the developer
+    Developer,
+    /// Configuration for all broadcasters, set by the developer
+    Global,
+}
+
+/// Return Values for [Get Extension Configuration Segment](super::get_extension_configuration_segment)
+///
+/// [`get-extension-configuration-segment`](https://dev.twitch.tv/docs/api/reference#get-extension-configuration-segment)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionConfigurationSegment {
+    /// The type of segment.
+    pub segment: Segment,
+    /// The ID of the broadcaster that installed the extension. Not set if the segment is [`Segment::Global`].
+    #[serde(default)]
+    pub broadcaster_id: Option<types::UserId>,
+    /// The contents of the segment. This string may be a plain-text string or a string-encoded JSON object.
+    pub content: String,
+    /// The version number that identifies this definition of the segment’s data.
+    pub version: String,
+}
+
+impl Request for GetExtensionConfigurationSegmentRequest {
+    type Response = Vec<ExtensionConfigurationSegment>;
+
+    const PATH: &'static str = "extensions/configurations";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetExtensionConfigurationSegmentRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionConfigurationSegmentRequest::builder()
+        .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2".to_string())
+        .segment(vec![Segment::Global])
+        .build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "segment": "global",
+            "content": "{\"foo\":\"bar\"}",
+            "version": "0.0.1"
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/configurations?extension_id=uo6dggojyb8d6soh92zknwmi5ej1q2&segment=global"
+    );
+
+    dbg!(
+        GetExtensionConfigurationSegmentRequest::parse_response(Some(req), &uri, http_response)
+            .unwrap()
+    );
+}
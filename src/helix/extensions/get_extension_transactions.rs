@@ -0,0 +1,224 @@
+//! Get Extension Transactions allows extension back end servers to fetch a list of transactions that have occurred for their extension across all of Twitch.
+//! [`get-extension-transactions`](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+//!
+//! A transaction is a record of a user exchanging Bits for an in-Extension digital good.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionTransactionsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionTransactionsRequest`] with the [`GetExtensionTransactionsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::extensions::get_extension_transactions;
+//! let request = get_extension_transactions::GetExtensionTransactionsRequest::builder()
+//!     .extension_id("1234".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionTransaction]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, extensions::get_extension_transactions};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_extension_transactions::GetExtensionTransactionsRequest::builder()
+//!     .extension_id("1234".to_string())
+//!     .build();
+//! let response: Vec<get_extension_transactions::ExtensionTransaction> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionTransactionsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionTransactionsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Transactions](super::get_extension_transactions)
+///
+/// [`get-extension-transactions`](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetExtensionTransactionsRequest {
+    /// ID of the extension to list transactions for.
+    #[builder(setter(into))]
+    pub extension_id: types::ExtensionId,
+    /// Transaction IDs to look up. Can include multiple to fetch multiple transactions in a single request. Maximum: 100
+    #[builder(default)]
+    pub id: Vec<String>,
+    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+    /// Maximum number of objects to return. Maximum: 100. Default: 20.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+}
+
+/// Return Values for [Get Extension Transactions](super::get_extension_transactions)
+///
+/// [`get-extension-transactions`](https://dev.twitch.tv/docs/api/reference#get-extension-transactions)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionTransaction {
+    /// Unique identifier of the Bits-in-Extensions transaction.
+    pub id: String,
+    /// UTC timestamp when this transaction occurred.
+    pub timestamp: types::Timestamp,
+    /// Twitch user ID of the channel the transaction occurred on.
+    pub broadcaster_id: types::UserId,
+    /// Login name of the broadcaster.
+    pub broadcaster_login: types::UserName,
+    /// Twitch display name of the broadcaster.
+    pub broadcaster_name: types::DisplayName,
+    /// Twitch user ID of the user who generated the transaction.
+    pub user_id: types::UserId,
+    /// Login name of the user who generated the transaction.
+    pub user_login: types::UserName,
+    /// Twitch display name of the user who generated the transaction.
+    pub user_name: types::DisplayName,
+    /// Enum of the product type. Currently only `BITS_IN_EXTENSION`.
+    pub product_type: ProductType,
+    /// Details about the product that was purchased.
+    pub product_data: ProductData,
+}
+
+/// Type of product in a [transaction](ExtensionTransaction)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ProductType {
+    /// Bits in extension
+    BitsInExtension,
+}
+
+/// Details about a product that was purchased in a [transaction](ExtensionTransaction)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ProductData {
+    /// Indicates the type of transaction, e.g. `twitch.ext` followed by the extension ID.
+    pub domain: String,
+    /// Unique identifier for the product across the extension.
+    pub sku: String,
+    /// The cost of the product.
+    pub cost: Cost,
+    /// Indicates if the product is in development and not available for public use.
+    #[serde(rename = "inDevelopment")]
+    pub in_development: bool,
+    /// Display name of the product.
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    /// Expiration of the product. Always empty for products in a transaction.
+    #[serde(default)]
+    pub expiration: String,
+    /// Indicates whether or not the data was sent over the extension pubsub to all instances of the extension.
+    pub broadcast: bool,
+}
+
+/// Cost of a product
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Cost {
+    /// Number of units of the product's cost.
+    pub amount: i64,
+    /// Unit of the cost.
+    #[serde(rename = "type")]
+    pub type_: CostType,
+}
+
+impl Cost {
+    /// A cost of `amount` bits
+    pub fn bits(amount: i64) -> Cost {
+        Cost {
+            amount,
+            type_: CostType::Bits,
+        }
+    }
+}
+
+/// Unit of a [cost](Cost)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum CostType {
+    /// Bits
+    Bits,
+}
+
+impl Request for GetExtensionTransactionsRequest {
+    type Response = Vec<ExtensionTransaction>;
+
+    const PATH: &'static str = "extensions/transactions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetExtensionTransactionsRequest {}
+
+impl helix::Paginated for GetExtensionTransactionsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionTransactionsRequest::builder()
+        .extension_id("1234".to_string())
+        .id(vec!["74c52265-e214-48a6-91b9-23b6014e8041".to_string()])
+        .build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "id": "74c52265-e214-48a6-91b9-23b6014e8041",
+            "timestamp": "2019-01-28T04:15:53.325Z",
+            "broadcaster_id": "439964613",
+            "broadcaster_login": "chikuseuma",
+            "broadcaster_name": "chikuseuma",
+            "user_id": "424596340",
+            "user_login": "quotrok",
+            "user_name": "quotrok",
+            "product_type": "BITS_IN_EXTENSION",
+            "product_data": {
+                "domain": "twitch.ext.uo6dggojyb8d6soh92zknwmi5ej1q2",
+                "sku": "testSku100",
+                "cost": {
+                    "amount": 100,
+                    "type": "bits"
+                },
+                "inDevelopment": false,
+                "displayName": "Test Product 100",
+                "expiration": "",
+                "broadcast": false
+            }
+        }
+    ],
+    "pagination": {
+        "cursor": "cursorString"
+    }
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/transactions?extension_id=1234&id=74c52265-e214-48a6-91b9-23b6014e8041"
+    );
+
+    dbg!(GetExtensionTransactionsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
@@ -0,0 +1,175 @@
+//! Returns one page of live channels that have installed or activated a specific Extension, identified by a client ID value assigned to the Extension when it is created.
+//! [`get-extension-live-channels`](https://dev.twitch.tv/docs/api/reference#get-extension-live-channels)
+//!
+//! A channel that recently went live may take a few minutes to appear in this list, and a channel may continue to appear on this list for a few minutes after it stops broadcasting.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetExtensionsLiveChannelsRequest]
+//!
+//! To use this endpoint, construct a [`GetExtensionsLiveChannelsRequest`] with the [`GetExtensionsLiveChannelsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::extensions::get_extensions_live_channels;
+//! let request = get_extensions_live_channels::GetExtensionsLiveChannelsRequest::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [ExtensionLiveChannel]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, extensions::get_extensions_live_channels};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_extensions_live_channels::GetExtensionsLiveChannelsRequest::builder()
+//!     .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2".to_string())
+//!     .build();
+//! let response: Vec<get_extensions_live_channels::ExtensionLiveChannel> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetExtensionsLiveChannelsRequest::parse_response(None, &request.get_uri(), response)`](GetExtensionsLiveChannelsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Extension Live Channels](super::get_extensions_live_channels)
+///
+/// [`get-extension-live-channels`](https://dev.twitch.tv/docs/api/reference#get-extension-live-channels)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetExtensionsLiveChannelsRequest {
+    /// ID of the extension to search for.
+    #[builder(setter(into))]
+    pub extension_id: types::ExtensionId,
+    /// Maximum number of objects to return. Maximum: 100. Default: 20.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+    /// The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+}
+
+/// Return Values for [Get Extension Live Channels](super::get_extensions_live_channels)
+///
+/// [`get-extension-live-channels`](https://dev.twitch.tv/docs/api/reference#get-extension-live-channels)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ExtensionLiveChannel {
+    /// ID of the broadcaster.
+    pub broadcaster_id: types::UserId,
+    /// Broadcaster’s display name.
+    pub broadcaster_name: types::DisplayName,
+    /// Name of the game being played.
+    pub game_name: String,
+    /// ID of the game being played.
+    pub game_id: types::CategoryId,
+    /// Title of the stream.
+    pub title: String,
+}
+
+impl Request for GetExtensionsLiveChannelsRequest {
+    type Response = Vec<ExtensionLiveChannel>;
+
+    const PATH: &'static str = "extensions/live";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetExtensionsLiveChannelsRequest {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        // FIXME: This endpoint returns the cursor directly in `pagination` instead of `pagination.cursor`
+        #[derive(PartialEq, Deserialize, Debug)]
+        struct InnerResponse {
+            data: Vec<ExtensionLiveChannel>,
+            #[serde(default, deserialize_with = "helix::deserialize_none_from_empty_string")]
+            pagination: Option<helix::Cursor>,
+        }
+
+        let response: InnerResponse = helix::parse_json(response).map_err(|e| {
+            helix::HelixRequestGetError::DeserializeError(
+                response.to_string(),
+                e,
+                uri.clone(),
+                status,
+            )
+        })?;
+        Ok(helix::Response {
+            data: response.data,
+            pagination: response.pagination,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+impl helix::Paginated for GetExtensionsLiveChannelsRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetExtensionsLiveChannelsRequest::builder()
+        .extension_id("uo6dggojyb8d6soh92zknwmi5ej1q2".to_string())
+        .build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "broadcaster_id": "252766116",
+            "broadcaster_name": "swoosh_xii",
+            "game_name": "Tom Clancy's Rainbow Six Siege",
+            "game_id": "460630",
+            "title": "[PS4] ITA/ENG UNRANKED CHILL(SENZA IMPEGNO)"
+        },
+        {
+            "broadcaster_id": "102235475",
+            "broadcaster_name": "tyrahhh",
+            "game_name": "Dead by Daylight",
+            "game_id": "491487",
+            "title": "rank 1 DBD survivor <3"
+        }
+    ],
+    "pagination": "YVc1emRHRnNiRG94T1RJMU5UVTFPRGc0"
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/extensions/live?extension_id=uo6dggojyb8d6soh92zknwmi5ej1q2"
+    );
+
+    let res =
+        GetExtensionsLiveChannelsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        res.pagination.as_deref(),
+        Some("YVc1emRHRnNiRG94T1RJMU5UVTFPRGc0")
+    );
+    dbg!(res);
+}
@@ -0,0 +1,159 @@
+//! Gets information about active extensions installed by a specified user, identified by a user ID or Bearer token.
+//! [`get-user-active-extensions`](https://dev.twitch.tv/docs/api/reference#get-user-active-extensions)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetUserActiveExtensionsRequest]
+//!
+//! To use this endpoint, construct a [`GetUserActiveExtensionsRequest`] with the [`GetUserActiveExtensionsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::extensions::get_user_active_extensions;
+//! let request = get_user_active_extensions::GetUserActiveExtensionsRequest::builder()
+//!     .user_id("1234".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [UserActiveExtensions]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, extensions::get_user_active_extensions};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_user_active_extensions::GetUserActiveExtensionsRequest::builder()
+//!     .user_id("1234".to_string())
+//!     .build();
+//! let response: get_user_active_extensions::UserActiveExtensions = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetUserActiveExtensionsRequest::parse_response(None, &request.get_uri(), response)`](GetUserActiveExtensionsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get User Active Extensions](super::get_user_active_extensions)
+///
+/// [`get-user-active-extensions`](https://dev.twitch.tv/docs/api/reference#get-user-active-extensions)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetUserActiveExtensionsRequest {
+    /// ID of the user whose installed extensions will be returned. If not provided, the user in the Bearer token is used.
+    #[builder(default, setter(into))]
+    pub user_id: Option<types::UserId>,
+}
+
+/// Return Values for [Get User Active Extensions](super::get_user_active_extensions)
+///
+/// [`get-user-active-extensions`](https://dev.twitch.tv/docs/api/reference#get-user-active-extensions)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct UserActiveExtensions {
+    /// Extensions in panel slots.
+    #[serde(default)]
+    pub panel: HashMap<ExtensionSlot, ActiveExtension>,
+    /// Extensions in overlay slots.
+    #[serde(default)]
+    pub overlay: HashMap<ExtensionSlot, ActiveExtension>,
+    /// Extensions in component slots.
+    #[serde(default)]
+    pub component: HashMap<ExtensionSlot, ActiveComponentExtension>,
+}
+
+impl Request for GetUserActiveExtensionsRequest {
+    type Response = UserActiveExtensions;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[
+        twitch_oauth2::Scope::UserReadBroadcast,
+        twitch_oauth2::Scope::UserEditBroadcast,
+    ];
+    const PATH: &'static str = "users/extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[];
+}
+
+impl RequestGet for GetUserActiveExtensionsRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetUserActiveExtensionsRequest::builder()
+        .user_id("1234".to_string())
+        .build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": {
+        "panel": {
+            "1": {
+                "active": true,
+                "id": "rh6jq1q334hqc2rr1qlzqbvwlfl3x0",
+                "version": "1.1.0",
+                "name": "TopClip"
+            },
+            "2": {
+                "active": true,
+                "id": "wi08ebtatdc7oj83wtl9uxwz807l8b",
+                "version": "1.1.8",
+                "name": "Streamlabs Leaderboard"
+            },
+            "3": {
+                "active": true,
+                "id": "naty2zwfp7vecaivuve8ef1hohh6bo",
+                "version": "1.0.9",
+                "name": "Streamlabs Stream Schedule & Countdown"
+            }
+        },
+        "overlay": {
+            "1": {
+                "active": true,
+                "id": "zfh2irvx2jb4s60f02jq0ajm8vwgka",
+                "version": "1.0.19",
+                "name": "Streamlabs"
+            }
+        },
+        "component": {
+            "1": {
+                "active": true,
+                "id": "lqnf3zxk0rv0g7gq92mtmnirjz2cjj",
+                "version": "0.0.1",
+                "name": "Dev Experience Test",
+                "x": 0,
+                "y": 0
+            },
+            "2": {
+                "active": false
+            }
+        }
+    }
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/users/extensions?user_id=1234"
+    );
+
+    let res = GetUserActiveExtensionsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.panel.len(), 3);
+    assert!(!res.component[&ExtensionSlot::Slot2].active);
+    assert_eq!(res.component[&ExtensionSlot::Slot1].x, Some(0));
+    dbg!(res);
+}
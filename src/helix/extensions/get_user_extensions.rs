@@ -0,0 +1,164 @@
+//! Gets a list of all extensions (both active and inactive) for a specified user, identified by a Bearer token.
+//! [`get-user-extensions`](https://dev.twitch.tv/docs/api/reference#get-user-extensions)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetUserExtensionsRequest]
+//!
+//! To use this endpoint, construct a [`GetUserExtensionsRequest`] with the [`GetUserExtensionsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::extensions::get_user_extensions;
+//! let request = get_user_extensions::GetUserExtensionsRequest::builder().build();
+//! ```
+//!
+//! ## Response: [UserExtension]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, extensions::get_user_extensions};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_user_extensions::GetUserExtensionsRequest::builder().build();
+//! let response: Vec<get_user_extensions::UserExtension> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetUserExtensionsRequest::parse_response(None, &request.get_uri(), response)`](GetUserExtensionsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get User Extensions](super::get_user_extensions)
+///
+/// [`get-user-extensions`](https://dev.twitch.tv/docs/api/reference#get-user-extensions)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetUserExtensionsRequest {}
+
+/// Return Values for [Get User Extensions](super::get_user_extensions)
+///
+/// [`get-user-extensions`](https://dev.twitch.tv/docs/api/reference#get-user-extensions)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct UserExtension {
+    /// Indicates whether the extension is configured such that it can be activated.
+    pub can_activate: bool,
+    /// ID of the extension.
+    pub id: types::ExtensionId,
+    /// Name of the extension.
+    pub name: String,
+    /// Types for which the extension can be activated.
+    #[serde(rename = "type")]
+    pub type_: Vec<ExtensionType>,
+    /// Version of the extension.
+    pub version: String,
+}
+
+/// Types for which an extension can be activated
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ExtensionType {
+    /// Component
+    Component,
+    /// Mobile
+    Mobile,
+    /// Panel
+    Panel,
+    /// Overlay
+    Overlay,
+}
+
+impl Request for GetUserExtensionsRequest {
+    type Response = Vec<UserExtension>;
+
+    const PATH: &'static str = "users/extensions/list";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadBroadcast];
+}
+
+impl RequestGet for GetUserExtensionsRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetUserExtensionsRequest::builder().build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "id": "wi08ebtatdc7oj83wtl9uxwz807l8b",
+            "version": "1.1.8",
+            "name": "Streamlabs Leaderboard",
+            "can_activate": true,
+            "type": [
+                "panel"
+            ]
+        },
+        {
+            "id": "d4uvtfdr04uq6raoenvj7m86gdk16v",
+            "version": "2.0.2",
+            "name": "Prime Subscription and Loot Reminder",
+            "can_activate": true,
+            "type": [
+                "overlay"
+            ]
+        },
+        {
+            "id": "rh6jq1q334hqc2rr1qlzqbvwlfl3x0",
+            "version": "1.1.0",
+            "name": "TopClip",
+            "can_activate": true,
+            "type": [
+                "mobile",
+                "panel"
+            ]
+        },
+        {
+            "id": "zfh2irvx2jb4s60f02jq0ajm8vwgka",
+            "version": "1.0.19",
+            "name": "Streamlabs",
+            "can_activate": true,
+            "type": [
+                "mobile",
+                "overlay"
+            ]
+        },
+        {
+            "id": "lqnf3zxk0rv0g7gq92mtmnirjz2cjj",
+            "version": "0.0.1",
+            "name": "Dev Experience Test",
+            "can_activate": true,
+            "type": [
+                "component",
+                "mobile",
+                "panel",
+                "overlay"
+            ]
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/users/extensions/list?"
+    );
+
+    dbg!(GetUserExtensionsRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
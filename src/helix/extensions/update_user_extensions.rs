@@ -0,0 +1,236 @@
+//! Updates the activation state, extension ID, and/or version number of installed extensions for a specified user, identified by a Bearer token.
+//! [`update-user-extensions`](https://dev.twitch.tv/docs/api/reference#update-user-extensions)
+//!
+//! If you try to activate a given extension under multiple extension types, the last write wins (and there is no guarantee of write order).
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateUserExtensionsRequest]
+//!
+//! To use this endpoint, construct a [`UpdateUserExtensionsRequest`] with the [`UpdateUserExtensionsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::extensions::update_user_extensions;
+//! let request = update_user_extensions::UpdateUserExtensionsRequest::builder().build();
+//! ```
+//!
+//! ## Body: [UpdateUserExtensionsBody]
+//!
+//! We also need to provide a body to the request containing the slots we want to change.
+//!
+//! ```
+//! # use twitch_api2::helix::extensions::{update_user_extensions, ActiveExtension, ExtensionSlot};
+//! let mut body = update_user_extensions::UpdateUserExtensionsBody::builder().build();
+//! body.panel.insert(
+//!     ExtensionSlot::Slot1,
+//!     ActiveExtension::builder()
+//!         .active(true)
+//!         .id("rh6jq1q334hqc2rr1qlzqbvwlfl3x0".to_string())
+//!         .version("1.1.0".to_string())
+//!         .build(),
+//! );
+//! ```
+//!
+//! ## Response: [UpdateUserExtensions]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, extensions::{update_user_extensions, ActiveExtension, ExtensionSlot}};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = update_user_extensions::UpdateUserExtensionsRequest::builder().build();
+//! let mut body = update_user_extensions::UpdateUserExtensionsBody::builder().build();
+//! body.panel.insert(ExtensionSlot::Slot3, ActiveExtension::builder().active(false).build());
+//! let response: update_user_extensions::UpdateUserExtensions = client.req_put(request, body, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`UpdateUserExtensionsRequest::parse_response(&request.get_uri(), response)`](UpdateUserExtensionsRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Update User Extensions](super::update_user_extensions)
+///
+/// [`update-user-extensions`](https://dev.twitch.tv/docs/api/reference#update-user-extensions)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateUserExtensionsRequest {}
+
+/// Body Parameters for [Update User Extensions](super::update_user_extensions)
+///
+/// [`update-user-extensions`](https://dev.twitch.tv/docs/api/reference#update-user-extensions)
+///
+/// # Notes
+///
+/// Only the slots included are changed, leave a map empty to not touch any slot of that type.
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateUserExtensionsBody {
+    /// Extensions to set in panel slots.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub panel: HashMap<ExtensionSlot, ActiveExtension>,
+    /// Extensions to set in overlay slots.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub overlay: HashMap<ExtensionSlot, ActiveExtension>,
+    /// Extensions to set in component slots.
+    #[builder(default)]
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub component: HashMap<ExtensionSlot, ActiveComponentExtension>,
+}
+
+impl helix::HelixRequestBody for UpdateUserExtensionsBody {
+    fn try_to_body(&self) -> Result<Vec<u8>, helix::BodyError> {
+        #[derive(Serialize)]
+        struct IUpdateUserExtensionsBody<'a> {
+            data: &'a UpdateUserExtensionsBody,
+        }
+
+        serde_json::to_vec(&IUpdateUserExtensionsBody { data: self }).map_err(Into::into)
+    }
+}
+
+/// Return Values for [Update User Extensions](super::update_user_extensions)
+///
+/// [`update-user-extensions`](https://dev.twitch.tv/docs/api/reference#update-user-extensions)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum UpdateUserExtensions {
+    /// 200 - Extensions updated successfully
+    Success,
+    /// 400 - Request was invalid, for example an extension was activated in a slot it does not support.
+    BadRequest,
+    /// 401 - Authorization failed.
+    AuthFailed,
+}
+
+impl std::convert::TryFrom<http::StatusCode> for UpdateUserExtensions {
+    type Error = std::borrow::Cow<'static, str>;
+
+    fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+        match s {
+            http::StatusCode::OK | http::StatusCode::NO_CONTENT => {
+                Ok(UpdateUserExtensions::Success)
+            }
+            http::StatusCode::BAD_REQUEST => Ok(UpdateUserExtensions::BadRequest),
+            http::StatusCode::UNAUTHORIZED => Ok(UpdateUserExtensions::AuthFailed),
+            other => Err(other.canonical_reason().unwrap_or("").into()),
+        }
+    }
+}
+
+impl Request for UpdateUserExtensionsRequest {
+    type Response = UpdateUserExtensions;
+
+    const PATH: &'static str = "users/extensions";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserEditBroadcast];
+}
+
+impl RequestPut for UpdateUserExtensionsRequest {
+    type Body = UpdateUserExtensionsBody;
+
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateUserExtensionsRequest::builder().build();
+
+    let mut body = UpdateUserExtensionsBody::builder().build();
+    body.panel.insert(
+        ExtensionSlot::Slot1,
+        ActiveExtension::builder()
+            .active(true)
+            .id("rh6jq1q334hqc2rr1qlzqbvwlfl3x0".to_string())
+            .version("1.1.0".to_string())
+            .build(),
+    );
+    body.component.insert(
+        ExtensionSlot::Slot2,
+        ActiveComponentExtension::builder()
+            .active(true)
+            .id("lqnf3zxk0rv0g7gq92mtmnirjz2cjj".to_string())
+            .version("0.0.1".to_string())
+            .x(0)
+            .y(0)
+            .build(),
+    );
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"data":{"panel":{"1":{"active":true,"id":"rh6jq1q334hqc2rr1qlzqbvwlfl3x0","version":"1.1.0"}},"component":{"2":{"active":true,"id":"lqnf3zxk0rv0g7gq92mtmnirjz2cjj","version":"0.0.1","x":0,"y":0}}}}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": {
+        "panel": {
+            "1": {
+                "active": true,
+                "id": "rh6jq1q334hqc2rr1qlzqbvwlfl3x0",
+                "version": "1.1.0",
+                "name": "TopClip"
+            },
+            "2": {
+                "active": false
+            },
+            "3": {
+                "active": false
+            }
+        },
+        "overlay": {
+            "1": {
+                "active": false
+            }
+        },
+        "component": {
+            "1": {
+                "active": false
+            },
+            "2": {
+                "active": true,
+                "id": "lqnf3zxk0rv0g7gq92mtmnirjz2cjj",
+                "version": "0.0.1",
+                "name": "Dev Experience Test",
+                "x": 0,
+                "y": 0
+            }
+        }
+    }
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(200).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/users/extensions?"
+    );
+
+    dbg!(UpdateUserExtensionsRequest::parse_response(&uri, http_response).unwrap());
+}
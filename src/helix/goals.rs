@@ -0,0 +1,47 @@
+//! Helix endpoints regarding creator goals
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, goals::GetCreatorGoalsRequest};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = GetCreatorGoalsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//!
+//! println!("{:?}", &client.req_get(req, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod get_creator_goals;
+
+#[doc(inline)]
+pub use get_creator_goals::{CreatorGoal, GetCreatorGoalsRequest};
+
+/// Type of a [creator goal](CreatorGoal)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CreatorGoalType {
+    /// The goal is to increase followers.
+    Follower,
+    /// The goal is to increase subscriptions. This type shows the net increase or decrease in tier points associated with the subscriptions.
+    Subscription,
+    /// The goal is to increase subscriptions. This type shows the net increase or decrease in the number of subscriptions.
+    SubscriptionCount,
+    /// The goal is to increase subscriptions. This type shows only the net increase in tier points associated with the subscriptions.
+    NewSubscription,
+    /// The goal is to increase subscriptions. This type shows only the net increase in the number of subscriptions.
+    NewSubscriptionCount,
+}
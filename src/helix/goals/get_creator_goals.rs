@@ -0,0 +1,144 @@
+//! Gets the broadcaster’s list of active goals.
+//! [`get-creator-goals`](https://dev.twitch.tv/docs/api/reference#get-creator-goals)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetCreatorGoalsRequest]
+//!
+//! To use this endpoint, construct a [`GetCreatorGoalsRequest`] with the [`GetCreatorGoalsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::goals::get_creator_goals;
+//! let request = get_creator_goals::GetCreatorGoalsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [CreatorGoal]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, goals::get_creator_goals};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_creator_goals::GetCreatorGoalsRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let response: Vec<get_creator_goals::CreatorGoal> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetCreatorGoalsRequest::parse_response(None, &request.get_uri(), response)`](GetCreatorGoalsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Creator Goals](super::get_creator_goals)
+///
+/// [`get-creator-goals`](https://dev.twitch.tv/docs/api/reference#get-creator-goals)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetCreatorGoalsRequest {
+    /// The ID of the broadcaster that created the goals. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+}
+
+/// Return Values for [Get Creator Goals](super::get_creator_goals)
+///
+/// [`get-creator-goals`](https://dev.twitch.tv/docs/api/reference#get-creator-goals)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CreatorGoal {
+    /// An ID that identifies this goal.
+    pub id: String,
+    /// An ID that identifies the broadcaster that created the goal.
+    pub broadcaster_id: types::UserId,
+    /// The broadcaster’s display name.
+    pub broadcaster_name: types::DisplayName,
+    /// The broadcaster’s login name.
+    pub broadcaster_login: types::UserName,
+    /// The type of goal.
+    #[serde(rename = "type")]
+    pub type_: CreatorGoalType,
+    /// A description of the goal. Is an empty string if not specified.
+    pub description: String,
+    /// The goal’s current value. The interpretation depends on [`type_`](CreatorGoal::type_).
+    pub current_amount: i64,
+    /// The goal’s target value.
+    pub target_amount: i64,
+    /// The UTC date and time that the broadcaster created the goal.
+    pub created_at: types::Timestamp,
+}
+
+impl CreatorGoal {
+    /// Progress towards the target, where `1.0` means the goal is reached.
+    pub fn progress(&self) -> f64 {
+        if self.target_amount == 0 {
+            return 0.0;
+        }
+        self.current_amount as f64 / self.target_amount as f64
+    }
+}
+
+impl Request for GetCreatorGoalsRequest {
+    type Response = Vec<CreatorGoal>;
+
+    const PATH: &'static str = "goals";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:read:goals"),
+    )];
+}
+
+impl RequestGet for GetCreatorGoalsRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetCreatorGoalsRequest::builder()
+        .broadcaster_id("141981764")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+          {
+            "id": "1woowvbkiNv8BRxEWSqmQz6Zk92",
+            "broadcaster_id": "141981764",
+            "broadcaster_name": "TwitchDev",
+            "broadcaster_login": "twitchdev",
+            "type": "follower",
+            "description": "Follow goal for Helix testing",
+            "current_amount": 27062,
+            "target_amount": 30000,
+            "created_at": "2021-08-16T17:22:23Z"
+          }
+        ]
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/goals?broadcaster_id=141981764"
+    );
+
+    let res = GetCreatorGoalsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res[0].type_, CreatorGoalType::Follower);
+    assert!((res[0].progress() - 0.902_066_666).abs() < 1e-6);
+}
@@ -0,0 +1,168 @@
+//! Helix endpoints regarding Guest Star
+//!
+//! Guest Star is in open beta on Twitch and may change at any time. To enable these endpoints, use feature
+//! <span
+//!   class="module-item stab portability"
+//!   style="display: inline; border-radius: 3px; padding: 2px; font-size: 80%; line-height: 1.2;"
+//! ><code>unsupported</code></span>.
+//! Note that this crate doesn't try to keep changes to these endpoints semver compatible.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{self, HelixClient, guest_star::{CreateGuestStarSessionRequest, SendGuestStarInviteRequest}};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = CreateGuestStarSessionRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .build();
+//! let session = client.req_post(req, helix::EmptyBody, &token).await?.data;
+//!
+//! let req = SendGuestStarInviteRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id(session.id)
+//!     .guest_id("144601104")
+//!     .build();
+//! println!("{:?}", &client.req_post(req, helix::EmptyBody, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod assign_guest_star_slot;
+pub mod create_guest_star_session;
+pub mod delete_guest_star_invite;
+pub mod delete_guest_star_slot;
+pub mod end_guest_star_session;
+pub mod get_channel_guest_star_settings;
+pub mod get_guest_star_invites;
+pub mod get_guest_star_session;
+pub mod send_guest_star_invite;
+pub mod update_channel_guest_star_settings;
+pub mod update_guest_star_slot;
+pub mod update_guest_star_slot_settings;
+
+#[doc(inline)]
+pub use assign_guest_star_slot::{AssignGuestStarSlot, AssignGuestStarSlotRequest};
+#[doc(inline)]
+pub use create_guest_star_session::CreateGuestStarSessionRequest;
+#[doc(inline)]
+pub use delete_guest_star_invite::{DeleteGuestStarInvite, DeleteGuestStarInviteRequest};
+#[doc(inline)]
+pub use delete_guest_star_slot::{DeleteGuestStarSlot, DeleteGuestStarSlotRequest};
+#[doc(inline)]
+pub use end_guest_star_session::{EndGuestStarSession, EndGuestStarSessionRequest};
+#[doc(inline)]
+pub use get_channel_guest_star_settings::{
+    GetChannelGuestStarSettingsRequest, GuestStarSettings,
+};
+#[doc(inline)]
+pub use get_guest_star_invites::{GetGuestStarInvitesRequest, GuestStarInvite};
+#[doc(inline)]
+pub use get_guest_star_session::GetGuestStarSessionRequest;
+#[doc(inline)]
+pub use send_guest_star_invite::{SendGuestStarInvite, SendGuestStarInviteRequest};
+#[doc(inline)]
+pub use update_channel_guest_star_settings::{
+    UpdateChannelGuestStarSettings, UpdateChannelGuestStarSettingsBody,
+    UpdateChannelGuestStarSettingsRequest,
+};
+#[doc(inline)]
+pub use update_guest_star_slot::{UpdateGuestStarSlot, UpdateGuestStarSlotRequest};
+#[doc(inline)]
+pub use update_guest_star_slot_settings::{
+    UpdateGuestStarSlotSettings, UpdateGuestStarSlotSettingsRequest,
+};
+
+/// How the guests are laid out in a Guest Star browser source
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum GroupLayout {
+    /// All live guests are tiled within the browser source with the same size.
+    TiledLayout,
+    /// All live guests are tiled within the browser source with the same size. If there is an active screen share, it is sized larger than the other guests.
+    ScreenshareLayout,
+    /// All live guests are arranged in a horizontal bar within the browser source.
+    HorizontalLayout,
+    /// All live guests are arranged in a vertical bar within the browser source.
+    VerticalLayout,
+}
+
+/// A Guest Star session
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct GuestStarSession {
+    /// ID uniquely representing the Guest Star session.
+    pub id: String,
+    /// List of guests currently interacting with the Guest Star session.
+    pub guests: Vec<Guest>,
+}
+
+/// A guest in a [Guest Star session](GuestStarSession)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Guest {
+    /// ID representing this guest’s slot assignment. Host is always in slot `"0"`.
+    pub slot_id: String,
+    /// Flag determining whether or not the guest is visible in the browser source in the host’s streaming software.
+    pub is_live: bool,
+    /// User ID of the guest assigned to this slot.
+    pub user_id: types::UserId,
+    /// Display name of the guest assigned to this slot.
+    pub user_display_name: types::DisplayName,
+    /// Login of the guest assigned to this slot.
+    pub user_login: types::UserName,
+    /// Value from 0 to 100 representing the host’s volume setting for this guest.
+    pub volume: u32,
+    /// Timestamp when this guest was assigned a slot in the session.
+    pub assigned_at: types::Timestamp,
+    /// Information about the guest’s audio settings
+    pub audio_settings: MediaSettings,
+    /// Information about the guest’s video settings
+    pub video_settings: MediaSettings,
+}
+
+/// Audio or video settings of a [guest](Guest)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct MediaSettings {
+    /// Flag determining whether the host is allowing the guest’s audio or video to be seen or heard within the session.
+    pub is_host_enabled: bool,
+    /// Flag determining whether the guest is allowing their audio or video to be transmitted to the session.
+    pub is_guest_enabled: bool,
+    /// Flag determining whether the guest has an appropriate audio or video device available to be transmitted to the session.
+    pub is_available: bool,
+}
+
+impl MediaSettings {
+    /// The media is enabled by both the host and the guest, and a device is available.
+    pub fn is_active(&self) -> bool {
+        self.is_host_enabled && self.is_guest_enabled && self.is_available
+    }
+}
+
+/// Status of a [Guest Star invite](GuestStarInvite)
+#[derive(PartialEq, Eq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum InviteStatus {
+    /// The user has been invited to the session but has not acknowledged it.
+    Invited,
+    /// The invited user has acknowledged the invite and joined the waiting room, but may still be setting up their media devices or otherwise preparing to join the call.
+    Accepted,
+    /// The invited user has signaled they are ready to join the call from the waiting room.
+    Ready,
+}
@@ -0,0 +1,172 @@
+//! Allows a previously invited user to be assigned a slot within the active Guest Star session, once that guest has indicated they are ready to join.
+//! [`assign-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#assign-guest-star-slot)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [AssignGuestStarSlotRequest]
+//!
+//! To use this endpoint, construct a [`AssignGuestStarSlotRequest`] with the [`AssignGuestStarSlotRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::assign_guest_star_slot;
+//! let request = assign_guest_star_slot::AssignGuestStarSlotRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .guest_id("144601104")
+//!     .slot_id("1")
+//!     .build();
+//! ```
+//!
+//! ## Response: [AssignGuestStarSlot]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::assign_guest_star_slot};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = assign_guest_star_slot::AssignGuestStarSlotRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .guest_id("144601104")
+//!     .slot_id("1")
+//!     .build();
+//! let response: assign_guest_star_slot::AssignGuestStarSlot =
+//!     client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`AssignGuestStarSlotRequest::parse_response(None, &request.get_uri(), response)`](AssignGuestStarSlotRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Assign Guest Star Slot](super::assign_guest_star_slot)
+///
+/// [`assign-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#assign-guest-star-slot)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct AssignGuestStarSlotRequest {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+    /// The ID of the Guest Star session in which to assign the slot.
+    #[builder(setter(into))]
+    pub session_id: String,
+    /// The Twitch User ID corresponding to the guest to assign a slot in the session. This user must already have an invite to this session, and have indicated that they are ready to join.
+    #[builder(setter(into))]
+    pub guest_id: types::UserId,
+    /// The slot assignment to give to the user. Must be a numeric identifier between “1” and “N” where N is the max number of slots for the session.
+    #[builder(setter(into))]
+    pub slot_id: String,
+}
+
+/// Return Values for [Assign Guest Star Slot](super::assign_guest_star_slot)
+///
+/// [`assign-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#assign-guest-star-slot)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum AssignGuestStarSlot {
+    /// 204 - Successfully assigned the guest to the slot.
+    Success,
+    /// 400 - The guest is not ready, has no invite, or the slot is invalid or occupied.
+    BadRequest(String),
+    /// 401 - Authorization failed.
+    AuthFailed(String),
+    /// 403 - The user in `moderator_id` is not permitted to assign slots.
+    Forbidden(String),
+}
+
+impl Request for AssignGuestStarSlotRequest {
+    type Response = AssignGuestStarSlot;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:guest_star"),
+    )];
+    const PATH: &'static str = "guest_star/slot";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:guest_star"),
+    )];
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status {
+            http::StatusCode::BAD_REQUEST => Some(AssignGuestStarSlot::BadRequest(message)),
+            http::StatusCode::UNAUTHORIZED => Some(AssignGuestStarSlot::AuthFailed(message)),
+            http::StatusCode::FORBIDDEN => Some(AssignGuestStarSlot::Forbidden(message)),
+            _ => None,
+        }
+    }
+}
+
+impl RequestPost for AssignGuestStarSlotRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let data = match status {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => AssignGuestStarSlot::Success,
+            _ => {
+                return Err(helix::HelixRequestPostError::InvalidResponse {
+                    reason: "unexpected status code",
+                    response: response.to_string(),
+                    status,
+                    uri: uri.clone(),
+                })
+            }
+        };
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = AssignGuestStarSlotRequest::builder()
+        .broadcaster_id("9321049")
+        .moderator_id("9321049")
+        .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+        .guest_id("144601104")
+        .slot_id("1")
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/slot?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI&guest_id=144601104&slot_id=1"
+    );
+
+    let res = AssignGuestStarSlotRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data, AssignGuestStarSlot::Success);
+}
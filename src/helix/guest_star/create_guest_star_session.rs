@@ -0,0 +1,163 @@
+//! Programmatically creates a Guest Star session on behalf of the broadcaster.
+//! [`create-guest-star-session`](https://dev.twitch.tv/docs/api/reference#create-guest-star-session)
+//!
+//! # Notes
+//!
+//! Requires the broadcaster to be present in the call interface, or the call will be ended automatically.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [CreateGuestStarSessionRequest]
+//!
+//! To use this endpoint, construct a [`CreateGuestStarSessionRequest`] with the [`CreateGuestStarSessionRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::create_guest_star_session;
+//! let request = create_guest_star_session::CreateGuestStarSessionRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .build();
+//! ```
+//!
+//! ## Response: [GuestStarSession]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::{self, create_guest_star_session}};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = create_guest_star_session::CreateGuestStarSessionRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .build();
+//! let response: guest_star::GuestStarSession =
+//!     client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`CreateGuestStarSessionRequest::parse_response(None, &request.get_uri(), response)`](CreateGuestStarSessionRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Create Guest Star Session](super::create_guest_star_session)
+///
+/// [`create-guest-star-session`](https://dev.twitch.tv/docs/api/reference#create-guest-star-session)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct CreateGuestStarSessionRequest {
+    /// The ID of the broadcaster you want to create a Guest Star session for. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+}
+
+impl Request for CreateGuestStarSessionRequest {
+    type Response = GuestStarSession;
+
+    const PATH: &'static str = "guest_star/session";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:guest_star"),
+    )];
+}
+
+impl RequestPost for CreateGuestStarSessionRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<Self::Response>> = helix::parse_json(&response_str)
+            .map_err(|e| {
+                helix::HelixRequestPostError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            helix::HelixRequestPostError::InvalidResponse {
+                reason: "response included no data",
+                response: response_str.to_string(),
+                status,
+                uri: uri.clone(),
+            }
+        })?;
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = CreateGuestStarSessionRequest::builder()
+        .broadcaster_id("9321049")
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+                "guests": [
+                    {
+                        "slot_id": "0",
+                        "is_live": true,
+                        "user_id": "9321049",
+                        "user_display_name": "Cool_User",
+                        "user_login": "cool_user",
+                        "volume": 100,
+                        "assigned_at": "2023-01-02T04:16:53.325Z",
+                        "audio_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        },
+                        "video_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        }
+                    }
+                ]
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/session?broadcaster_id=9321049"
+    );
+
+    let res = CreateGuestStarSessionRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res.id, "2KFRQbFtpmfyD3IevNRnCzOPRJI");
+    assert!(res.guests[0].video_settings.is_active());
+}
@@ -0,0 +1,147 @@
+//! Revokes a previously sent invite for a Guest Star session.
+//! [`delete-guest-star-invite`](https://dev.twitch.tv/docs/api/reference#delete-guest-star-invite)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [DeleteGuestStarInviteRequest]
+//!
+//! To use this endpoint, construct a [`DeleteGuestStarInviteRequest`] with the [`DeleteGuestStarInviteRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::delete_guest_star_invite;
+//! let request = delete_guest_star_invite::DeleteGuestStarInviteRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .guest_id("144601104")
+//!     .build();
+//! ```
+//!
+//! ## Response: [DeleteGuestStarInvite]
+//!
+//! Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::delete_guest_star_invite};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = delete_guest_star_invite::DeleteGuestStarInviteRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .guest_id("144601104")
+//!     .build();
+//! let response: delete_guest_star_invite::DeleteGuestStarInvite =
+//!     client.req_delete(request, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+//! and parse the [`http::Response`] with [`DeleteGuestStarInviteRequest::parse_response(&request.get_uri(), response)`](DeleteGuestStarInviteRequest::parse_response)
+
+use super::*;
+use helix::RequestDelete;
+
+/// Query Parameters for [Delete Guest Star Invite](super::delete_guest_star_invite)
+///
+/// [`delete-guest-star-invite`](https://dev.twitch.tv/docs/api/reference#delete-guest-star-invite)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct DeleteGuestStarInviteRequest {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+    /// The session ID for the invite to be revoked on behalf of the broadcaster.
+    #[builder(setter(into))]
+    pub session_id: String,
+    /// Twitch User ID for the guest to revoke the Guest Star session invite from.
+    #[builder(setter(into))]
+    pub guest_id: types::UserId,
+}
+
+/// Return Values for [Delete Guest Star Invite](super::delete_guest_star_invite)
+///
+/// [`delete-guest-star-invite`](https://dev.twitch.tv/docs/api/reference#delete-guest-star-invite)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum DeleteGuestStarInvite {
+    /// 204 - Successfully revoked the invite.
+    Success,
+}
+
+impl std::convert::TryFrom<http::StatusCode> for DeleteGuestStarInvite {
+    type Error = std::borrow::Cow<'static, str>;
+
+    fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+        match s {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => {
+                Ok(DeleteGuestStarInvite::Success)
+            }
+            other => Err(other.canonical_reason().unwrap_or("").into()),
+        }
+    }
+}
+
+impl Request for DeleteGuestStarInviteRequest {
+    type Response = DeleteGuestStarInvite;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:guest_star"),
+    )];
+    const PATH: &'static str = "guest_star/invites";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:guest_star"),
+    )];
+}
+
+impl RequestDelete for DeleteGuestStarInviteRequest {
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestDeleteError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = DeleteGuestStarInviteRequest::builder()
+        .broadcaster_id("9321049")
+        .moderator_id("9321049")
+        .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+        .guest_id("144601104")
+        .build();
+
+    dbg!(req.create_request("token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/invites?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI&guest_id=144601104"
+    );
+
+    assert_eq!(
+        DeleteGuestStarInviteRequest::parse_response(&uri, http_response).unwrap(),
+        DeleteGuestStarInvite::Success
+    );
+}
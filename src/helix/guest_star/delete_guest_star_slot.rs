@@ -0,0 +1,159 @@
+//! Allows a caller to remove a slot assignment from a user participating in an active Guest Star session.
+//! [`delete-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#delete-guest-star-slot)
+//!
+//! # Notes
+//!
+//! This revokes their access to the session immediately and disables their access to publish or subscribe to media within the session.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [DeleteGuestStarSlotRequest]
+//!
+//! To use this endpoint, construct a [`DeleteGuestStarSlotRequest`] with the [`DeleteGuestStarSlotRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::delete_guest_star_slot;
+//! let request = delete_guest_star_slot::DeleteGuestStarSlotRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .guest_id("144601104")
+//!     .slot_id("1")
+//!     .build();
+//! ```
+//!
+//! ## Response: [DeleteGuestStarSlot]
+//!
+//! Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::delete_guest_star_slot};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = delete_guest_star_slot::DeleteGuestStarSlotRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .guest_id("144601104")
+//!     .slot_id("1")
+//!     .build();
+//! let response: delete_guest_star_slot::DeleteGuestStarSlot =
+//!     client.req_delete(request, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+//! and parse the [`http::Response`] with [`DeleteGuestStarSlotRequest::parse_response(&request.get_uri(), response)`](DeleteGuestStarSlotRequest::parse_response)
+
+use super::*;
+use helix::RequestDelete;
+
+/// Query Parameters for [Delete Guest Star Slot](super::delete_guest_star_slot)
+///
+/// [`delete-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#delete-guest-star-slot)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct DeleteGuestStarSlotRequest {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+    /// The ID of the Guest Star session in which to remove the slot assignment.
+    #[builder(setter(into))]
+    pub session_id: String,
+    /// The Twitch User ID corresponding to the guest to remove from the session.
+    #[builder(setter(into))]
+    pub guest_id: types::UserId,
+    /// The slot ID representing the slot assignment to remove from the session.
+    #[builder(setter(into))]
+    pub slot_id: String,
+    /// Flag signaling that the guest should be reinvited to the session, sending them back to the invite queue.
+    #[builder(default, setter(into))]
+    pub should_reinvite_guest: Option<bool>,
+}
+
+/// Return Values for [Delete Guest Star Slot](super::delete_guest_star_slot)
+///
+/// [`delete-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#delete-guest-star-slot)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum DeleteGuestStarSlot {
+    /// 204 - Successfully removed the user from the slot.
+    Success,
+}
+
+impl std::convert::TryFrom<http::StatusCode> for DeleteGuestStarSlot {
+    type Error = std::borrow::Cow<'static, str>;
+
+    fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+        match s {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => Ok(DeleteGuestStarSlot::Success),
+            other => Err(other.canonical_reason().unwrap_or("").into()),
+        }
+    }
+}
+
+impl Request for DeleteGuestStarSlotRequest {
+    type Response = DeleteGuestStarSlot;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:guest_star"),
+    )];
+    const PATH: &'static str = "guest_star/slot";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:guest_star"),
+    )];
+}
+
+impl RequestDelete for DeleteGuestStarSlotRequest {
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestDeleteError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = DeleteGuestStarSlotRequest::builder()
+        .broadcaster_id("9321049")
+        .moderator_id("9321049")
+        .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+        .guest_id("144601104")
+        .slot_id("1")
+        .should_reinvite_guest(true)
+        .build();
+
+    dbg!(req.create_request("token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/slot?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI&guest_id=144601104&slot_id=1&should_reinvite_guest=true"
+    );
+
+    assert_eq!(
+        DeleteGuestStarSlotRequest::parse_response(&uri, http_response).unwrap(),
+        DeleteGuestStarSlot::Success
+    );
+}
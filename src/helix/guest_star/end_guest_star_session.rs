@@ -0,0 +1,170 @@
+//! Programmatically ends a Guest Star session on behalf of the broadcaster.
+//! [`end-guest-star-session`](https://dev.twitch.tv/docs/api/reference#end-guest-star-session)
+//!
+//! # Notes
+//!
+//! Performs the same action as if the host clicked the “End Call” button in the Guest Star UI.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [EndGuestStarSessionRequest]
+//!
+//! To use this endpoint, construct a [`EndGuestStarSessionRequest`] with the [`EndGuestStarSessionRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::end_guest_star_session;
+//! let request = end_guest_star_session::EndGuestStarSessionRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .build();
+//! ```
+//!
+//! ## Response: [EndGuestStarSession]
+//!
+//! Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::end_guest_star_session};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = end_guest_star_session::EndGuestStarSessionRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .build();
+//! let response: end_guest_star_session::EndGuestStarSession =
+//!     client.req_delete(request, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+//! and parse the [`http::Response`] with [`EndGuestStarSessionRequest::parse_response(&request.get_uri(), response)`](EndGuestStarSessionRequest::parse_response)
+
+use super::*;
+use helix::RequestDelete;
+
+/// Query Parameters for [End Guest Star Session](super::end_guest_star_session)
+///
+/// [`end-guest-star-session`](https://dev.twitch.tv/docs/api/reference#end-guest-star-session)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct EndGuestStarSessionRequest {
+    /// The ID of the broadcaster you want to end a Guest Star session for. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// ID for the session to end on behalf of the broadcaster.
+    #[builder(setter(into))]
+    pub session_id: String,
+}
+
+/// Return Values for [End Guest Star Session](super::end_guest_star_session)
+///
+/// [`end-guest-star-session`](https://dev.twitch.tv/docs/api/reference#end-guest-star-session)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum EndGuestStarSession {
+    /// 200 - Successfully ended the session, returns the session as it was when it ended.
+    Success(GuestStarSession),
+}
+
+impl Request for EndGuestStarSessionRequest {
+    type Response = EndGuestStarSession;
+
+    const PATH: &'static str = "guest_star/session";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:guest_star"),
+    )];
+}
+
+impl RequestDelete for EndGuestStarSessionRequest {
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestDeleteError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<GuestStarSession>> = helix::parse_json(response)
+            .map_err(|e| {
+                helix::HelixRequestDeleteError::DeserializeError(
+                    response.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        response
+            .data
+            .into_iter()
+            .next()
+            .map(EndGuestStarSession::Success)
+            .ok_or_else(|| helix::HelixRequestDeleteError::Error {
+                error: String::new(),
+                status,
+                message: "response included no data".to_string(),
+                uri: uri.clone(),
+            })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = EndGuestStarSessionRequest::builder()
+        .broadcaster_id("9321049")
+        .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+        .build();
+
+    dbg!(req.create_request("token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+                "guests": [
+                    {
+                        "slot_id": "0",
+                        "is_live": true,
+                        "user_id": "9321049",
+                        "user_display_name": "Cool_User",
+                        "user_login": "cool_user",
+                        "volume": 100,
+                        "assigned_at": "2023-01-02T04:16:53.325Z",
+                        "audio_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        },
+                        "video_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        }
+                    }
+                ]
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(200).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/session?broadcaster_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI"
+    );
+
+    let EndGuestStarSession::Success(session) =
+        EndGuestStarSessionRequest::parse_response(&uri, http_response).unwrap();
+    assert_eq!(session.id, "2KFRQbFtpmfyD3IevNRnCzOPRJI");
+}
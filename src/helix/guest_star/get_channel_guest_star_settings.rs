@@ -0,0 +1,164 @@
+//! Gets the channel settings for configuration of the Guest Star feature for a particular host.
+//! [`get-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#get-channel-guest-star-settings)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetChannelGuestStarSettingsRequest]
+//!
+//! To use this endpoint, construct a [`GetChannelGuestStarSettingsRequest`] with the [`GetChannelGuestStarSettingsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::get_channel_guest_star_settings;
+//! let request =
+//!     get_channel_guest_star_settings::GetChannelGuestStarSettingsRequest::builder()
+//!         .broadcaster_id("932104")
+//!         .moderator_id("9321049")
+//!         .build();
+//! ```
+//!
+//! ## Response: [GuestStarSettings]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::get_channel_guest_star_settings};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request =
+//!     get_channel_guest_star_settings::GetChannelGuestStarSettingsRequest::builder()
+//!         .broadcaster_id("932104")
+//!         .moderator_id("9321049")
+//!         .build();
+//! let response: Option<get_channel_guest_star_settings::GuestStarSettings> =
+//!     client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetChannelGuestStarSettingsRequest::parse_response(None, &request.get_uri(), response)`](GetChannelGuestStarSettingsRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Channel Guest Star Settings](super::get_channel_guest_star_settings)
+///
+/// [`get-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#get-channel-guest-star-settings)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetChannelGuestStarSettingsRequest {
+    /// The ID of the broadcaster you want to get guest star settings for.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+}
+
+/// Return Values for [Get Channel Guest Star Settings](super::get_channel_guest_star_settings)
+///
+/// [`get-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#get-channel-guest-star-settings)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct GuestStarSettings {
+    /// Flag determining if Guest Star moderators have access to control whether a guest is live once assigned to a slot.
+    pub is_moderator_send_live_enabled: bool,
+    /// Number of slots the Guest Star call interface will allow the host to add to a call.
+    pub slot_count: u32,
+    /// Flag determining if Browser Sources subscribed to sessions on this channel should output audio.
+    pub is_browser_source_audio_enabled: bool,
+    /// How the guests within a session should be laid out within the browser source.
+    pub group_layout: GroupLayout,
+    /// View only token to generate browser source URLs.
+    pub browser_source_token: String,
+}
+
+impl Request for GetChannelGuestStarSettingsRequest {
+    type Response = Option<GuestStarSettings>;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("channel:manage:guest_star")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:guest_star")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:manage:guest_star")),
+    ];
+    const PATH: &'static str = "guest_star/channel_settings";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:read:guest_star"),
+    )];
+}
+
+impl RequestGet for GetChannelGuestStarSettingsRequest {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<GuestStarSettings>> = helix::parse_json(response)
+            .map_err(|e| {
+                helix::HelixRequestGetError::DeserializeError(
+                    response.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        Ok(helix::Response {
+            data: response.data.into_iter().next(),
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetChannelGuestStarSettingsRequest::builder()
+        .broadcaster_id("932104")
+        .moderator_id("9321049")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "is_moderator_send_live_enabled": true,
+                "slot_count": 4,
+                "is_browser_source_audio_enabled": true,
+                "group_layout": "TILED_LAYOUT",
+                "browser_source_token": "eihq8rew7q3hgierufhi3q"
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/channel_settings?broadcaster_id=932104&moderator_id=9321049"
+    );
+
+    let res = GetChannelGuestStarSettingsRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(res.slot_count, 4);
+    assert_eq!(res.group_layout, GroupLayout::TiledLayout);
+}
@@ -0,0 +1,145 @@
+//! Provides the caller with a list of pending invites to a Guest Star session, including the invitee’s ready status while joining the waiting room.
+//! [`get-guest-star-invites`](https://dev.twitch.tv/docs/api/reference#get-guest-star-invites)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetGuestStarInvitesRequest]
+//!
+//! To use this endpoint, construct a [`GetGuestStarInvitesRequest`] with the [`GetGuestStarInvitesRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::get_guest_star_invites;
+//! let request = get_guest_star_invites::GetGuestStarInvitesRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .build();
+//! ```
+//!
+//! ## Response: [GuestStarInvite]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::get_guest_star_invites};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_guest_star_invites::GetGuestStarInvitesRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .build();
+//! let response: Vec<get_guest_star_invites::GuestStarInvite> =
+//!     client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetGuestStarInvitesRequest::parse_response(None, &request.get_uri(), response)`](GetGuestStarInvitesRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Guest Star Invites](super::get_guest_star_invites)
+///
+/// [`get-guest-star-invites`](https://dev.twitch.tv/docs/api/reference#get-guest-star-invites)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetGuestStarInvitesRequest {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+    /// The session ID to query for invite status.
+    #[builder(setter(into))]
+    pub session_id: String,
+}
+
+/// Return Values for [Get Guest Star Invites](super::get_guest_star_invites)
+///
+/// [`get-guest-star-invites`](https://dev.twitch.tv/docs/api/reference#get-guest-star-invites)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct GuestStarInvite {
+    /// Twitch User ID corresponding to the invited guest.
+    pub user_id: types::UserId,
+    /// Timestamp when this user was invited to the session.
+    pub invited_at: types::Timestamp,
+    /// Status representing the invited user’s join state.
+    pub status: InviteStatus,
+    /// Flag signaling that the invited user has chosen to disable their local video device. The user has hidden themselves, but they may choose to reveal their video feed upon joining the session.
+    pub is_video_enabled: bool,
+    /// Flag signaling that the invited user has chosen to disable their local audio device. The user has muted themselves, but they may choose to unmute their audio feed upon joining the session.
+    pub is_audio_enabled: bool,
+    /// Flag signaling that the invited user has a video device available for sharing.
+    pub is_video_available: bool,
+    /// Flag signaling that the invited user has an audio device available for sharing.
+    pub is_audio_available: bool,
+}
+
+impl Request for GetGuestStarInvitesRequest {
+    type Response = Vec<GuestStarInvite>;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("channel:manage:guest_star")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:guest_star")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:manage:guest_star")),
+    ];
+    const PATH: &'static str = "guest_star/invites";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:read:guest_star"),
+    )];
+}
+
+impl RequestGet for GetGuestStarInvitesRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetGuestStarInvitesRequest::builder()
+        .broadcaster_id("9321049")
+        .moderator_id("9321049")
+        .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "user_id": "144601104",
+                "invited_at": "2023-01-02T04:16:53.325Z",
+                "status": "INVITED",
+                "is_audio_enabled": false,
+                "is_video_enabled": true,
+                "is_audio_available": true,
+                "is_video_available": true
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/invites?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI"
+    );
+
+    let res = GetGuestStarInvitesRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data;
+    assert_eq!(res[0].status, InviteStatus::Invited);
+}
@@ -0,0 +1,178 @@
+//! Gets information about an ongoing Guest Star session for a particular channel.
+//! [`get-guest-star-session`](https://dev.twitch.tv/docs/api/reference#get-guest-star-session)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetGuestStarSessionRequest]
+//!
+//! To use this endpoint, construct a [`GetGuestStarSessionRequest`] with the [`GetGuestStarSessionRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::get_guest_star_session;
+//! let request = get_guest_star_session::GetGuestStarSessionRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .build();
+//! ```
+//!
+//! ## Response: [GuestStarSession]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::{self, get_guest_star_session}};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_guest_star_session::GetGuestStarSessionRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .build();
+//! let response: Option<guest_star::GuestStarSession> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetGuestStarSessionRequest::parse_response(None, &request.get_uri(), response)`](GetGuestStarSessionRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Guest Star Session](super::get_guest_star_session)
+///
+/// [`get-guest-star-session`](https://dev.twitch.tv/docs/api/reference#get-guest-star-session)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetGuestStarSessionRequest {
+    /// ID for the user hosting the Guest Star session.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+}
+
+impl Request for GetGuestStarSessionRequest {
+    type Response = Option<GuestStarSession>;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("channel:manage:guest_star")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:read:guest_star")),
+        twitch_oauth2::Scope::Other(std::borrow::Cow::Borrowed("moderator:manage:guest_star")),
+    ];
+    const PATH: &'static str = "guest_star/session";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:read:guest_star"),
+    )];
+}
+
+impl RequestGet for GetGuestStarSessionRequest {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<GuestStarSession>> = helix::parse_json(response)
+            .map_err(|e| {
+                helix::HelixRequestGetError::DeserializeError(
+                    response.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        Ok(helix::Response {
+            data: response.data.into_iter().next(),
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetGuestStarSessionRequest::builder()
+        .broadcaster_id("9321049")
+        .moderator_id("9321049")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+            {
+                "id": "2KFRQbFtpmfyD3IevNRnCzOPRJI",
+                "guests": [
+                    {
+                        "slot_id": "0",
+                        "is_live": true,
+                        "user_id": "9321049",
+                        "user_display_name": "Cool_User",
+                        "user_login": "cool_user",
+                        "volume": 100,
+                        "assigned_at": "2023-01-02T04:16:53.325Z",
+                        "audio_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        },
+                        "video_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        }
+                    },
+                    {
+                        "slot_id": "1",
+                        "is_live": true,
+                        "user_id": "144601104",
+                        "user_display_name": "Cool_Guest",
+                        "user_login": "cool_guest",
+                        "volume": 100,
+                        "assigned_at": "2023-01-02T04:20:59.325Z",
+                        "audio_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": true
+                        },
+                        "video_settings": {
+                            "is_available": true,
+                            "is_host_enabled": true,
+                            "is_guest_enabled": false
+                        }
+                    }
+                ]
+            }
+        ]
+    }
+    "#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/session?broadcaster_id=9321049&moderator_id=9321049"
+    );
+
+    let res = GetGuestStarSessionRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data
+        .unwrap();
+    assert_eq!(res.guests.len(), 2);
+    assert!(!res.guests[1].video_settings.is_active());
+}
@@ -0,0 +1,166 @@
+//! Sends an invite to a specified guest on behalf of the broadcaster for a Guest Star session in progress.
+//! [`send-guest-star-invite`](https://dev.twitch.tv/docs/api/reference#send-guest-star-invite)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SendGuestStarInviteRequest]
+//!
+//! To use this endpoint, construct a [`SendGuestStarInviteRequest`] with the [`SendGuestStarInviteRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::send_guest_star_invite;
+//! let request = send_guest_star_invite::SendGuestStarInviteRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .guest_id("144601104")
+//!     .build();
+//! ```
+//!
+//! ## Response: [SendGuestStarInvite]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::send_guest_star_invite};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = send_guest_star_invite::SendGuestStarInviteRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .guest_id("144601104")
+//!     .build();
+//! let response: send_guest_star_invite::SendGuestStarInvite =
+//!     client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`SendGuestStarInviteRequest::parse_response(None, &request.get_uri(), response)`](SendGuestStarInviteRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Send Guest Star Invite](super::send_guest_star_invite)
+///
+/// [`send-guest-star-invite`](https://dev.twitch.tv/docs/api/reference#send-guest-star-invite)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct SendGuestStarInviteRequest {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+    /// The session ID for the invite to be sent on behalf of the broadcaster.
+    #[builder(setter(into))]
+    pub session_id: String,
+    /// Twitch User ID for the guest to invite to the Guest Star session.
+    #[builder(setter(into))]
+    pub guest_id: types::UserId,
+}
+
+/// Return Values for [Send Guest Star Invite](super::send_guest_star_invite)
+///
+/// [`send-guest-star-invite`](https://dev.twitch.tv/docs/api/reference#send-guest-star-invite)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum SendGuestStarInvite {
+    /// 204 - Successfully sent the invite.
+    Success,
+    /// 400 - The session is invalid, or the guest has already been invited.
+    BadRequest(String),
+    /// 401 - Authorization failed.
+    AuthFailed(String),
+    /// 403 - The user in `moderator_id` is not permitted to send invites.
+    Forbidden(String),
+}
+
+impl Request for SendGuestStarInviteRequest {
+    type Response = SendGuestStarInvite;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:guest_star"),
+    )];
+    const PATH: &'static str = "guest_star/invites";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:guest_star"),
+    )];
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status {
+            http::StatusCode::BAD_REQUEST => Some(SendGuestStarInvite::BadRequest(message)),
+            http::StatusCode::UNAUTHORIZED => Some(SendGuestStarInvite::AuthFailed(message)),
+            http::StatusCode::FORBIDDEN => Some(SendGuestStarInvite::Forbidden(message)),
+            _ => None,
+        }
+    }
+}
+
+impl RequestPost for SendGuestStarInviteRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let data = match status {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => SendGuestStarInvite::Success,
+            _ => {
+                return Err(helix::HelixRequestPostError::InvalidResponse {
+                    reason: "unexpected status code",
+                    response: response.to_string(),
+                    status,
+                    uri: uri.clone(),
+                })
+            }
+        };
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SendGuestStarInviteRequest::builder()
+        .broadcaster_id("9321049")
+        .moderator_id("9321049")
+        .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+        .guest_id("144601104")
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/invites?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI&guest_id=144601104"
+    );
+
+    let res = SendGuestStarInviteRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data, SendGuestStarInvite::Success);
+}
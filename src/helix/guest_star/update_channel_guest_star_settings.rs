@@ -0,0 +1,183 @@
+//! Mutates the channel settings for configuration of the Guest Star feature for a particular host.
+//! [`update-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateChannelGuestStarSettingsRequest]
+//!
+//! To use this endpoint, construct a [`UpdateChannelGuestStarSettingsRequest`] with the [`UpdateChannelGuestStarSettingsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::update_channel_guest_star_settings;
+//! let request =
+//!     update_channel_guest_star_settings::UpdateChannelGuestStarSettingsRequest::builder()
+//!         .broadcaster_id("9321049")
+//!         .build();
+//! ```
+//!
+//! ## Body: [UpdateChannelGuestStarSettingsBody]
+//!
+//! We also need to provide a body to the request containing what we want to change.
+//!
+//! ```
+//! # use twitch_api2::helix::guest_star::{self, update_channel_guest_star_settings};
+//! let body = update_channel_guest_star_settings::UpdateChannelGuestStarSettingsBody::builder()
+//!     .slot_count(4)
+//!     .group_layout(guest_star::GroupLayout::ScreenshareLayout)
+//!     .build();
+//! ```
+//!
+//! ## Response: [UpdateChannelGuestStarSettings]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::update_channel_guest_star_settings};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request =
+//!     update_channel_guest_star_settings::UpdateChannelGuestStarSettingsRequest::builder()
+//!         .broadcaster_id("9321049")
+//!         .build();
+//! let body = update_channel_guest_star_settings::UpdateChannelGuestStarSettingsBody::builder()
+//!     .slot_count(4)
+//!     .build();
+//! let response: update_channel_guest_star_settings::UpdateChannelGuestStarSettings =
+//!     client.req_put(request, body, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`UpdateChannelGuestStarSettingsRequest::parse_response(&request.get_uri(), response)`](UpdateChannelGuestStarSettingsRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Update Channel Guest Star Settings](super::update_channel_guest_star_settings)
+///
+/// [`update-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct UpdateChannelGuestStarSettingsRequest {
+    /// The ID of the broadcaster you want to update Guest Star settings for.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+}
+
+/// Body Parameters for [Update Channel Guest Star Settings](super::update_channel_guest_star_settings)
+///
+/// [`update-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateChannelGuestStarSettingsBody {
+    /// Flag determining if Guest Star moderators have access to control whether a guest is live once assigned to a slot.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_moderator_send_live_enabled: Option<bool>,
+    /// Number of slots the Guest Star call interface will allow the host to add to a call. Required to be between 1 and 6.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot_count: Option<u32>,
+    /// Flag determining if Browser Sources subscribed to sessions on this channel should output audio.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_browser_source_audio_enabled: Option<bool>,
+    /// How the guests within a session should be laid out within the browser source.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_layout: Option<GroupLayout>,
+    /// Flag determining if Guest Star should regenerate the auth token associated with the channel’s browser sources. Providing a true value for this will immediately invalidate all browser sources previously configured in your streaming software.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regenerate_browser_sources: Option<bool>,
+}
+
+impl helix::private::SealedSerialize for UpdateChannelGuestStarSettingsBody {}
+
+/// Return Values for [Update Channel Guest Star Settings](super::update_channel_guest_star_settings)
+///
+/// [`update-channel-guest-star-settings`](https://dev.twitch.tv/docs/api/reference#update-channel-guest-star-settings)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum UpdateChannelGuestStarSettings {
+    /// 204 - Successfully updated channel settings.
+    Success,
+}
+
+impl std::convert::TryFrom<http::StatusCode> for UpdateChannelGuestStarSettings {
+    type Error = std::borrow::Cow<'static, str>;
+
+    fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+        match s {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => {
+                Ok(UpdateChannelGuestStarSettings::Success)
+            }
+            other => Err(other.canonical_reason().unwrap_or("").into()),
+        }
+    }
+}
+
+impl Request for UpdateChannelGuestStarSettingsRequest {
+    type Response = UpdateChannelGuestStarSettings;
+
+    const PATH: &'static str = "guest_star/channel_settings";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:guest_star"),
+    )];
+}
+
+impl RequestPut for UpdateChannelGuestStarSettingsRequest {
+    type Body = UpdateChannelGuestStarSettingsBody;
+
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateChannelGuestStarSettingsRequest::builder()
+        .broadcaster_id("9321049")
+        .build();
+
+    let body = UpdateChannelGuestStarSettingsBody::builder()
+        .group_layout(GroupLayout::TiledLayout)
+        .build();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"group_layout":"TILED_LAYOUT"}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/channel_settings?broadcaster_id=9321049"
+    );
+
+    assert_eq!(
+        UpdateChannelGuestStarSettingsRequest::parse_response(&uri, http_response).unwrap(),
+        UpdateChannelGuestStarSettings::Success
+    );
+}
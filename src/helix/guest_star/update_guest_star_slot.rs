@@ -0,0 +1,157 @@
+//! Allows a user to update the assigned slot for a particular user within the active Guest Star session.
+//! [`update-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateGuestStarSlotRequest]
+//!
+//! To use this endpoint, construct a [`UpdateGuestStarSlotRequest`] with the [`UpdateGuestStarSlotRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::update_guest_star_slot;
+//! let request = update_guest_star_slot::UpdateGuestStarSlotRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .source_slot_id("1")
+//!     .destination_slot_id("2".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [UpdateGuestStarSlot]
+//!
+//! Send the request to receive the response with [`HelixClient::req_patch()`](helix::HelixClient::req_patch).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::update_guest_star_slot};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = update_guest_star_slot::UpdateGuestStarSlotRequest::builder()
+//!     .broadcaster_id("9321049")
+//!     .moderator_id("9321049")
+//!     .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!     .source_slot_id("1")
+//!     .destination_slot_id("2".to_string())
+//!     .build();
+//! let response: update_guest_star_slot::UpdateGuestStarSlot =
+//!     client.req_patch(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPatch::create_request)
+//! and parse the [`http::Response`] with [`UpdateGuestStarSlotRequest::parse_response(None, &request.get_uri(), response)`](UpdateGuestStarSlotRequest::parse_response)
+
+use super::*;
+use helix::RequestPatch;
+
+/// Query Parameters for [Update Guest Star Slot](super::update_guest_star_slot)
+///
+/// [`update-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct UpdateGuestStarSlotRequest {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+    /// The ID of the Guest Star session in which to update slot settings.
+    #[builder(setter(into))]
+    pub session_id: String,
+    /// The slot assignment previously assigned to a user.
+    #[builder(setter(into))]
+    pub source_slot_id: String,
+    /// The slot to move this user assignment to. If the destination slot is occupied, the user assigned will be swapped into `source_slot_id`.
+    #[builder(default, setter(into))]
+    pub destination_slot_id: Option<String>,
+}
+
+/// Return Values for [Update Guest Star Slot](super::update_guest_star_slot)
+///
+/// [`update-guest-star-slot`](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum UpdateGuestStarSlot {
+    /// 204 - Successfully updated the slot assignment.
+    Success,
+}
+
+impl Request for UpdateGuestStarSlotRequest {
+    type Response = UpdateGuestStarSlot;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:guest_star"),
+    )];
+    const PATH: &'static str = "guest_star/slot";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:guest_star"),
+    )];
+}
+
+impl RequestPatch for UpdateGuestStarSlotRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    where
+        Self: Sized,
+    {
+        Ok(helix::Response {
+            data: match status {
+                http::StatusCode::NO_CONTENT | http::StatusCode::OK => UpdateGuestStarSlot::Success,
+                _ => {
+                    return Err(helix::HelixRequestPatchError::InvalidResponse {
+                        reason: "unexpected status code",
+                        response: response.to_string(),
+                        status,
+                        uri: uri.clone(),
+                    })
+                }
+            },
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateGuestStarSlotRequest::builder()
+        .broadcaster_id("9321049")
+        .moderator_id("9321049")
+        .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+        .source_slot_id("1")
+        .destination_slot_id("2".to_string())
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/slot?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI&source_slot_id=1&destination_slot_id=2"
+    );
+
+    let res = UpdateGuestStarSlotRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data, UpdateGuestStarSlot::Success);
+}
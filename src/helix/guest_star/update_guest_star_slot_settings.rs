@@ -0,0 +1,176 @@
+//! Allows a user to update slot settings for a particular guest within a Guest Star session, such as allowing the user to share audio or video within the call as a host.
+//! [`update-guest-star-slot-settings`](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot-settings)
+//!
+//! # Notes
+//!
+//! These settings will be broadcasted to all subscribers which control their view of the guest in that slot.
+//! One or more of the optional parameters to this API can be specified at any time.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateGuestStarSlotSettingsRequest]
+//!
+//! To use this endpoint, construct a [`UpdateGuestStarSlotSettingsRequest`] with the [`UpdateGuestStarSlotSettingsRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::guest_star::update_guest_star_slot_settings;
+//! let request =
+//!     update_guest_star_slot_settings::UpdateGuestStarSlotSettingsRequest::builder()
+//!         .broadcaster_id("9321049")
+//!         .moderator_id("9321049")
+//!         .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!         .slot_id("1")
+//!         .is_audio_enabled(false)
+//!         .build();
+//! ```
+//!
+//! ## Response: [UpdateGuestStarSlotSettings]
+//!
+//! Send the request to receive the response with [`HelixClient::req_patch()`](helix::HelixClient::req_patch).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, guest_star::update_guest_star_slot_settings};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request =
+//!     update_guest_star_slot_settings::UpdateGuestStarSlotSettingsRequest::builder()
+//!         .broadcaster_id("9321049")
+//!         .moderator_id("9321049")
+//!         .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+//!         .slot_id("1")
+//!         .is_audio_enabled(false)
+//!         .build();
+//! let response: update_guest_star_slot_settings::UpdateGuestStarSlotSettings =
+//!     client.req_patch(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPatch::create_request)
+//! and parse the [`http::Response`] with [`UpdateGuestStarSlotSettingsRequest::parse_response(None, &request.get_uri(), response)`](UpdateGuestStarSlotSettingsRequest::parse_response)
+
+use super::*;
+use helix::RequestPatch;
+
+/// Query Parameters for [Update Guest Star Slot Settings](super::update_guest_star_slot_settings)
+///
+/// [`update-guest-star-slot-settings`](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot-settings)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct UpdateGuestStarSlotSettingsRequest {
+    /// The ID of the broadcaster running the Guest Star session.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that has permission to moderate the broadcaster’s chat room. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+    /// The ID of the Guest Star session in which to update a slot’s settings.
+    #[builder(setter(into))]
+    pub session_id: String,
+    /// The slot assignment that has previously been assigned to a user.
+    #[builder(setter(into))]
+    pub slot_id: String,
+    /// Flag indicating whether the slot is allowed to share their audio with the rest of the session. If false, the slot will be muted in any views containing the slot.
+    #[builder(default, setter(into))]
+    pub is_audio_enabled: Option<bool>,
+    /// Flag indicating whether the slot is allowed to share their video with the rest of the session. If false, the slot will have no video shared in any views containing the slot.
+    #[builder(default, setter(into))]
+    pub is_video_enabled: Option<bool>,
+    /// Flag indicating whether the user assigned to this slot is visible/can be heard from any public subscriptions. Generally, this determines whether or not the slot is enabled in any broadcasting software integrations.
+    #[builder(default, setter(into))]
+    pub is_live: Option<bool>,
+    /// Value from 0-100 that controls the audio volume for shared views containing the slot.
+    #[builder(default, setter(into))]
+    pub volume: Option<u32>,
+}
+
+/// Return Values for [Update Guest Star Slot Settings](super::update_guest_star_slot_settings)
+///
+/// [`update-guest-star-slot-settings`](https://dev.twitch.tv/docs/api/reference#update-guest-star-slot-settings)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum UpdateGuestStarSlotSettings {
+    /// 204 - Successfully updated the slot settings.
+    Success,
+}
+
+impl Request for UpdateGuestStarSlotSettingsRequest {
+    type Response = UpdateGuestStarSlotSettings;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:guest_star"),
+    )];
+    const PATH: &'static str = "guest_star/slot_settings";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:guest_star"),
+    )];
+}
+
+impl RequestPatch for UpdateGuestStarSlotSettingsRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPatchError>
+    where
+        Self: Sized,
+    {
+        Ok(helix::Response {
+            data: match status {
+                http::StatusCode::NO_CONTENT | http::StatusCode::OK => {
+                    UpdateGuestStarSlotSettings::Success
+                }
+                _ => {
+                    return Err(helix::HelixRequestPatchError::InvalidResponse {
+                        reason: "unexpected status code",
+                        response: response.to_string(),
+                        status,
+                        uri: uri.clone(),
+                    })
+                }
+            },
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateGuestStarSlotSettingsRequest::builder()
+        .broadcaster_id("9321049")
+        .moderator_id("9321049")
+        .session_id("2KFRQbFtpmfyD3IevNRnCzOPRJI")
+        .slot_id("1")
+        .is_audio_enabled(false)
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/guest_star/slot_settings?broadcaster_id=9321049&moderator_id=9321049&session_id=2KFRQbFtpmfyD3IevNRnCzOPRJI&slot_id=1&is_audio_enabled=false"
+    );
+
+    let res =
+        UpdateGuestStarSlotSettingsRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data, UpdateGuestStarSlotSettings::Success);
+}
@@ -35,16 +35,27 @@ use twitch_oauth2::TwitchToken;
 #[cfg_attr(nightly, doc(cfg(all(feature = "client", feature = "helix"))))]
 mod client_ext;
 
+pub mod analytics;
 pub mod bits;
+pub mod ccls;
 pub mod channels;
+pub mod charity;
+pub mod chat;
 pub mod clips;
+pub mod entitlements;
 #[cfg(feature = "eventsub")]
 #[cfg_attr(nightly, doc(cfg(feature = "eventsub")))]
 pub mod eventsub;
+pub mod extensions;
 pub mod games;
+pub mod goals;
+#[cfg(feature = "unsupported")]
+#[cfg_attr(nightly, doc(cfg(feature = "unsupported")))]
+pub mod guest_star;
 pub mod hypetrain;
 pub mod moderation;
 pub mod points;
+pub mod raids;
 pub mod search;
 pub mod streams;
 pub mod subscriptions;
@@ -53,6 +64,7 @@ pub mod teams;
 pub mod users;
 pub mod videos;
 pub mod webhooks;
+pub mod whispers;
 
 pub(crate) mod ser;
 pub use ser::Error as SerializeError;
@@ -110,6 +122,23 @@ struct InnerResponse<D> {
     /// A cursor value, to be used in a subsequent request to specify the starting point of the next set of results.
     #[serde(default)]
     pagination: Pagination,
+    /// Total number of results, if returned by the endpoint.
+    #[serde(default)]
+    total: Option<i64>,
+    /// Any other top-level fields.
+    #[serde(flatten)]
+    other: serde_json::Map<String, serde_json::Value>,
+}
+
+impl<D> InnerResponse<D> {
+    /// Other fields, if any were returned.
+    fn other(&mut self) -> Option<serde_json::Map<String, serde_json::Value>> {
+        if self.other.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(&mut self.other))
+        }
+    }
 }
 #[derive(Deserialize, Clone, Debug)]
 struct HelixRequestError {
@@ -234,9 +263,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     ) -> Result<D, ClientRequestError<<C as crate::HttpClient<'a>>::Error>>
     where
         R: Request<Response = D> + Request + RequestDelete,
-        D: std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>
-            + serde::de::DeserializeOwned
-            + PartialEq,
+        D: serde::de::DeserializeOwned + PartialEq,
         T: TwitchToken + ?Sized,
     {
         let req = request.create_request(token.token().secret(), token.client_id().as_str())?;
@@ -259,9 +286,7 @@ impl<'a, C: crate::HttpClient<'a>> HelixClient<'a, C> {
     where
         R: Request<Response = D> + Request + RequestPut<Body = B>,
         B: HelixRequestBody,
-        D: std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>
-            + serde::de::DeserializeOwned
-            + PartialEq,
+        D: serde::de::DeserializeOwned + PartialEq,
         T: TwitchToken + ?Sized,
     {
         let req =
@@ -344,6 +369,25 @@ pub trait Request: serde::Serialize {
         ))
         .map_err(Into::into)
     }
+    /// Type an error response as a [`Response`](Request::Response).
+    ///
+    /// Endpoints where Twitch documents the error statuses return them as variants of their response instead of as an error.
+    /// `message` is the message sent by Twitch with the error, empty if there was none.
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        let _ = (status, message);
+        None
+    }
+}
+
+/// Type an error response with [`Request::parse_error`]
+fn typed_error<R: Request>(status: http::StatusCode, response: &str) -> Option<R::Response> {
+    if status.is_success() {
+        return None;
+    }
+    let message = parse_json::<HelixRequestError>(response)
+        .map(|e| e.message)
+        .unwrap_or_default();
+    R::parse_error(status, message)
 }
 
 /// Helix endpoint POSTs information
@@ -395,6 +439,15 @@ pub trait RequestPost: Request {
         let text = std::str::from_utf8(&response.body()).map_err(|e| {
             HelixRequestPostError::Utf8Error(response.body().clone(), e, uri.clone())
         })?;
+        if let Some(data) = typed_error::<Self>(response.status(), text) {
+            return Ok(Response {
+                data,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+            });
+        }
         if let Ok(HelixRequestError {
             error,
             status,
@@ -422,7 +475,7 @@ pub trait RequestPost: Request {
     where
         Self: Sized,
     {
-        let response: InnerResponse<<Self as Request>::Response> =
+        let mut response: InnerResponse<<Self as Request>::Response> =
             parse_json(&response).map_err(|e| {
                 HelixRequestPostError::DeserializeError(
                     response.to_string(),
@@ -431,10 +484,13 @@ pub trait RequestPost: Request {
                     status,
                 )
             })?;
+        let other = response.other();
         Ok(Response {
             data: response.data,
             pagination: response.pagination.cursor,
             request,
+            total: response.total,
+            other,
         })
     }
 }
@@ -488,6 +544,15 @@ pub trait RequestPatch: Request {
         let text = std::str::from_utf8(&response.body()).map_err(|e| {
             HelixRequestPatchError::Utf8Error(response.body().clone(), e, uri.clone())
         })?;
+        if let Some(data) = typed_error::<Self>(response.status(), text) {
+            return Ok(Response {
+                data,
+                pagination: None,
+                request,
+                total: None,
+                other: None,
+            });
+        }
         if let Ok(HelixRequestError {
             error,
             status,
@@ -547,8 +612,6 @@ pub trait RequestDelete: Request {
         response: http::Response<Vec<u8>>,
     ) -> Result<<Self as Request>::Response, HelixRequestDeleteError>
     where
-        <Self as Request>::Response:
-            std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>,
         Self: Sized,
     {
         let text = std::str::from_utf8(&response.body()).map_err(|e| {
@@ -556,6 +619,9 @@ pub trait RequestDelete: Request {
         })?;
         // eprintln!("\n\nmessage is ------------ {} ------------", text);
 
+        if let Some(data) = typed_error::<Self>(response.status(), text) {
+            return Ok(data);
+        }
         if let Ok(HelixRequestError {
             error,
             status,
@@ -569,23 +635,44 @@ pub trait RequestDelete: Request {
                 uri: uri.clone(),
             });
         }
+        <Self as RequestDelete>::parse_inner_response(uri, text, response.status())
+    }
 
-        match response.status().try_into() {
-            Ok(result) => Ok(result),
-            Err(err) => Err(HelixRequestDeleteError::Error {
+    /// Parse a response string into the response.
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<<Self as Request>::Response, HelixRequestDeleteError>
+    where
+        Self: Sized;
+
+    /// Parse a response that is only described by its status code.
+    ///
+    /// Use this in [`parse_inner_response`](RequestDelete::parse_inner_response) for endpoints that return no data.
+    fn parse_status(
+        uri: &http::Uri,
+        _response: &str,
+        status: http::StatusCode,
+    ) -> Result<<Self as Request>::Response, HelixRequestDeleteError>
+    where
+        <Self as Request>::Response:
+            std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>,
+        Self: Sized,
+    {
+        status
+            .try_into()
+            .map_err(|err: std::borrow::Cow<'static, str>| HelixRequestDeleteError::Error {
                 error: String::new(),
-                status: response.status(),
+                status,
                 message: err.to_string(),
                 uri: uri.clone(),
-            }),
-        }
+            })
     }
 }
 
 /// Helix endpoint PUTs information
-pub trait RequestPut: Request
-where <Self as Request>::Response:
-        std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>> {
+pub trait RequestPut: Request {
     /// Body parameters
     type Body: HelixRequestBody;
 
@@ -629,6 +716,9 @@ where <Self as Request>::Response:
         })?;
         // eprintln!("\n\nmessage is ------------ {} ------------", text);
 
+        if let Some(data) = typed_error::<Self>(response.status(), text) {
+            return Ok(data);
+        }
         if let Ok(HelixRequestError {
             error,
             status,
@@ -643,17 +733,40 @@ where <Self as Request>::Response:
                 body: response.body().clone(),
             });
         }
+        <Self as RequestPut>::parse_inner_response(uri, text, response.status())
+    }
+
+    /// Parse a response string into the response.
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<<Self as Request>::Response, HelixRequestPutError>
+    where
+        Self: Sized;
 
-        match response.status().try_into() {
-            Ok(result) => Ok(result),
-            Err(err) => Err(HelixRequestPutError::Error {
+    /// Parse a response that is only described by its status code.
+    ///
+    /// Use this in [`parse_inner_response`](RequestPut::parse_inner_response) for endpoints that return no data.
+    fn parse_status(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<<Self as Request>::Response, HelixRequestPutError>
+    where
+        <Self as Request>::Response:
+            std::convert::TryFrom<http::StatusCode, Error = std::borrow::Cow<'static, str>>,
+        Self: Sized,
+    {
+        status
+            .try_into()
+            .map_err(|err: std::borrow::Cow<'static, str>| HelixRequestPutError::Error {
                 error: String::new(),
-                status: response.status(),
+                status,
                 message: err.to_string(),
                 uri: uri.clone(),
-                body: response.body().clone(),
-            }),
-        }
+                body: response.as_bytes().to_vec(),
+            })
     }
 }
 
@@ -725,13 +838,16 @@ pub trait RequestGet: Request {
     where
         Self: Sized,
     {
-        let response: InnerResponse<_> = parse_json(response).map_err(|e| {
+        let mut response: InnerResponse<_> = parse_json(response).map_err(|e| {
             HelixRequestGetError::DeserializeError(response.to_string(), e, uri.clone(), status)
         })?;
+        let other = response.other();
         Ok(Response {
             data: response.data,
             pagination: response.pagination.cursor,
             request,
+            total: response.total,
+            other,
         })
     }
 }
@@ -748,6 +864,12 @@ where
     pub pagination: Option<Cursor>,
     /// The request that was sent, used for [pagination](Paginated).
     pub request: Option<R>,
+    /// Response would return this many results if fully paginated. Sometimes this is not emitted or correct for this purpose, in those cases, this value will be `None`.
+    pub total: Option<i64>,
+    /// Fields which are not part of the data response, but are returned by the endpoint.
+    ///
+    /// See for example [Get Broadcaster Subscriptions](https://dev.twitch.tv/docs/api/reference#get-broadcaster-subscriptions) which returns this.
+    pub other: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 impl<R, D, T> Response<R, D>
@@ -842,6 +964,10 @@ pub enum ClientRequestError<RE: std::error::Error + Send + Sync + 'static> {
     /// could not parse DELETE response
     // #[error(transparent)] // FIXME: https://github.com/yaahc/displaydoc/issues/15
     HelixRequestDeleteError(#[from] HelixRequestDeleteError),
+    /// could not download or parse analytics report
+    #[cfg(feature = "csv")]
+    #[cfg_attr(nightly, doc(cfg(feature = "csv")))]
+    ReportError(#[from] analytics::ReportError),
     /// {0}
     Custom(std::borrow::Cow<'static, str>),
 }
@@ -1025,6 +1151,13 @@ pub enum HelixRequestDeleteError {
     },
     /// could not parse response as utf8 when calling `DELETE {2}`
     Utf8Error(Vec<u8>, #[source] std::str::Utf8Error, http::Uri),
+    /// deserialization failed when processing request response calling `DELETE {2}` with response: {3} - {0:?}
+    DeserializeError(
+        String,
+        #[source] serde_path_to_error::Error<serde_json::Error>,
+        http::Uri,
+        http::StatusCode,
+    ),
 }
 
 /// Errors that can happen when creating a body
@@ -12,7 +12,9 @@ pub mod get_banned_events;
 pub mod get_banned_users;
 pub mod get_moderator_events;
 pub mod get_moderators;
+pub mod get_shield_mode_status;
 pub mod manage_held_automod_messages;
+pub mod update_shield_mode_status;
 
 #[doc(inline)]
 pub use check_automod_status::{
@@ -27,7 +29,13 @@ pub use get_moderator_events::{GetModeratorEventsRequest, ModeratorEvent};
 #[doc(inline)]
 pub use get_moderators::{GetModeratorsRequest, Moderator};
 #[doc(inline)]
+pub use get_shield_mode_status::{GetShieldModeStatusRequest, ShieldModeStatus};
+#[doc(inline)]
 pub use manage_held_automod_messages::{
     AutoModAction, ManageHeldAutoModMessages, ManageHeldAutoModMessagesBody,
     ManageHeldAutoModMessagesRequest,
 };
+#[doc(inline)]
+pub use update_shield_mode_status::{
+    UpdateShieldModeStatus, UpdateShieldModeStatusBody, UpdateShieldModeStatusRequest,
+};
@@ -0,0 +1,173 @@
+//! Gets the broadcaster’s Shield Mode activation status.
+//! [`get-shield-mode-status`](https://dev.twitch.tv/docs/api/reference#get-shield-mode-status)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetShieldModeStatusRequest]
+//!
+//! To use this endpoint, construct a [`GetShieldModeStatusRequest`] with the [`GetShieldModeStatusRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::moderation::get_shield_mode_status;
+//! let request = get_shield_mode_status::GetShieldModeStatusRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .moderator_id("5678")
+//!     .build();
+//! ```
+//!
+//! ## Response: [ShieldModeStatus]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, moderation::get_shield_mode_status};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_shield_mode_status::GetShieldModeStatusRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .moderator_id("5678")
+//!     .build();
+//! let response: Option<get_shield_mode_status::ShieldModeStatus> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetShieldModeStatusRequest::parse_response(None, &request.get_uri(), response)`](GetShieldModeStatusRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Shield Mode Status](super::get_shield_mode_status)
+///
+/// [`get-shield-mode-status`](https://dev.twitch.tv/docs/api/reference#get-shield-mode-status)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetShieldModeStatusRequest {
+    /// The ID of the broadcaster whose Shield Mode activation status you want to get.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that is one of the broadcaster’s moderators. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+}
+
+/// Return Values for [Get Shield Mode Status](super::get_shield_mode_status) and [Update Shield Mode Status](super::update_shield_mode_status)
+///
+/// [`get-shield-mode-status`](https://dev.twitch.tv/docs/api/reference#get-shield-mode-status)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct ShieldModeStatus {
+    /// Whether Shield Mode is active.
+    pub is_active: bool,
+    /// An ID that identifies the moderator that last activated Shield Mode. Is an empty string if Shield Mode hasn’t been previously activated.
+    pub moderator_id: types::UserId,
+    /// The moderator’s login name. Is an empty string if Shield Mode hasn’t been previously activated.
+    pub moderator_login: types::UserName,
+    /// The moderator’s display name. Is an empty string if Shield Mode hasn’t been previously activated.
+    pub moderator_name: types::DisplayName,
+    /// The UTC timestamp of when Shield Mode was last activated. Is an empty string if Shield Mode hasn’t been previously activated.
+    pub last_activated_at: types::Timestamp,
+}
+
+impl ShieldModeStatus {
+    /// The moderator that last activated Shield Mode, if it has been activated before.
+    pub fn last_activated_by(&self) -> Option<types::User> {
+        if self.moderator_id.is_empty() {
+            return None;
+        }
+        Some(types::User {
+            id: self.moderator_id.clone(),
+            login: self.moderator_login.clone(),
+            display_name: self.moderator_name.clone(),
+        })
+    }
+}
+
+impl Request for GetShieldModeStatusRequest {
+    type Response = Option<ShieldModeStatus>;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:shield_mode"),
+    )];
+    const PATH: &'static str = "moderation/shield_mode";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:read:shield_mode"),
+    )];
+}
+
+impl RequestGet for GetShieldModeStatusRequest {
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestGetError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<ShieldModeStatus>> = helix::parse_json(response)
+            .map_err(|e| {
+                helix::HelixRequestGetError::DeserializeError(
+                    response.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        Ok(helix::Response {
+            data: response.data.into_iter().next(),
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetShieldModeStatusRequest::builder()
+        .broadcaster_id("12345")
+        .moderator_id("98765")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+          {
+            "is_active": true,
+            "moderator_id": "98765",
+            "moderator_name": "SimplySimple",
+            "moderator_login": "simplysimple",
+            "last_activated_at": "2022-07-26T17:16:03.123Z"
+          }
+        ]
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/moderation/shield_mode?broadcaster_id=12345&moderator_id=98765"
+    );
+
+    let res = GetShieldModeStatusRequest::parse_response(Some(req), &uri, http_response)
+        .unwrap()
+        .data
+        .unwrap();
+    assert!(res.is_active);
+    assert_eq!(res.last_activated_by().unwrap().login, "simplysimple");
+}
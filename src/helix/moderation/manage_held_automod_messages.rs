@@ -146,6 +146,8 @@ impl RequestPost for ManageHeldAutoModMessagesRequest {
                 data: ManageHeldAutoModMessages::Success,
                 pagination: None,
                 request,
+                total: None,
+                other: None,
             }),
             _ => Err(helix::HelixRequestPostError::InvalidResponse {
                 reason: "unexpected status",
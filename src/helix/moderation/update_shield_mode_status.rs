@@ -0,0 +1,210 @@
+//! Activates or deactivates the broadcaster’s Shield Mode.
+//! [`update-shield-mode-status`](https://dev.twitch.tv/docs/api/reference#update-shield-mode-status)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateShieldModeStatusRequest]
+//!
+//! To use this endpoint, construct an [`UpdateShieldModeStatusRequest`] with the [`UpdateShieldModeStatusRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::moderation::update_shield_mode_status;
+//! let request = update_shield_mode_status::UpdateShieldModeStatusRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .moderator_id("5678")
+//!     .build();
+//! ```
+//!
+//! ## Body: [UpdateShieldModeStatusBody]
+//!
+//! We also need to provide a body to the request containing the new status.
+//!
+//! ```
+//! # use twitch_api2::helix::moderation::update_shield_mode_status;
+//! let body = update_shield_mode_status::UpdateShieldModeStatusBody::builder()
+//!     .is_active(true)
+//!     .build();
+//! ```
+//!
+//! ## Response: [UpdateShieldModeStatus]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, moderation::update_shield_mode_status};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = update_shield_mode_status::UpdateShieldModeStatusRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .moderator_id("5678")
+//!     .build();
+//! let body = update_shield_mode_status::UpdateShieldModeStatusBody::builder()
+//!     .is_active(true)
+//!     .build();
+//! let response: update_shield_mode_status::UpdateShieldModeStatus = client.req_put(request, body, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`UpdateShieldModeStatusRequest::parse_response(&request.get_uri(), response)`](UpdateShieldModeStatusRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Update Shield Mode Status](super::update_shield_mode_status)
+///
+/// [`update-shield-mode-status`](https://dev.twitch.tv/docs/api/reference#update-shield-mode-status)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct UpdateShieldModeStatusRequest {
+    /// The ID of the broadcaster whose Shield Mode you want to activate or deactivate.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+    /// The ID of the broadcaster or a user that is one of the broadcaster’s moderators. This ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub moderator_id: types::UserId,
+}
+
+/// Body Parameters for [Update Shield Mode Status](super::update_shield_mode_status)
+///
+/// [`update-shield-mode-status`](https://dev.twitch.tv/docs/api/reference#update-shield-mode-status)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct UpdateShieldModeStatusBody {
+    /// A Boolean value that determines whether to activate Shield Mode. Set to true to activate Shield Mode; otherwise, false to deactivate Shield Mode.
+    pub is_active: bool,
+}
+
+impl helix::private::SealedSerialize for UpdateShieldModeStatusBody {}
+
+/// Return Values for [Update Shield Mode Status](super::update_shield_mode_status)
+///
+/// [`update-shield-mode-status`](https://dev.twitch.tv/docs/api/reference#update-shield-mode-status)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum UpdateShieldModeStatus {
+    /// 200 - Successfully updated the broadcaster’s Shield Mode status.
+    Success(ShieldModeStatus),
+    /// 400 - The `is_active` field is missing or one of the IDs is invalid.
+    BadRequest(String),
+    /// 401 - Authorization failed.
+    AuthFailed(String),
+    /// 403 - The user in `moderator_id` is not one of the broadcaster’s moderators.
+    Forbidden(String),
+}
+
+impl Request for UpdateShieldModeStatusRequest {
+    type Response = UpdateShieldModeStatus;
+
+    const PATH: &'static str = "moderation/shield_mode";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("moderator:manage:shield_mode"),
+    )];
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status {
+            http::StatusCode::BAD_REQUEST => Some(UpdateShieldModeStatus::BadRequest(message)),
+            http::StatusCode::UNAUTHORIZED => Some(UpdateShieldModeStatus::AuthFailed(message)),
+            http::StatusCode::FORBIDDEN => Some(UpdateShieldModeStatus::Forbidden(message)),
+            _ => None,
+        }
+    }
+}
+
+impl RequestPut for UpdateShieldModeStatusRequest {
+    type Body = UpdateShieldModeStatusBody;
+
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        let resp: helix::InnerResponse<Vec<ShieldModeStatus>> = helix::parse_json(response)
+            .map_err(|e| {
+                helix::HelixRequestPutError::DeserializeError(
+                    response.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        resp.data
+            .into_iter()
+            .next()
+            .map(UpdateShieldModeStatus::Success)
+            .ok_or_else(|| helix::HelixRequestPutError::Error {
+                error: String::new(),
+                status,
+                message: "response included no data".to_string(),
+                uri: uri.clone(),
+                body: response.as_bytes().to_vec(),
+            })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateShieldModeStatusRequest::builder()
+        .broadcaster_id("12345")
+        .moderator_id("98765")
+        .build();
+
+    let body = UpdateShieldModeStatusBody::builder()
+        .is_active(false)
+        .build();
+
+    assert_eq!(
+        std::str::from_utf8(&body.try_to_body().unwrap()).unwrap(),
+        r#"{"is_active":false}"#
+    );
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+          {
+            "is_active": false,
+            "moderator_id": "98765",
+            "moderator_name": "SimplySimple",
+            "moderator_login": "simplysimple",
+            "last_activated_at": "2022-07-26T17:16:03.123Z"
+          }
+        ]
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(200).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/moderation/shield_mode?broadcaster_id=12345&moderator_id=98765"
+    );
+
+    match UpdateShieldModeStatusRequest::parse_response(&uri, http_response).unwrap() {
+        UpdateShieldModeStatus::Success(status) => {
+            assert!(!status.is_active);
+            assert_eq!(status.moderator_id, "98765");
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+
+    let data = br#"{"error":"Forbidden","status":403,"message":"not a moderator"}"#.to_vec();
+    let http_response = http::Response::builder().status(403).body(data).unwrap();
+    assert_eq!(
+        UpdateShieldModeStatusRequest::parse_response(&uri, http_response).unwrap(),
+        UpdateShieldModeStatus::Forbidden("not a moderator".to_string())
+    );
+}
@@ -163,6 +163,8 @@ impl RequestPost for CreateCustomRewardRequest {
             data,
             pagination: response.pagination.cursor,
             request,
+            total: None,
+            other: None,
         })
     }
 }
@@ -209,6 +209,8 @@ impl RequestPatch for UpdateCustomRewardRequest {
             data: resp,
             pagination: None,
             request,
+            total: None,
+            other: None,
         })
     }
 }
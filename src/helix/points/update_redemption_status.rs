@@ -173,6 +173,8 @@ impl RequestPatch for UpdateRedemptionStatusRequest {
             data: resp,
             pagination: None,
             request,
+            total: None,
+            other: None,
         })
     }
 }
@@ -0,0 +1,40 @@
+#![doc(alias = "raid")]
+//! Helix endpoints regarding raids
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, raids::{StartARaidRequest, StartARaid}};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = StartARaidRequest::builder()
+//!     .from_broadcaster_id("12345678")
+//!     .to_broadcaster_id("87654321")
+//!     .build();
+//!
+//! match client.req_post(req, twitch_api2::helix::EmptyBody, &token).await?.data {
+//!     StartARaid::Success(raid) => println!("raiding, mature: {}", raid.is_mature),
+//!     StartARaid::TooManyRequests(message) => println!("too many raids: {}", message),
+//!     other => println!("could not raid: {:?}", other),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod cancel_a_raid;
+pub mod start_a_raid;
+
+#[doc(inline)]
+pub use cancel_a_raid::{CancelARaid, CancelARaidRequest};
+#[doc(inline)]
+pub use start_a_raid::{Raid, StartARaid, StartARaidRequest};
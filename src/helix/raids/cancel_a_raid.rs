@@ -0,0 +1,147 @@
+//! Cancel a pending raid.
+//! [`cancel-a-raid`](https://dev.twitch.tv/docs/api/reference#cancel-a-raid)
+//!
+//! You can cancel a raid at any point up until the broadcaster clicks Raid Now in the Twitch UX or the 90-second countdown expires.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [CancelARaidRequest]
+//!
+//! To use this endpoint, construct a [`CancelARaidRequest`] with the [`CancelARaidRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::raids::cancel_a_raid;
+//! let request = cancel_a_raid::CancelARaidRequest::builder()
+//!     .broadcaster_id("12345678")
+//!     .build();
+//! ```
+//!
+//! ## Response: [CancelARaid]
+//!
+//! Send the request to receive the response with [`HelixClient::req_delete()`](helix::HelixClient::req_delete).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, raids::cancel_a_raid};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = cancel_a_raid::CancelARaidRequest::builder()
+//!     .broadcaster_id("12345678")
+//!     .build();
+//! let response: cancel_a_raid::CancelARaid = client.req_delete(request, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestDelete::create_request)
+//! and parse the [`http::Response`] with [`CancelARaidRequest::parse_response(&request.get_uri(), response)`](CancelARaidRequest::parse_response)
+
+use super::*;
+use helix::RequestDelete;
+
+/// Query Parameters for [Cancel a raid](super::cancel_a_raid)
+///
+/// [`cancel-a-raid`](https://dev.twitch.tv/docs/api/reference#cancel-a-raid)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct CancelARaidRequest {
+    /// The ID of the broadcaster that initiated the raid. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+}
+
+/// Return Values for [Cancel a raid](super::cancel_a_raid)
+///
+/// [`cancel-a-raid`](https://dev.twitch.tv/docs/api/reference#cancel-a-raid)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum CancelARaid {
+    /// 204 - The pending raid was successfully canceled.
+    Success,
+    /// 401 - Authorization failed.
+    AuthFailed(String),
+    /// 404 - The broadcaster doesn’t have a pending raid to cancel.
+    NoPendingRaid(String),
+    /// 429 - The broadcaster exceeded the number of raid requests that they may make.
+    TooManyRequests(String),
+}
+
+impl std::convert::TryFrom<http::StatusCode> for CancelARaid {
+    type Error = std::borrow::Cow<'static, str>;
+
+    fn try_from(s: http::StatusCode) -> Result<Self, Self::Error> {
+        match s {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => Ok(CancelARaid::Success),
+            other => Err(other.canonical_reason().unwrap_or("").into()),
+        }
+    }
+}
+
+impl Request for CancelARaidRequest {
+    type Response = CancelARaid;
+
+    const PATH: &'static str = "raids";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:raids"),
+    )];
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status {
+            http::StatusCode::UNAUTHORIZED => Some(CancelARaid::AuthFailed(message)),
+            http::StatusCode::NOT_FOUND => Some(CancelARaid::NoPendingRaid(message)),
+            http::StatusCode::TOO_MANY_REQUESTS => Some(CancelARaid::TooManyRequests(message)),
+            _ => None,
+        }
+    }
+}
+
+impl RequestDelete for CancelARaidRequest {
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestDeleteError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = CancelARaidRequest::builder()
+        .broadcaster_id("12345678")
+        .build();
+
+    dbg!(req.create_request("token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/raids?broadcaster_id=12345678"
+    );
+
+    assert_eq!(
+        CancelARaidRequest::parse_response(&uri, http_response).unwrap(),
+        CancelARaid::Success
+    );
+
+    let data = br#"{"error":"Not Found","status":404,"message":"The broadcaster doesn't have a pending raid to cancel"}"#.to_vec();
+    let http_response = http::Response::builder().status(404).body(data).unwrap();
+    assert_eq!(
+        CancelARaidRequest::parse_response(&uri, http_response).unwrap(),
+        CancelARaid::NoPendingRaid(
+            "The broadcaster doesn't have a pending raid to cancel".to_string()
+        )
+    );
+}
@@ -0,0 +1,215 @@
+//! Raid another channel by sending the broadcaster’s viewers to the targeted channel.
+//! [`start-a-raid`](https://dev.twitch.tv/docs/api/reference#start-a-raid)
+//!
+//! When you call the API from a chat bot or extension, the Twitch UI pops up a window at the top of the chat room that identifies the number of viewers in the raid.
+//! The raid occurs when the broadcaster clicks Raid Now or after the 90-second countdown expires.
+//!
+//! The rate limit is 10 requests within a 10-minute window, exceeding it results in [`StartARaid::TooManyRequests`].
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [StartARaidRequest]
+//!
+//! To use this endpoint, construct a [`StartARaidRequest`] with the [`StartARaidRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::raids::start_a_raid;
+//! let request = start_a_raid::StartARaidRequest::builder()
+//!     .from_broadcaster_id("12345678")
+//!     .to_broadcaster_id("87654321")
+//!     .build();
+//! ```
+//!
+//! ## Response: [StartARaid]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, raids::start_a_raid};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = start_a_raid::StartARaidRequest::builder()
+//!     .from_broadcaster_id("12345678")
+//!     .to_broadcaster_id("87654321")
+//!     .build();
+//! let response: start_a_raid::StartARaid = client.req_post(request, helix::EmptyBody, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`StartARaidRequest::parse_response(None, &request.get_uri(), response)`](StartARaidRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Start a raid](super::start_a_raid)
+///
+/// [`start-a-raid`](https://dev.twitch.tv/docs/api/reference#start-a-raid)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct StartARaidRequest {
+    /// The ID of the broadcaster that’s sending the raiding party. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub from_broadcaster_id: types::UserId,
+    /// The ID of the broadcaster to raid.
+    #[builder(setter(into))]
+    pub to_broadcaster_id: types::UserId,
+}
+
+/// Information about a pending raid, returned by [Start a raid](super::start_a_raid)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Raid {
+    /// The UTC date and time of when the raid was requested.
+    pub created_at: types::Timestamp,
+    /// Indicates whether the channel being raided contains mature content.
+    pub is_mature: bool,
+}
+
+/// Return Values for [Start a raid](super::start_a_raid)
+///
+/// [`start-a-raid`](https://dev.twitch.tv/docs/api/reference#start-a-raid)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum StartARaid {
+    /// 200 - Successfully requested to start a raid.
+    Success(Raid),
+    /// 400 - The broadcaster may not raid themselves, the targeted channel doesn’t exist or doesn’t accept raids from this broadcaster.
+    BadRequest(String),
+    /// 401 - Authorization failed.
+    AuthFailed(String),
+    /// 404 - The targeted channel was not found.
+    NotFound(String),
+    /// 409 - The broadcaster is already in the process of raiding another channel.
+    AlreadyRaiding(String),
+    /// 429 - The broadcaster exceeded the number of raid requests that they may make.
+    TooManyRequests(String),
+}
+
+impl Request for StartARaidRequest {
+    type Response = StartARaid;
+
+    const PATH: &'static str = "raids";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("channel:manage:raids"),
+    )];
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status {
+            http::StatusCode::BAD_REQUEST => Some(StartARaid::BadRequest(message)),
+            http::StatusCode::UNAUTHORIZED => Some(StartARaid::AuthFailed(message)),
+            http::StatusCode::NOT_FOUND => Some(StartARaid::NotFound(message)),
+            http::StatusCode::CONFLICT => Some(StartARaid::AlreadyRaiding(message)),
+            http::StatusCode::TOO_MANY_REQUESTS => Some(StartARaid::TooManyRequests(message)),
+            _ => None,
+        }
+    }
+}
+
+impl RequestPost for StartARaidRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let data = match status {
+            http::StatusCode::OK => {
+                let resp: helix::InnerResponse<Vec<Raid>> =
+                    helix::parse_json(response).map_err(|e| {
+                        helix::HelixRequestPostError::DeserializeError(
+                            response.to_string(),
+                            e,
+                            uri.clone(),
+                            status,
+                        )
+                    })?;
+                StartARaid::Success(resp.data.into_iter().next().ok_or(
+                    helix::HelixRequestPostError::InvalidResponse {
+                        reason: "expected at least one element in data",
+                        response: response.to_string(),
+                        status,
+                        uri: uri.clone(),
+                    },
+                )?)
+            }
+            _ => {
+                return Err(helix::HelixRequestPostError::InvalidResponse {
+                    reason: "unexpected status code",
+                    response: response.to_string(),
+                    status,
+                    uri: uri.clone(),
+                })
+            }
+        };
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = StartARaidRequest::builder()
+        .from_broadcaster_id("12345678")
+        .to_broadcaster_id("87654321")
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "created_at": "2022-02-18T07:20:50.52Z",
+            "is_mature": false
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(200).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/raids?from_broadcaster_id=12345678&to_broadcaster_id=87654321"
+    );
+
+    let res = StartARaidRequest::parse_response(Some(req.clone()), &uri, http_response).unwrap();
+    assert!(matches!(
+        res.data,
+        StartARaid::Success(Raid {
+            is_mature: false,
+            ..
+        })
+    ));
+
+    let data = br#"{"error":"Too Many Requests","status":429,"message":"The broadcaster exceeded the number of raid requests"}"#.to_vec();
+    let http_response = http::Response::builder().status(429).body(data).unwrap();
+    let res = StartARaidRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        res.data,
+        StartARaid::TooManyRequests(
+            "The broadcaster exceeded the number of raid requests".to_string()
+        )
+    );
+}
@@ -26,16 +26,27 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
+#[doc(inline)]
+pub use create_stream_marker::{
+    CreateStreamMarkerBody, CreateStreamMarkerRequest, CreatedStreamMarker,
+};
 #[doc(inline)]
 pub use get_followed_streams::GetFollowedStreamsRequest;
 #[doc(inline)]
+pub use get_stream_key::{GetStreamKeyRequest, StreamKey};
+#[doc(inline)]
 pub use get_stream_tags::{GetStreamTagsRequest, Tag};
 #[doc(inline)]
+pub use get_stream_markers::{GetStreamMarkersRequest, StreamMarker, StreamMarkers, VideoMarkers};
+#[doc(inline)]
 pub use get_streams::{GetStreamsRequest, Stream};
 #[doc(inline)]
 pub use replace_stream_tags::{ReplaceStreamTags, ReplaceStreamTagsBody, ReplaceStreamTagsRequest};
 
+pub mod create_stream_marker;
 pub mod get_followed_streams;
+pub mod get_stream_key;
+pub mod get_stream_markers;
 pub mod get_stream_tags;
 pub mod get_streams;
 pub mod replace_stream_tags;
@@ -0,0 +1,182 @@
+//! Creates a marker in the stream of a user specified by user ID.
+//! [`create-stream-marker`](https://dev.twitch.tv/docs/api/reference#create-stream-marker)
+//!
+//! A marker is an arbitrary point in a stream that the broadcaster wants to mark; e.g., to easily return to later.
+//! Markers can only be created in live streams that have VOD storage enabled.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [CreateStreamMarkerRequest]
+//!
+//! To use this endpoint, construct a [`CreateStreamMarkerRequest`] with the [`CreateStreamMarkerRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::streams::create_stream_marker;
+//! let request = create_stream_marker::CreateStreamMarkerRequest::builder().build();
+//! ```
+//!
+//! ## Body: [CreateStreamMarkerBody]
+//!
+//! We also need to provide a body to the request containing the user to mark the stream of.
+//!
+//! ```
+//! # use twitch_api2::helix::streams::create_stream_marker;
+//! let body = create_stream_marker::CreateStreamMarkerBody::builder()
+//!     .user_id("123")
+//!     .description("hello, this is a marker!".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [CreatedStreamMarker]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, streams::create_stream_marker};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = create_stream_marker::CreateStreamMarkerRequest::builder().build();
+//! let body = create_stream_marker::CreateStreamMarkerBody::builder()
+//!     .user_id("123")
+//!     .description("hello, this is a marker!".to_string())
+//!     .build();
+//! let response: create_stream_marker::CreatedStreamMarker = client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`CreateStreamMarkerRequest::parse_response(None, &request.get_uri(), response)`](CreateStreamMarkerRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Create Stream Marker](super::create_stream_marker)
+///
+/// [`create-stream-marker`](https://dev.twitch.tv/docs/api/reference#create-stream-marker)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct CreateStreamMarkerRequest {}
+
+/// Body Parameters for [Create Stream Marker](super::create_stream_marker)
+///
+/// [`create-stream-marker`](https://dev.twitch.tv/docs/api/reference#create-stream-marker)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct CreateStreamMarkerBody {
+    /// ID of the broadcaster in whose live stream the marker is created.
+    #[builder(setter(into))]
+    pub user_id: types::UserId,
+    /// Description of or comments on the marker. Max length is 140 characters.
+    #[builder(default, setter(into))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl helix::private::SealedSerialize for CreateStreamMarkerBody {}
+
+/// Return Values for [Create Stream Marker](super::create_stream_marker)
+///
+/// [`create-stream-marker`](https://dev.twitch.tv/docs/api/reference#create-stream-marker)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct CreatedStreamMarker {
+    /// Unique ID of the marker.
+    pub id: String,
+    /// RFC3339 timestamp of the marker.
+    pub created_at: types::Timestamp,
+    /// Description of the marker.
+    pub description: String,
+    /// Relative offset (in seconds) of the marker, from the beginning of the stream.
+    pub position_seconds: i64,
+}
+
+impl Request for CreateStreamMarkerRequest {
+    type Response = CreatedStreamMarker;
+
+    const PATH: &'static str = "streams/markers";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelManageBroadcast];
+}
+
+impl RequestPost for CreateStreamMarkerRequest {
+    type Body = CreateStreamMarkerBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response_str: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let response: helix::InnerResponse<Vec<Self::Response>> = helix::parse_json(&response_str)
+            .map_err(|e| {
+                helix::HelixRequestPostError::DeserializeError(
+                    response_str.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        let data = response.data.into_iter().next().ok_or_else(|| {
+            helix::HelixRequestPostError::InvalidResponse {
+                reason: "response included no data",
+                response: response_str.to_string(),
+                status,
+                uri: uri.clone(),
+            }
+        })?;
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = CreateStreamMarkerRequest::builder().build();
+
+    let body = CreateStreamMarkerBody::builder()
+        .user_id("123")
+        .description("hello, this is a marker!".to_string())
+        .build();
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "id": "123",
+            "created_at": "2018-08-20T20:10:03Z",
+            "description": "hello, this is a marker!",
+            "position_seconds": 244
+        }
+    ]
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/streams/markers?"
+    );
+
+    dbg!(CreateStreamMarkerRequest::parse_response(Some(req), &uri, http_response).unwrap());
+}
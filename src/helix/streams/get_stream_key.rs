@@ -0,0 +1,110 @@
+//! Gets the channel’s stream key.
+//! [`get-stream-key`](https://dev.twitch.tv/docs/api/reference#get-stream-key)
+//!
+//! The key is wrapped in [`types::Secret`] so that it is redacted when logged.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetStreamKeyRequest]
+//!
+//! To use this endpoint, construct a [`GetStreamKeyRequest`] with the [`GetStreamKeyRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::streams::get_stream_key;
+//! let request = get_stream_key::GetStreamKeyRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! ```
+//!
+//! ## Response: [StreamKey]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, streams::get_stream_key};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_stream_key::GetStreamKeyRequest::builder()
+//!     .broadcaster_id("1234")
+//!     .build();
+//! let response: Vec<get_stream_key::StreamKey> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetStreamKeyRequest::parse_response(None, &request.get_uri(), response)`](GetStreamKeyRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Stream Key](super::get_stream_key)
+///
+/// [`get-stream-key`](https://dev.twitch.tv/docs/api/reference#get-stream-key)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct GetStreamKeyRequest {
+    /// The ID of the broadcaster that owns the channel. The ID must match the user ID in the access token.
+    #[builder(setter(into))]
+    pub broadcaster_id: types::UserId,
+}
+
+/// Return Values for [Get Stream Key](super::get_stream_key)
+///
+/// [`get-stream-key`](https://dev.twitch.tv/docs/api/reference#get-stream-key)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct StreamKey {
+    /// The channel’s stream key.
+    pub stream_key: types::Secret,
+}
+
+impl Request for GetStreamKeyRequest {
+    type Response = Vec<StreamKey>;
+
+    const PATH: &'static str = "streams/key";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::ChannelReadStreamKey];
+}
+
+impl RequestGet for GetStreamKeyRequest {}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetStreamKeyRequest::builder()
+        .broadcaster_id("141981764")
+        .build();
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+          {
+            "stream_key": "live_44322889_a34ub37c8ajv98a0"
+          }
+        ]
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/streams/key?broadcaster_id=141981764"
+    );
+
+    let res = GetStreamKeyRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        res.data[0].stream_key.secret(),
+        "live_44322889_a34ub37c8ajv98a0"
+    );
+    assert!(!format!("{:?}", res).contains("live_44322889"));
+}
@@ -0,0 +1,179 @@
+//! Gets a list of markers for either a specified user’s most recent stream or a specified VOD/video (stream), ordered by recency.
+//! [`get-stream-markers`](https://dev.twitch.tv/docs/api/reference#get-stream-markers)
+//!
+//! Markers are grouped by the user and by the video they were created in.
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [GetStreamMarkersRequest]
+//!
+//! To use this endpoint, construct a [`GetStreamMarkersRequest`] with the [`GetStreamMarkersRequest::builder()`] method.
+//! Only one of `user_id` and `video_id` must be specified.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::streams::get_stream_markers;
+//! let request = get_stream_markers::GetStreamMarkersRequest::builder()
+//!     .user_id("123".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [StreamMarkers]
+//!
+//! Send the request to receive the response with [`HelixClient::req_get()`](helix::HelixClient::req_get).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, streams::get_stream_markers};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = get_stream_markers::GetStreamMarkersRequest::builder()
+//!     .user_id("123".to_string())
+//!     .build();
+//! let response: Vec<get_stream_markers::StreamMarkers> = client.req_get(request, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestGet::create_request)
+//! and parse the [`http::Response`] with [`GetStreamMarkersRequest::parse_response(None, &request.get_uri(), response)`](GetStreamMarkersRequest::parse_response)
+
+use super::*;
+use helix::RequestGet;
+
+/// Query Parameters for [Get Stream Markers](super::get_stream_markers)
+///
+/// [`get-stream-markers`](https://dev.twitch.tv/docs/api/reference#get-stream-markers)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct GetStreamMarkersRequest {
+    /// ID of the broadcaster from whose stream markers are returned.
+    #[builder(default, setter(into))]
+    pub user_id: Option<types::UserId>,
+    /// ID of the VOD/video whose stream markers are returned.
+    #[builder(default, setter(into))]
+    pub video_id: Option<types::VideoId>,
+    /// Cursor for forward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub after: Option<helix::Cursor>,
+    /// Cursor for backward pagination: tells the server where to start fetching the next set of results, in a multi-page response. The cursor value specified here is from the pagination response field of a prior query.
+    #[builder(default)]
+    pub before: Option<helix::Cursor>,
+    /// Number of values to be returned when getting videos by user or game ID. Limit: 100. Default: 20.
+    #[builder(default, setter(into))]
+    pub first: Option<usize>,
+}
+
+/// Return Values for [Get Stream Markers](super::get_stream_markers)
+///
+/// [`get-stream-markers`](https://dev.twitch.tv/docs/api/reference#get-stream-markers)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct StreamMarkers {
+    /// ID of the user whose markers are returned.
+    pub user_id: types::UserId,
+    /// Display name corresponding to `user_id`.
+    pub user_name: types::DisplayName,
+    /// Login corresponding to `user_id`.
+    pub user_login: types::UserName,
+    /// Videos and their markers.
+    pub videos: Vec<VideoMarkers>,
+}
+
+/// Markers in a video, returned by [Get Stream Markers](super::get_stream_markers)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct VideoMarkers {
+    /// ID of the stream (VOD/video) that was marked.
+    pub video_id: types::VideoId,
+    /// Markers in the video.
+    pub markers: Vec<StreamMarker>,
+}
+
+/// A marker in a [video](VideoMarkers)
+#[derive(PartialEq, Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct StreamMarker {
+    /// Unique ID of the marker.
+    pub id: String,
+    /// RFC3339 timestamp of the marker.
+    pub created_at: types::Timestamp,
+    /// Description of the marker.
+    pub description: String,
+    /// Relative offset (in seconds) of the marker, from the beginning of the stream.
+    pub position_seconds: i64,
+    /// A link to the stream with a query parameter that is a timestamp of the marker’s location.
+    #[serde(rename = "URL")]
+    pub url: String,
+}
+
+impl Request for GetStreamMarkersRequest {
+    type Response = Vec<StreamMarkers>;
+
+    const PATH: &'static str = "streams/markers";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadBroadcast];
+}
+
+impl RequestGet for GetStreamMarkersRequest {}
+
+impl helix::Paginated for GetStreamMarkersRequest {
+    fn set_pagination(&mut self, cursor: Option<helix::Cursor>) { self.after = cursor }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = GetStreamMarkersRequest::builder()
+        .user_id("123".to_string())
+        .first(5)
+        .build();
+
+    // From twitch docs
+    let data = br#"
+{
+    "data": [
+        {
+            "user_id": "123",
+            "user_name": "TwitchName",
+            "user_login": "twitchname",
+            "videos": [
+                {
+                    "video_id": "456",
+                    "markers": [
+                        {
+                            "id": "106b8d6243a4f883d25ad75e6cdffdc4",
+                            "created_at": "2018-08-20T20:10:03Z",
+                            "description": "hello, this is a marker!",
+                            "position_seconds": 244,
+                            "URL": "https://twitch.tv/videos/456?t=0h4m06s"
+                        }
+                    ]
+                }
+            ]
+        }
+    ],
+    "pagination": {
+        "cursor": "eyJiIjpudWxsLCJhIjoiMjk1MjA0Mzk3OjI1Mzpib29rbWFyazoxMDZiOGQ1Y"
+    }
+}
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/streams/markers?user_id=123&first=5"
+    );
+
+    let res = GetStreamMarkersRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(res.data[0].videos[0].markers[0].position_seconds, 244);
+    dbg!(res);
+}
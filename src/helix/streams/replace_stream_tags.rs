@@ -123,6 +123,17 @@ impl Request for ReplaceStreamTagsRequest {
 
 impl RequestPut for ReplaceStreamTagsRequest {
     type Body = ReplaceStreamTagsBody;
+
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
 }
 
 #[test]
@@ -114,6 +114,8 @@ impl RequestGet for CheckUserSubscriptionRequest {
             )?,
             pagination: inner_response.pagination.cursor,
             request,
+            total: None,
+            other: None,
         })
     }
 }
@@ -126,7 +126,9 @@ fn test_request() {
     ],
     "pagination": {
         "cursor": "xxxx"
-    }
+    },
+    "total": 13,
+    "points": 13
 }
 "#
     .to_vec();
@@ -139,7 +141,9 @@ fn test_request() {
         "https://api.twitch.tv/helix/subscriptions?broadcaster_id=123"
     );
 
-    dbg!(
+    let res = dbg!(
         GetBroadcasterSubscriptionsRequest::parse_response(Some(req), &uri, http_response).unwrap()
     );
+    assert_eq!(res.total, Some(13));
+    assert_eq!(res.other.unwrap()["points"], 13);
 }
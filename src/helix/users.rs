@@ -32,6 +32,7 @@ pub mod get_user_block_list;
 pub mod get_users;
 pub mod get_users_follows;
 pub mod unblock_user;
+pub mod update_user;
 
 #[doc(inline)]
 pub use block_user::{BlockUser, BlockUserRequest};
@@ -47,3 +48,5 @@ pub use get_users::{GetUsersRequest, User};
 pub use get_users_follows::{GetUsersFollowsRequest, UsersFollows};
 #[doc(inline)]
 pub use unblock_user::{UnblockUser, UnblockUserRequest};
+#[doc(inline)]
+pub use update_user::UpdateUserRequest;
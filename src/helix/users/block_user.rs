@@ -126,6 +126,17 @@ impl Request for BlockUserRequest {
 
 impl RequestPut for BlockUserRequest {
     type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
 }
 
 #[test]
@@ -167,6 +167,8 @@ impl RequestPost for CreateUserFollowsRequest {
             data: response, // FIXME: This should be a bit better...
             pagination: <_>::default(),
             request,
+            total: None,
+            other: None,
         })
     }
 
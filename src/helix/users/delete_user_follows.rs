@@ -94,7 +94,18 @@ impl Request for DeleteUserFollowsRequest {
     const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserEditFollows];
 }
 
-impl RequestDelete for DeleteUserFollowsRequest {}
+impl RequestDelete for DeleteUserFollowsRequest {
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestDeleteError>
+    where
+        Self: Sized,
+    {
+        Self::parse_status(uri, response, status)
+    }
+}
 
 #[test]
 fn test_request() {
@@ -1,6 +1,11 @@
 //! Gets information on follow relationships between two Twitch users.
 //! [`get-users-follows`](https://dev.twitch.tv/docs/api/reference#get-users-follows)
 //!
+//! # Notes
+//!
+//! This endpoint is deprecated by Twitch, prefer [Get Channel Followers](crate::helix::channels::get_channel_followers)
+//! and [Get Followed Channels](crate::helix::channels::get_followed_channels).
+//!
 //! ## Request: [GetUsersFollowsRequest]
 //!
 //! To use this endpoint, construct a [`GetUsersFollowsRequest`] with the [`GetUsersFollowsRequest::builder()`] method.
@@ -140,6 +145,8 @@ impl RequestGet for GetUsersFollowsRequest {
             },
             pagination: response.pagination.cursor,
             request,
+            total: response.total,
+            other: None,
         })
     }
 }
@@ -0,0 +1,141 @@
+//! Updates the description of the user specified by the bearer token.
+//! [`update-user`](https://dev.twitch.tv/docs/api/reference#update-user)
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [UpdateUserRequest]
+//!
+//! To use this endpoint, construct a [`UpdateUserRequest`] with the [`UpdateUserRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::users::update_user;
+//! let request = update_user::UpdateUserRequest::builder()
+//!     .description("my description".to_string())
+//!     .build();
+//! ```
+//!
+//! ## Response: [User]
+//!
+//! Send the request to receive the response with [`HelixClient::req_put()`](helix::HelixClient::req_put).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, users::update_user};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = update_user::UpdateUserRequest::builder()
+//!     .description("my description".to_string())
+//!     .build();
+//! let response: helix::users::User = client.req_put(request, helix::EmptyBody, &token).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(&token, &client_id)`](helix::RequestPut::create_request)
+//! and parse the [`http::Response`] with [`UpdateUserRequest::parse_response(&request.get_uri(), response)`](UpdateUserRequest::parse_response)
+
+use super::*;
+use helix::RequestPut;
+
+/// Query Parameters for [Update User](super::update_user)
+///
+/// [`update-user`](https://dev.twitch.tv/docs/api/reference#update-user)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct UpdateUserRequest {
+    /// The string to update the channel’s description to. The description is limited to a maximum of 300 characters.
+    ///
+    /// To remove the description, set this to an empty string. If [`None`], the description is not changed.
+    #[builder(default, setter(into))]
+    pub description: Option<String>,
+}
+
+impl Request for UpdateUserRequest {
+    type Response = User;
+
+    #[cfg(feature = "twitch_oauth2")]
+    const OPT_SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserReadEmail];
+    const PATH: &'static str = "users";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::UserEdit];
+}
+
+impl RequestPut for UpdateUserRequest {
+    type Body = helix::EmptyBody;
+
+    fn parse_inner_response(
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<Self::Response, helix::HelixRequestPutError>
+    where
+        Self: Sized,
+    {
+        let resp: helix::InnerResponse<Vec<User>> =
+            helix::parse_json(response).map_err(|e| {
+                helix::HelixRequestPutError::DeserializeError(
+                    response.to_string(),
+                    e,
+                    uri.clone(),
+                    status,
+                )
+            })?;
+        resp.data
+            .into_iter()
+            .next()
+            .ok_or_else(|| helix::HelixRequestPutError::Error {
+                error: String::new(),
+                status,
+                message: "response included no data".to_string(),
+                uri: uri.clone(),
+                body: response.as_bytes().to_vec(),
+            })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = UpdateUserRequest::builder()
+        .description("BaldAngel".to_string())
+        .build();
+
+    dbg!(req.create_request(EmptyBody, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#"
+    {
+        "data": [
+          {
+            "id": "44322889",
+            "login": "dallas",
+            "display_name": "dallas",
+            "type": "staff",
+            "broadcaster_type": "affiliate",
+            "description": "BaldAngel",
+            "profile_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/4d1f36cbf1f0072d-profile_image-300x300.png",
+            "offline_image_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/dallas-channel_offline_image-2e82c1df2a464df7-1920x1080.jpeg",
+            "view_count": 6995,
+            "email": "not-real@email.com",
+            "created_at": "2013-06-03T19:12:02.580593Z"
+          }
+        ]
+      }
+"#
+    .to_vec();
+
+    let http_response = http::Response::builder().status(200).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/users?description=BaldAngel"
+    );
+
+    let user = UpdateUserRequest::parse_response(&uri, http_response).unwrap();
+    assert_eq!(user.id.as_str(), "44322889");
+    assert_eq!(user.description.as_deref(), Some("BaldAngel"));
+}
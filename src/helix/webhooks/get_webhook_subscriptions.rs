@@ -91,6 +91,8 @@ impl RequestGet for GetWebhookSubscriptionsRequest {
             },
             pagination: response.pagination.cursor,
             request,
+            total: Some(response.total),
+            other: None,
         })
     }
 }
@@ -215,6 +215,8 @@ impl<T: Topic> RequestPost for WebhookHubRequest<T> {
             data: response, // FIXME: This should be a bit better...
             pagination: <_>::default(),
             request,
+            total: None,
+            other: None,
         })
     }
 }
@@ -0,0 +1,34 @@
+#![doc(alias = "whisper")]
+//! Helix endpoints regarding whispers
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use twitch_api2::helix::{HelixClient, whispers::{SendWhisperBody, SendWhisperRequest}};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let client = HelixClient::new();
+//! # let _: &HelixClient<twitch_api2::DummyHttpClient> = &client;
+//! let req = SendWhisperRequest::builder()
+//!     .from_user_id("123")
+//!     .to_user_id("456")
+//!     .build();
+//! let body = SendWhisperBody::builder().message("hello").build();
+//!
+//! println!("{:?}", &client.req_post(req, body, &token).await?.data);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    helix::{self, Request},
+    types,
+};
+use serde::{Deserialize, Serialize};
+
+pub mod send_whisper;
+
+#[doc(inline)]
+pub use send_whisper::{SendWhisper, SendWhisperBody, SendWhisperRequest};
@@ -0,0 +1,200 @@
+//! Sends a whisper message to the specified user.
+//! [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+//!
+//! # Notes
+//!
+//! The user sending the whisper must have a verified phone number.
+//! Sending to a user for the first time is limited to 40 unique recipients per day, exceeding it results in [`SendWhisper::TooManyRequests`].
+//!
+//! # Accessing the endpoint
+//!
+//! ## Request: [SendWhisperRequest]
+//!
+//! To use this endpoint, construct a [`SendWhisperRequest`] with the [`SendWhisperRequest::builder()`] method.
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::whispers::send_whisper;
+//! let request = send_whisper::SendWhisperRequest::builder()
+//!     .from_user_id("123")
+//!     .to_user_id("456")
+//!     .build();
+//! ```
+//!
+//! ## Body: [SendWhisperBody]
+//!
+//! We also need to provide a body to the request containing the message.
+//!
+//! ```
+//! # use twitch_api2::helix::whispers::send_whisper;
+//! let body = send_whisper::SendWhisperBody::builder()
+//!     .message("hello")
+//!     .build();
+//! ```
+//!
+//! ## Response: [SendWhisper]
+//!
+//! Send the request to receive the response with [`HelixClient::req_post()`](helix::HelixClient::req_post).
+//!
+//! ```rust, no_run
+//! use twitch_api2::helix::{self, whispers::send_whisper};
+//! # use twitch_api2::client;
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+//! # let client: helix::HelixClient<'static, client::DummyHttpClient> = helix::HelixClient::default();
+//! # let token = twitch_oauth2::AccessToken::new("validtoken".to_string());
+//! # let token = twitch_oauth2::UserToken::from_existing(twitch_oauth2::dummy_http_client, token, None, None).await?;
+//! let request = send_whisper::SendWhisperRequest::builder()
+//!     .from_user_id("123")
+//!     .to_user_id("456")
+//!     .build();
+//! let body = send_whisper::SendWhisperBody::builder()
+//!     .message("hello")
+//!     .build();
+//! let response: send_whisper::SendWhisper = client.req_post(request, body, &token).await?.data;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! You can also get the [`http::Request`] with [`request.create_request(body, &token, &client_id)`](helix::RequestPost::create_request)
+//! and parse the [`http::Response`] with [`SendWhisperRequest::parse_response(None, &request.get_uri(), response)`](SendWhisperRequest::parse_response)
+
+use super::*;
+use helix::RequestPost;
+
+/// Query Parameters for [Send Whisper](super::send_whisper)
+///
+/// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct SendWhisperRequest {
+    /// The ID of the user sending the whisper. This user must have a verified phone number. This ID must match the user ID in the user access token.
+    #[builder(setter(into))]
+    pub from_user_id: types::UserId,
+    /// The ID of the user to receive the whisper.
+    #[builder(setter(into))]
+    pub to_user_id: types::UserId,
+}
+
+/// Body Parameters for [Send Whisper](super::send_whisper)
+///
+/// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+#[derive(PartialEq, typed_builder::TypedBuilder, Deserialize, Serialize, Clone, Debug)]
+#[non_exhaustive]
+pub struct SendWhisperBody {
+    /// The whisper message to send. The message must not be empty. Messages that exceed the maximum length are truncated.
+    #[builder(setter(into))]
+    pub message: String,
+}
+
+impl helix::private::SealedSerialize for SendWhisperBody {}
+
+/// Return Values for [Send Whisper](super::send_whisper)
+///
+/// Errors carry the message sent by Twitch.
+///
+/// [`send-whisper`](https://dev.twitch.tv/docs/api/reference#send-whisper)
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[non_exhaustive]
+pub enum SendWhisper {
+    /// 204 - Successfully sent the whisper message or the message was silently dropped.
+    Success,
+    /// 400 - The sender and receiver are the same user, or the message is empty.
+    BadRequest(String),
+    /// 401 - The user in `from_user_id` doesn’t have a verified phone number, or authorization failed. The message tells which.
+    Unauthorized(String),
+    /// 403 - The sender is not allowed to send whispers, e.g. because the account is suspended.
+    Forbidden(String),
+    /// 404 - The user in `to_user_id` was not found.
+    NotFound(String),
+    /// 429 - The sender exceeded the number of whisper messages or unique recipients they may send to.
+    TooManyRequests(String),
+}
+
+impl Request for SendWhisperRequest {
+    type Response = SendWhisper;
+
+    const PATH: &'static str = "whispers";
+    #[cfg(feature = "twitch_oauth2")]
+    const SCOPE: &'static [twitch_oauth2::Scope] = &[twitch_oauth2::Scope::Other(
+        std::borrow::Cow::Borrowed("user:manage:whispers"),
+    )];
+
+    fn parse_error(status: http::StatusCode, message: String) -> Option<Self::Response> {
+        match status {
+            http::StatusCode::BAD_REQUEST => Some(SendWhisper::BadRequest(message)),
+            http::StatusCode::UNAUTHORIZED => Some(SendWhisper::Unauthorized(message)),
+            http::StatusCode::FORBIDDEN => Some(SendWhisper::Forbidden(message)),
+            http::StatusCode::NOT_FOUND => Some(SendWhisper::NotFound(message)),
+            http::StatusCode::TOO_MANY_REQUESTS => Some(SendWhisper::TooManyRequests(message)),
+            _ => None,
+        }
+    }
+}
+
+impl RequestPost for SendWhisperRequest {
+    type Body = SendWhisperBody;
+
+    fn parse_inner_response(
+        request: Option<Self>,
+        uri: &http::Uri,
+        response: &str,
+        status: http::StatusCode,
+    ) -> Result<helix::Response<Self, Self::Response>, helix::HelixRequestPostError>
+    where
+        Self: Sized,
+    {
+        let data = match status {
+            http::StatusCode::NO_CONTENT | http::StatusCode::OK => SendWhisper::Success,
+            _ => {
+                return Err(helix::HelixRequestPostError::InvalidResponse {
+                    reason: "unexpected status code",
+                    response: response.to_string(),
+                    status,
+                    uri: uri.clone(),
+                })
+            }
+        };
+        Ok(helix::Response {
+            data,
+            pagination: None,
+            request,
+            total: None,
+            other: None,
+        })
+    }
+}
+
+#[test]
+fn test_request() {
+    use helix::*;
+    let req = SendWhisperRequest::builder()
+        .from_user_id("123")
+        .to_user_id("456")
+        .build();
+
+    let body = SendWhisperBody::builder().message("hello").build();
+
+    dbg!(req.create_request(body, "token", "clientid").unwrap());
+
+    // From twitch docs
+    let data = br#""#.to_vec();
+
+    let http_response = http::Response::builder().status(204).body(data).unwrap();
+
+    let uri = req.get_uri().unwrap();
+    assert_eq!(
+        uri.to_string(),
+        "https://api.twitch.tv/helix/whispers?from_user_id=123&to_user_id=456"
+    );
+
+    let res = SendWhisperRequest::parse_response(Some(req.clone()), &uri, http_response).unwrap();
+    assert_eq!(res.data, SendWhisper::Success);
+
+    let data = br#"{"error":"Unauthorized","status":401,"message":"the sender does not have a verified phone number"}"#.to_vec();
+    let http_response = http::Response::builder().status(401).body(data).unwrap();
+    let res = SendWhisperRequest::parse_response(Some(req), &uri, http_response).unwrap();
+    assert_eq!(
+        res.data,
+        SendWhisper::Unauthorized("the sender does not have a verified phone number".to_string())
+    );
+}
@@ -64,6 +64,8 @@ impl<'a, C: crate::HttpClient<'a>> TmiClient<'a, C> {
     /// # Notes
     ///
     /// This function will aside from url sanitize the broadcasters username, will also remove any `#` and make it lowercase ascii
+    ///
+    /// This endpoint is being deprecated by Twitch. [`HelixClient::get_chatters`](crate::HelixClient::get_chatters) returns the same [`GetChatters`] using [Get Chatters](crate::helix::chat::get_chatters).
     pub async fn get_chatters(
         &'a self,
         broadcaster: &str,
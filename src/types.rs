@@ -45,6 +45,21 @@ pub type StreamId = String;
 /// A message ID
 pub type MsgId = String;
 
+/// An Extension ID, also the client ID of the extension
+pub type ExtensionId = String;
+
+/// A poll ID
+pub type PollId = String;
+
+/// A poll choice ID
+pub type PollChoiceId = String;
+
+/// A prediction ID
+pub type PredictionId = String;
+
+/// A prediction outcome ID
+pub type PredictionOutcomeId = String;
+
 /// A game or category as defined by Twitch
 #[derive(PartialEq, Deserialize, Debug, Clone)]
 #[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
@@ -90,6 +105,48 @@ impl Serialize for SubscriptionTier {
     }
 }
 
+/// A content classification label that can be applied to a channel
+///
+/// Localized names and descriptions are returned by the Helix endpoint Get Content Classification Labels.
+#[derive(PartialEq, Eq, Deserialize, Clone, Debug)]
+#[serde(field_identifier)]
+pub enum ContentClassificationId {
+    /// Discussions or debates about politics or sensitive social issues such as elections, civic integrity, military conflict, and civil rights in a polarizing manner.
+    DebatedSocialIssuesAndPolitics,
+    /// Excessive tobacco glorification or promotion, any marijuana consumption/use, legal drug and alcohol induced intoxication, discussions of illegal drugs.
+    DrugsIntoxication,
+    /// Content that focuses on sexualized physical attributes and activities, sexual topics, or experiences.
+    SexualThemes,
+    /// Simulations and/or depictions of realistic violence, gore, extreme injury, or death.
+    ViolentGraphic,
+    /// Participating in online or in-person gambling, poker or fantasy sports, that involve the exchange of real money.
+    Gambling,
+    /// Prolonged, and repeated use of obscenities, profanities, and vulgarities, especially as a regular part of speech.
+    ProfanityVulgarity,
+    /// Games that are rated Mature or less suitable for a younger audience. This label is set automatically from the game and cannot be changed.
+    MatureGame,
+    /// Other
+    Other(String),
+}
+
+impl Serialize for ContentClassificationId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        serializer.serialize_str(match self {
+            ContentClassificationId::DebatedSocialIssuesAndPolitics => {
+                "DebatedSocialIssuesAndPolitics"
+            }
+            ContentClassificationId::DrugsIntoxication => "DrugsIntoxication",
+            ContentClassificationId::SexualThemes => "SexualThemes",
+            ContentClassificationId::ViolentGraphic => "ViolentGraphic",
+            ContentClassificationId::Gambling => "Gambling",
+            ContentClassificationId::ProfanityVulgarity => "ProfanityVulgarity",
+            ContentClassificationId::MatureGame => "MatureGame",
+            ContentClassificationId::Other(o) => o,
+        })
+    }
+}
+
 /// Broadcaster types: "partner", "affiliated", or "".
 #[derive(PartialEq, Deserialize, Clone, Debug)]
 pub enum BroadcasterType {
@@ -307,3 +364,191 @@ pub enum Max {
         max_per_user_per_stream: u32,
     },
 }
+
+/// An amount of money, as used by charity campaigns.
+///
+/// Twitch represents the amount as a minor-unit integer, e.g. `5500` with `2` decimal places is `55.00`.
+/// Use [`Display`](std::fmt::Display) for an exact decimal string or [`Amount::as_f64`] for a float.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "deny_unknown_fields", serde(deny_unknown_fields))]
+#[non_exhaustive]
+pub struct Amount {
+    /// The monetary amount. The amount is specified in the currency’s minor unit.
+    pub value: i64,
+    /// The number of decimal places used by the currency.
+    pub decimal_places: u32,
+    /// The ISO-4217 three-letter currency code that identifies the type of currency in `value`.
+    pub currency: String,
+}
+
+impl Amount {
+    /// The amount as a float, e.g. `55.0` for a `value` of `5500` with `2` decimal places.
+    pub fn as_f64(&self) -> f64 { self.value as f64 / 10f64.powi(self.decimal_places as i32) }
+}
+
+impl std::fmt::Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.value < 0 { "-" } else { "" };
+        let digits = self.value.unsigned_abs().to_string();
+        let places = self.decimal_places as usize;
+        if places == 0 {
+            return write!(f, "{}{}", sign, digits);
+        }
+        let digits = format!("{:0>width$}", digits, width = places + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - places);
+        write!(f, "{}{}.{}", sign, whole, fraction)
+    }
+}
+
+/// A secret value, such as a stream key.
+///
+/// The value is redacted in [`Debug`](std::fmt::Debug) and [`Display`](std::fmt::Display) output, use [`Secret::secret`] to access it.
+/// Serializing writes the secret value, so that it can be stored and deserialized again.
+#[derive(Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Create a new secret
+    pub fn new(secret: String) -> Self { Secret(secret) }
+
+    /// Get the secret value
+    pub fn secret(&self) -> &str { &self.0 }
+
+    /// Consume the wrapper and return the secret value
+    pub fn into_secret(self) -> String { self.0 }
+}
+
+impl std::fmt::Debug for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Secret([redacted])")
+    }
+}
+
+impl std::fmt::Display for Secret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result { f.write_str("[redacted]") }
+}
+
+/// Parse a RFC3339 timestamp, e.g. `2021-02-19T23:47:00.8091512Z`
+#[cfg(all(feature = "eventsub", any(feature = "hmac", feature = "helix")))]
+pub(crate) fn parse_timestamp(timestamp: &str) -> Option<std::time::SystemTime> {
+    /// Parse a number of exactly `width` digits
+    fn num(s: &str, width: usize) -> Option<i64> {
+        if s.len() != width || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    }
+
+    let (date, time) = timestamp.split_at(timestamp.find(&['T', 't'][..])?);
+    let time = &time[1..];
+    let mut date = date.splitn(3, '-');
+    let (year, month, day) = (
+        num(date.next()?, 4)?,
+        num(date.next()?, 2)?,
+        num(date.next()?, 2)?,
+    );
+
+    let (time, offset) = if let Some(time) = time.strip_suffix(&['Z', 'z'][..]) {
+        (time, 0)
+    } else {
+        let split = time.rfind(&['+', '-'][..])?;
+        let (time, offset) = time.split_at(split);
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let (hours, minutes) = offset[1..].split_at(offset[1..].find(':')?);
+        (
+            time,
+            sign * (num(hours, 2)? * 3600 + num(&minutes[1..], 2)? * 60),
+        )
+    };
+    let (time, nanos) = match time.find('.') {
+        Some(dot) => {
+            let fraction = &time[dot + 1..];
+            if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            // Precision beyond nanoseconds is ignored
+            let digits = &fraction[..fraction.len().min(9)];
+            (
+                &time[..dot],
+                num(digits, digits.len())? * 10_i64.pow(9 - digits.len() as u32),
+            )
+        }
+        None => (time, 0),
+    };
+    let mut time = time.splitn(3, ':');
+    let (hour, minute, second) = (
+        num(time.next()?, 2)?,
+        num(time.next()?, 2)?,
+        num(time.next()?, 2)?,
+    );
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // Days since the unix epoch, from http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era.checked_mul(146097)?.checked_add(doe - 719468)?;
+
+    let seconds = days
+        .checked_mul(86400)?
+        .checked_add(hour * 3600 + minute * 60 + second - offset)?;
+    if seconds < 0 {
+        return None;
+    }
+    Some(std::time::UNIX_EPOCH + std::time::Duration::new(seconds as u64, nanos as u32))
+}
+
+#[test]
+fn amount_decimal() {
+    let amount = |value, decimal_places| Amount {
+        value,
+        decimal_places,
+        currency: "USD".to_string(),
+    };
+    assert_eq!(amount(5500, 2).to_string(), "55.00");
+    assert_eq!(amount(5, 2).to_string(), "0.05");
+    assert_eq!(amount(-123, 1).to_string(), "-12.3");
+    assert_eq!(amount(1000, 0).to_string(), "1000");
+    assert!((amount(5550, 2).as_f64() - 55.5).abs() < f64::EPSILON);
+}
+
+#[test]
+fn secret_redacted() {
+    let secret = Secret::new("live_44322889_nCGwsCu4".to_string());
+    assert_eq!(format!("{:?}", secret), "Secret([redacted])");
+    assert_eq!(secret.to_string(), "[redacted]");
+    assert_eq!(secret.secret(), "live_44322889_nCGwsCu4");
+    assert_eq!(
+        serde_json::to_string(&secret).unwrap(),
+        r#""live_44322889_nCGwsCu4""#
+    );
+}
+
+#[test]
+#[cfg(all(feature = "eventsub", any(feature = "hmac", feature = "helix")))]
+fn timestamps() {
+    use std::time::{Duration, UNIX_EPOCH};
+    let sent = UNIX_EPOCH + Duration::new(1613778420, 809151200);
+    assert_eq!(parse_timestamp("2021-02-19T23:47:00.8091512Z"), Some(sent));
+    assert_eq!(
+        parse_timestamp("2021-02-20T01:47:00.8091512+02:00"),
+        Some(sent)
+    );
+    assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(UNIX_EPOCH));
+    assert_eq!(parse_timestamp("2021-02-19 23:47:00Z"), None);
+    assert_eq!(parse_timestamp("2021-13-19T23:47:00Z"), None);
+    assert_eq!(parse_timestamp("2021-02-19T23:47:00.80915x2Z"), None);
+    assert_eq!(parse_timestamp("99999999999999999-02-19T23:47:00Z"), None);
+    assert_eq!(parse_timestamp("2021-2-19T23:47:00Z"), None);
+    assert_eq!(parse_timestamp("2021-02-19T23:47:00+2:00"), None);
+}